@@ -1 +1,3 @@
-pub mod rest;
\ No newline at end of file
+pub mod rest;
+pub mod sqs;
+pub mod websocket;
\ No newline at end of file
@@ -0,0 +1,63 @@
+use crate::adapter::incoming::rest::openapi::header_parameter;
+use axum::extract::FromRequestParts;
+use axum::http::header::ACCEPT_LANGUAGE;
+use axum::http::request::Parts;
+use std::convert::Infallible;
+use utoipa::openapi::path::{Parameter, ParameterIn};
+use utoipa::IntoParams;
+
+/// Locales of the `Accept-Language` header, most preferred first. Wildcards and `q=0` entries are dropped.
+#[derive(Debug, Default)]
+pub struct AcceptedLanguages(pub Vec<String>);
+
+fn parse(header: &str) -> Vec<String> {
+    let mut weighted: Vec<(f32, &str)> = header.split(',')
+        .filter_map(|entry| {
+            let mut parameters = entry.split(';').map(str::trim);
+            let locale = parameters.next().filter(|locale| !locale.is_empty() && *locale != "*")?;
+            let quality = parameters
+                .find_map(|parameter| parameter.strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.parse::<f32>().ok())?;
+            (quality > 0.0).then_some((quality, locale))
+        })
+        .collect();
+
+    // The sort is stable, so locales of equal quality keep the order of the header.
+    weighted.sort_by(|first, second| second.0.total_cmp(&first.0));
+    weighted.into_iter().map(|(_, locale)| locale.to_string()).collect()
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for AcceptedLanguages {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let locales = parts.headers.get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(parse)
+            .unwrap_or_default();
+        Ok(AcceptedLanguages(locales))
+    }
+}
+
+impl IntoParams for AcceptedLanguages {
+    fn into_params(_parameter_in: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        vec![header_parameter(ACCEPT_LANGUAGE.as_str(), "Locales to localise content in, after the recipient's own")]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_orders_by_quality() {
+        let result = parse("fr;q=0.5, pt-BR, *;q=0.1, en;q=0.8, de;q=0, es;q=0.8");
+
+        assert_eq!(result, vec!["pt-BR", "en", "es", "fr"]);
+    }
+
+    #[test]
+    fn test_parse_ignores_malformed_entries() {
+        assert_eq!(parse("en;q=high, , pt"), vec!["pt"]);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::application::configuration::application_state::AppState;
+use crate::application::error::ApplicationError;
+use axum::extract::{FromRequestParts, Query};
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+use serde::Deserialize;
+use std::sync::Arc;
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// Recipient authenticated by the bearer token in the `Authorization` header of the request.
+#[derive(Debug)]
+pub struct AuthenticatedRecipient(pub String);
+
+/// Recipient of an event stream or WebSocket, authenticated as `AuthenticatedRecipient` is.
+///
+/// Browsers cannot set headers on `EventSource` or WebSocket connections, so the token is also accepted as an
+/// `access_token` query parameter. Only the streaming routes take it, as query strings end up in access logs and
+/// `Referer` headers.
+#[derive(Debug)]
+pub struct StreamingRecipient(pub String);
+
+/// Proof that the request was made by a recipient configured as an administrator; others are answered `403`.
+#[derive(Debug)]
+pub struct AuthenticatedAdministrator;
+
+#[derive(Debug, Deserialize)]
+struct AccessTokenQuery {
+    access_token: Option<String>,
+}
+
+fn bearer_token(parts: &Parts) -> Option<String> {
+    parts.headers.get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+        .map(str::to_string)
+}
+
+fn query_token(parts: &Parts) -> Option<String> {
+    Query::<AccessTokenQuery>::try_from_uri(&parts.uri).ok()
+        .and_then(|Query(query)| query.access_token)
+}
+
+fn authenticate(token: Option<String>, state: &AppState) -> Result<String, ApplicationError> {
+    let token = token.ok_or_else(|| ApplicationError::Unauthorized("Missing bearer token".to_string()))?;
+    state.authentication_service.authenticate(&token)
+}
+
+impl FromRequestParts<Arc<AppState>> for AuthenticatedRecipient {
+    type Rejection = ApplicationError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &Arc<AppState>) -> Result<Self, Self::Rejection> {
+        authenticate(bearer_token(parts), state).map(AuthenticatedRecipient)
+    }
+}
+
+impl FromRequestParts<Arc<AppState>> for StreamingRecipient {
+    type Rejection = ApplicationError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &Arc<AppState>) -> Result<Self, Self::Rejection> {
+        authenticate(bearer_token(parts).or_else(|| query_token(parts)), state).map(StreamingRecipient)
+    }
+}
+
+impl FromRequestParts<Arc<AppState>> for AuthenticatedAdministrator {
+    type Rejection = ApplicationError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &Arc<AppState>) -> Result<Self, Self::Rejection> {
+        let AuthenticatedRecipient(recipient) = AuthenticatedRecipient::from_request_parts(parts, state).await?;
+        if !state.authentication_service.is_administrator(&recipient) {
+            return Err(ApplicationError::Forbidden(format!("{} is not an administrator", recipient)));
+        }
+        Ok(AuthenticatedAdministrator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::service::authentication_service::MockAuthenticationServicePort;
+    use axum::http::Request;
+    use mockall::predicate::eq;
+
+    fn app_state() -> Arc<AppState> {
+        let mut authentication_service = MockAuthenticationServicePort::new();
+        authentication_service.expect_authenticate()
+            .with(eq("valid-token"))
+            .returning(|_| Ok("recipient".to_string()));
+        authentication_service.expect_authenticate()
+            .with(eq("admin-token"))
+            .returning(|_| Ok("admin".to_string()));
+        authentication_service.expect_authenticate()
+            .returning(|_| Err(ApplicationError::Unauthorized("Invalid token".to_string())));
+        authentication_service.expect_is_administrator()
+            .returning(|recipient| recipient == "admin");
+
+        Arc::new(AppState { authentication_service: Arc::new(authentication_service), ..AppState::mocked() })
+    }
+
+    async fn authenticate(request: Request<()>) -> Result<AuthenticatedRecipient, ApplicationError> {
+        let (mut parts, _) = request.into_parts();
+        AuthenticatedRecipient::from_request_parts(&mut parts, &app_state()).await
+    }
+
+    async fn authenticate_stream(request: Request<()>) -> Result<StreamingRecipient, ApplicationError> {
+        let (mut parts, _) = request.into_parts();
+        StreamingRecipient::from_request_parts(&mut parts, &app_state()).await
+    }
+
+    async fn authenticate_administrator(request: Request<()>) -> Result<AuthenticatedAdministrator, ApplicationError> {
+        let (mut parts, _) = request.into_parts();
+        AuthenticatedAdministrator::from_request_parts(&mut parts, &app_state()).await
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_from_header() {
+        let request = Request::builder().uri("/").header(AUTHORIZATION, "Bearer valid-token").body(()).unwrap();
+
+        assert_eq!(authenticate(request).await.unwrap().0, "recipient");
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_from_query_unauthorized() {
+        let request = Request::builder().uri("/?access_token=valid-token").body(()).unwrap();
+
+        assert!(matches!(authenticate(request).await, Err(ApplicationError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_stream_from_header() {
+        let request = Request::builder().uri("/").header(AUTHORIZATION, "Bearer valid-token").body(()).unwrap();
+
+        assert_eq!(authenticate_stream(request).await.unwrap().0, "recipient");
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_stream_from_query() {
+        let request = Request::builder().uri("/?lang=en&access_token=valid-token").body(()).unwrap();
+
+        assert_eq!(authenticate_stream(request).await.unwrap().0, "recipient");
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_stream_from_percent_encoded_query() {
+        let request = Request::builder().uri("/?access_token=valid%2Dtoken").body(()).unwrap();
+
+        assert_eq!(authenticate_stream(request).await.unwrap().0, "recipient");
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_missing_or_invalid_token() {
+        let missing = Request::builder().uri("/").body(()).unwrap();
+        let invalid = Request::builder().uri("/").header(AUTHORIZATION, "Bearer forged").body(()).unwrap();
+
+        assert!(matches!(authenticate(missing).await, Err(ApplicationError::Unauthorized(_))));
+        assert!(matches!(authenticate(invalid).await, Err(ApplicationError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_administrator() {
+        let administrator = Request::builder().uri("/").header(AUTHORIZATION, "Bearer admin-token").body(()).unwrap();
+        let recipient = Request::builder().uri("/").header(AUTHORIZATION, "Bearer valid-token").body(()).unwrap();
+        let anonymous = Request::builder().uri("/").body(()).unwrap();
+
+        assert!(authenticate_administrator(administrator).await.is_ok());
+        assert!(matches!(authenticate_administrator(recipient).await, Err(ApplicationError::Forbidden(_))));
+        assert!(matches!(authenticate_administrator(anonymous).await, Err(ApplicationError::Unauthorized(_))));
+    }
+}
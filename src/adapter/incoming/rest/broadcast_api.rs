@@ -0,0 +1,133 @@
+use crate::adapter::incoming::rest::request::create_broadcast::CreateBroadcastRequest;
+use crate::adapter::incoming::rest::response::broadcast::BroadcastResponse;
+use crate::application::configuration::application_state::AppState;
+use crate::application::error::ApplicationError;
+use crate::application::service::broadcast_service::BroadcastServicePort;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub struct BroadcastApi {
+    broadcast_service: Arc<dyn BroadcastServicePort>,
+}
+
+impl BroadcastApi {
+    pub fn new(broadcast_service: Arc<dyn BroadcastServicePort>) -> Self {
+        Self { broadcast_service }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/broadcast",
+    tag = "broadcasts",
+    summary = "Create a broadcast",
+    request_body = CreateBroadcastRequest,
+    responses((status = 201, description = "The broadcast created, announced by a `created` event", body = BroadcastResponse)),
+)]
+/// Stores the broadcast and publishes a `created` event for it, which webhooks receive once with `recipient` set to
+/// `*`, and the event stream and WebSocket of every recipient as addressed to them.
+pub async fn create_broadcast(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<CreateBroadcastRequest>,
+) -> Result<(StatusCode, Json<BroadcastResponse>), ApplicationError> {
+    let broadcast = state.broadcast_api.broadcast_service.create_broadcast(payload.into()).await?;
+    Ok((StatusCode::CREATED, Json(broadcast.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/broadcast/{uuid}",
+    tag = "broadcasts",
+    summary = "Get a broadcast",
+    params(("uuid" = String, Path, description = "Broadcast id")),
+    responses((status = 200, description = "The broadcast", body = BroadcastResponse)),
+)]
+pub async fn get_broadcast(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+) -> Result<Json<BroadcastResponse>, ApplicationError> {
+    let broadcast = state.broadcast_api.broadcast_service.get_broadcast(uuid).await?;
+    Ok(Json(broadcast.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/broadcast",
+    tag = "broadcasts",
+    summary = "List broadcasts",
+    responses((status = 200, description = "The broadcasts", body = Vec<BroadcastResponse>)),
+)]
+pub async fn get_broadcasts(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<BroadcastResponse>>, ApplicationError> {
+    let broadcasts = state.broadcast_api.broadcast_service.list_broadcasts().await?;
+    Ok(Json(broadcasts.into_iter().map(|broadcast| broadcast.into()).collect()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/broadcast/{uuid}",
+    tag = "broadcasts",
+    summary = "Delete a broadcast",
+    params(("uuid" = String, Path, description = "Broadcast id")),
+    responses((status = 204, description = "The broadcast is deleted")),
+)]
+pub async fn delete_broadcast(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+) -> Result<StatusCode, ApplicationError> {
+    state.broadcast_api.broadcast_service.delete_broadcast(uuid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn broadcast_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(create_broadcast, get_broadcasts))
+        .routes(routes!(get_broadcast, delete_broadcast))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::command::create_broadcast_command::CreateBroadcastCommand;
+    use crate::application::service::broadcast_service::MockBroadcastServicePort;
+    use crate::domain::model::broadcast::Broadcast;
+    use crate::domain::model::priority::Priority;
+    use mockall::predicate::eq;
+
+    fn app_state(mock_service: MockBroadcastServicePort) -> Arc<AppState> {
+        Arc::new(AppState {
+            broadcast_api: Arc::new(BroadcastApi::new(Arc::new(mock_service))),
+            ..AppState::mocked()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_broadcast_success() {
+        let mut mock_service = MockBroadcastServicePort::new();
+        mock_service
+            .expect_create_broadcast()
+            .with(eq(CreateBroadcastCommand {
+                title: Some("Maintenance".to_string()),
+                message: "Down at 22:00".to_string(),
+                category: None,
+                priority: Priority::High,
+            }))
+            .returning(|command| Ok(Broadcast::new(command.title, command.message)));
+
+        let request = CreateBroadcastRequest {
+            title: Some("Maintenance".to_string()),
+            message: "Down at 22:00".to_string(),
+            category: None,
+            priority: Priority::High,
+        };
+        let (status, response) = create_broadcast(State(app_state(mock_service)), Json(request)).await.unwrap();
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(response.0.message, "Down at 22:00");
+    }
+}
@@ -0,0 +1,47 @@
+use crate::adapter::incoming::rest::openapi::operations_mut;
+use axum::http::header::LINK;
+use axum::http::{HeaderName, HeaderValue};
+use axum::middleware::map_response;
+use axum::response::Response;
+use utoipa::openapi::Deprecated;
+use utoipa_axum::router::OpenApiRouter;
+
+/// When the routes replaced by `/v1` were deprecated, as an RFC 9745 date.
+const DEPRECATED_SINCE: &str = "@1792368000";
+const SUCCESSOR: &str = "</v1/notifications>; rel=\"successor-version\"";
+
+/// Announces on every response of a route replaced by `/v1` that it is deprecated, and where its successor is.
+pub async fn mark_deprecated(mut response: Response) -> Response {
+    let headers = response.headers_mut();
+    headers.insert(HeaderName::from_static("deprecation"), HeaderValue::from_static(DEPRECATED_SINCE));
+    headers.append(LINK, HeaderValue::from_static(SUCCESSOR));
+    response
+}
+
+/// Deprecates every route given, both in its responses and in the API document.
+pub fn deprecated<S>(routes: OpenApiRouter<S>) -> OpenApiRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let mut routes = routes.layer(map_response(mark_deprecated));
+    for path_item in routes.get_openapi_mut().paths.paths.values_mut() {
+        operations_mut(path_item).for_each(|operation| operation.deprecated = Some(Deprecated::True));
+    }
+    routes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+
+    #[tokio::test]
+    async fn test_mark_deprecated_keeps_response() {
+        let response = mark_deprecated(StatusCode::NOT_FOUND.into_response()).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers().get("deprecation").unwrap(), DEPRECATED_SINCE);
+        assert_eq!(response.headers().get(LINK).unwrap(), SUCCESSOR);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::adapter::incoming::rest::openapi::header_parameter;
+use crate::adapter::incoming::rest::response::envelope::Envelope;
+use crate::adapter::incoming::rest::response::notification::NotificationResponse;
+use crate::adapter::incoming::rest::response::state_change::StateChangeResponse;
+use crate::application::error::ApplicationError;
+use crate::domain::model::notification::NotificationKind;
+use axum::extract::FromRequestParts;
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_MATCH, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::convert::Infallible;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use utoipa::openapi::path::{Parameter, ParameterIn};
+use utoipa::IntoParams;
+
+/// Version of the notification required by the `If-Match` header, if any, as `*` matches every version.
+#[derive(Debug, Default)]
+pub struct IfMatch(pub Option<u64>);
+
+/// Only a single strong ETag is understood, as no other kind is ever handed out.
+fn parse(header: &str) -> Option<Option<u64>> {
+    let header = header.trim();
+    if header == "*" {
+        return Some(None);
+    }
+    header.strip_prefix('"')?.strip_suffix('"')?.parse().ok().map(Some)
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for IfMatch {
+    type Rejection = ApplicationError;
+
+    /// An ETag that cannot be parsed matches no version, so it fails the precondition as a stale one would.
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(IF_MATCH) else {
+            return Ok(IfMatch(None));
+        };
+        value.to_str().ok()
+            .and_then(parse)
+            .map(IfMatch)
+            .ok_or_else(|| ApplicationError::PreconditionFailed("If-Match must be * or the ETag of the notification".to_string()))
+    }
+}
+
+impl IntoParams for IfMatch {
+    fn into_params(_parameter_in: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        vec![header_parameter(IF_MATCH.as_str(), "`ETag` of the notification the change is based on, or `*`")]
+    }
+}
+
+fn http_date(time: DateTime<Utc>) -> String {
+    time.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// What a client revalidating its copy knows of it, from `If-None-Match` and `If-Modified-Since`.
+#[derive(Debug, Default)]
+pub struct CacheValidators {
+    if_none_match: Option<String>,
+    if_modified_since: Option<DateTime<Utc>>,
+    untagged: bool,
+}
+
+impl CacheValidators {
+    /// Leaves a response without `ETag` and `Last-Modified`, for bodies that are not told apart by them and so cannot
+    /// be revalidated.
+    pub fn untagged(self) -> Self {
+        Self { untagged: true, ..self }
+    }
+
+    /// Whether the client's copy is still current. `If-None-Match` takes precedence and compares ETags weakly, and
+    /// `If-Modified-Since` only has a precision of seconds.
+    fn is_fresh(&self, etag: Option<&str>, last_modified: Option<DateTime<Utc>>) -> bool {
+        let opaque = |etag: &str| etag.trim_start_matches("W/").to_string();
+        if let Some(if_none_match) = &self.if_none_match {
+            return etag.is_some_and(|etag| {
+                if_none_match.split(',')
+                    .map(str::trim)
+                    .any(|candidate| candidate == "*" || opaque(candidate) == opaque(etag))
+            });
+        }
+        match (self.if_modified_since, last_modified) {
+            (Some(since), Some(last_modified)) => last_modified.timestamp() <= since.timestamp(),
+            _ => false,
+        }
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for CacheValidators {
+    type Rejection = Infallible;
+
+    /// Unreadable validators are ignored, which only costs the client a full response.
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let header = |name| parts.headers.get(name).and_then(|value| value.to_str().ok());
+        Ok(CacheValidators {
+            if_none_match: header(IF_NONE_MATCH).map(str::to_string),
+            if_modified_since: header(IF_MODIFIED_SINCE)
+                .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+                .map(|value| value.with_timezone(&Utc)),
+            untagged: false,
+        })
+    }
+}
+
+impl IntoParams for CacheValidators {
+    fn into_params(_parameter_in: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        vec![
+            header_parameter(IF_NONE_MATCH.as_str(), "`ETag`s of the copies held, answered `304` while still current"),
+            header_parameter(IF_MODIFIED_SINCE.as_str(), "`Last-Modified` time of the notification held"),
+        ]
+    }
+}
+
+/// A response holding a single notification, which carries its version as `ETag`, to be sent back in `If-Match`,
+/// and its `Last-Modified` time. Broadcasts have no version to send.
+pub trait Validated: IntoResponse {
+    fn notification(&self) -> &NotificationResponse;
+
+    fn etag(&self) -> Option<String> {
+        let notification = self.notification();
+        (notification.kind == NotificationKind::Personal).then(|| format!("\"{}\"", notification.version))
+    }
+
+    fn validator_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Some(etag) = self.etag().and_then(|etag| HeaderValue::from_str(&etag).ok()) {
+            headers.insert(ETAG, etag);
+        }
+        if let Ok(last_modified) = HeaderValue::from_str(&http_date(self.notification().last_updated.time)) {
+            headers.insert(LAST_MODIFIED, last_modified);
+        }
+        headers
+    }
+}
+
+#[derive(Debug)]
+pub struct VersionedJson(pub NotificationResponse);
+
+impl Validated for VersionedJson {
+    fn notification(&self) -> &NotificationResponse {
+        &self.0
+    }
+}
+
+impl IntoResponse for VersionedJson {
+    fn into_response(self) -> Response {
+        (self.validator_headers(), Json(self.0)).into_response()
+    }
+}
+
+impl Validated for Envelope<VersionedJson> {
+    fn notification(&self) -> &NotificationResponse {
+        &self.data.0
+    }
+}
+
+impl IntoResponse for Envelope<VersionedJson> {
+    fn into_response(self) -> Response {
+        (self.validator_headers(), Json(Envelope::new(self.data.0))).into_response()
+    }
+}
+
+impl Validated for StateChangeResponse {
+    fn notification(&self) -> &NotificationResponse {
+        &self.notification
+    }
+}
+
+impl IntoResponse for StateChangeResponse {
+    fn into_response(self) -> Response {
+        (self.validator_headers(), Json(self)).into_response()
+    }
+}
+
+/// A read answered with `304 Not Modified` when the copy the client revalidates is still current. Responses may
+/// only be cached privately, as they belong to one recipient, and must be revalidated before being reused.
+#[derive(Debug)]
+pub struct Conditional<T>(pub T, pub CacheValidators);
+
+fn conditional_response(headers: HeaderMap, fresh: bool, response: impl IntoResponse) -> Response {
+    let cache_control = [(CACHE_CONTROL, "private, no-cache")];
+    if fresh {
+        return (StatusCode::NOT_MODIFIED, cache_control, headers).into_response();
+    }
+    (cache_control, headers, response).into_response()
+}
+
+impl<T: Validated> IntoResponse for Conditional<T> {
+    fn into_response(self) -> Response {
+        let Conditional(validated, validators) = self;
+        if validators.untagged {
+            let mut response = conditional_response(HeaderMap::new(), false, validated);
+            response.headers_mut().remove(ETAG);
+            response.headers_mut().remove(LAST_MODIFIED);
+            return response;
+        }
+        let fresh = validators.is_fresh(validated.etag().as_deref(), Some(validated.notification().last_updated.time));
+        conditional_response(validated.validator_headers(), fresh, validated)
+    }
+}
+
+/// Lists are tagged with a hash of their body, as their items may change or leave the listing in ways no single
+/// time reflects; they have no `Last-Modified` for that reason.
+impl<T: Serialize> IntoResponse for Conditional<Json<T>> {
+    fn into_response(self) -> Response {
+        let Conditional(Json(value), validators) = self;
+        let body = match serde_json::to_vec(&value) {
+            Ok(body) => body,
+            Err(error) => return ApplicationError::InternalError(error.to_string()).into_response(),
+        };
+        if validators.untagged {
+            return conditional_response(HeaderMap::new(), false, ([(CONTENT_TYPE, "application/json")], body));
+        }
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        let etag = format!("W/\"{:016x}\"", hasher.finish());
+
+        let mut headers = HeaderMap::new();
+        if let Ok(value) = HeaderValue::from_str(&etag) {
+            headers.insert(ETAG, value);
+        }
+        let fresh = validators.is_fresh(Some(&etag), None);
+        conditional_response(headers, fresh, ([(CONTENT_TYPE, "application/json")], body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification::Notification;
+
+    #[test]
+    fn test_parse_if_match() {
+        assert_eq!(parse("\"3\""), Some(Some(3)));
+        assert_eq!(parse(" * "), Some(None));
+        assert_eq!(parse("W/\"3\""), None);
+        assert_eq!(parse("\"3\", \"4\""), None);
+    }
+
+    #[test]
+    fn test_versioned_json_sets_etag_of_personal_notifications() {
+        let notification = Notification { version: 2, ..Notification::new("recipient".to_string(), "message".to_string()) };
+        let broadcast = Notification { kind: NotificationKind::Broadcast, ..notification.clone() };
+
+        let response = VersionedJson(notification.into()).into_response();
+        let broadcast_response = VersionedJson(broadcast.into()).into_response();
+
+        assert_eq!(response.headers().get(ETAG).unwrap(), "\"2\"");
+        assert!(response.headers().get(LAST_MODIFIED).is_some());
+        assert!(broadcast_response.headers().get(ETAG).is_none());
+    }
+
+    #[test]
+    fn test_is_fresh() {
+        let last_modified = DateTime::parse_from_rfc3339("2024-05-01T10:00:00.500Z").unwrap().with_timezone(&Utc);
+        let if_none_match = |etags: &str| CacheValidators { if_none_match: Some(etags.to_string()), ..CacheValidators::default() };
+        let if_modified_since = |time: &str| CacheValidators {
+            if_modified_since: DateTime::parse_from_rfc2822(time).ok().map(|time| time.with_timezone(&Utc)),
+            ..CacheValidators::default()
+        };
+
+        assert!(if_none_match("\"1\", W/\"2\"").is_fresh(Some("\"2\""), None));
+        assert!(if_none_match("*").is_fresh(Some("\"2\""), None));
+        assert!(!if_none_match("\"1\"").is_fresh(Some("\"2\""), Some(last_modified)));
+        assert!(if_modified_since("Wed, 01 May 2024 10:00:00 GMT").is_fresh(None, Some(last_modified)));
+        assert!(!if_modified_since("Wed, 01 May 2024 09:59:59 GMT").is_fresh(None, Some(last_modified)));
+        assert!(!CacheValidators::default().is_fresh(Some("\"2\""), Some(last_modified)));
+    }
+
+    #[test]
+    fn test_conditional_list_not_modified() {
+        let response = Conditional(Json(vec!["first", "second"]), CacheValidators::default()).into_response();
+        let etag = response.headers().get(ETAG).unwrap().to_str().unwrap().to_string();
+
+        let revalidated = Conditional(
+            Json(vec!["first", "second"]),
+            CacheValidators { if_none_match: Some(etag.clone()), ..CacheValidators::default() },
+        ).into_response();
+        let changed = Conditional(
+            Json(vec!["first"]),
+            CacheValidators { if_none_match: Some(etag), ..CacheValidators::default() },
+        ).into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(revalidated.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(changed.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_untagged_list_never_not_modified() {
+        let validators = CacheValidators { if_none_match: Some("*".to_string()), ..CacheValidators::default() };
+
+        let response = Conditional(Json(vec!["first", "second"]), validators.untagged()).into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(ETAG).is_none());
+    }
+
+    #[test]
+    fn test_untagged_notification_never_not_modified() {
+        let notification = Notification { version: 2, ..Notification::new("recipient".to_string(), "message".to_string()) };
+        let validators = CacheValidators { if_none_match: Some("\"2\"".to_string()), ..CacheValidators::default() };
+
+        let response = Conditional(VersionedJson(notification.into()), validators.untagged()).into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(ETAG).is_none());
+        assert!(response.headers().get(LAST_MODIFIED).is_none());
+    }
+}
@@ -1,3 +1,16 @@
+pub mod accept_language;
+pub mod authentication;
+pub mod broadcast_api;
+pub mod deprecation;
+pub mod etag;
 pub mod notification_api;
+pub mod notification_stream_api;
+pub mod notification_template_api;
+pub mod openapi;
+pub mod presentation;
+pub mod recipient_profile_api;
 pub mod request;
-pub mod response;
\ No newline at end of file
+pub mod response;
+pub mod topic_api;
+pub mod v1;
+pub mod webhook_api;
\ No newline at end of file
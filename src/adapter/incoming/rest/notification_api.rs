@@ -1,104 +1,700 @@
+use crate::adapter::incoming::rest::accept_language::AcceptedLanguages;
+use crate::adapter::incoming::rest::authentication::AuthenticatedRecipient;
+use crate::adapter::incoming::rest::broadcast_api::broadcast_routes;
+use crate::adapter::incoming::rest::deprecation::deprecated;
+use crate::adapter::incoming::rest::etag::{CacheValidators, Conditional, IfMatch, VersionedJson};
+use crate::adapter::incoming::rest::notification_stream_api::notification_stream_routes;
+use crate::adapter::incoming::rest::notification_template_api::notification_template_routes;
+use crate::adapter::incoming::rest::openapi::{self, docs_routes, ApiDoc};
+use crate::adapter::incoming::rest::presentation::Presentation;
+use crate::adapter::incoming::rest::recipient_profile_api::recipient_profile_routes;
 use crate::adapter::incoming::rest::request::create_notification::CreateNotificationRequest;
+use crate::adapter::incoming::rest::request::create_notification_from_template::CreateNotificationFromTemplateRequest;
+use crate::adapter::incoming::rest::request::list_notifications::ListNotificationsQuery;
+use crate::adapter::incoming::rest::request::search_notifications::SearchNotificationsQuery;
+use crate::adapter::incoming::rest::request::snooze_notification::SnoozeNotificationRequest;
 use crate::adapter::incoming::rest::response::notification::NotificationResponse;
+use crate::adapter::incoming::rest::response::notification_group::NotificationGroupResponse;
+use crate::adapter::incoming::rest::response::notification_history::NotificationHistoryResponse;
+use crate::adapter::incoming::rest::response::notification_search_hit::NotificationSearchHitResponse;
+use crate::adapter::incoming::rest::response::state_change::StateChangeResponse;
+use crate::adapter::incoming::rest::response::tag_count::TagCountResponse;
+use crate::adapter::incoming::rest::response::unread_count::UnreadCountResponse;
+use crate::adapter::incoming::rest::topic_api::topic_routes;
+use crate::adapter::incoming::rest::v1::notification_api::notification_routes;
+use crate::adapter::incoming::rest::webhook_api::webhook_routes;
+use crate::adapter::incoming::websocket::notification_socket_api::notification_socket_routes;
 use crate::application::configuration::application_state::AppState;
 use crate::application::error::ApplicationError;
 use crate::application::service::notification_service::NotificationServicePort;
-use axum::extract::{Path, State};
-use axum::routing::{get, post};
+use crate::domain::model::notification_filter::NotificationFilter;
+use crate::domain::model::notification_history::ChangeSource;
+use axum::extract::{Path, Query, State};
 use axum::{Json, Router};
 use std::sync::Arc;
+use utoipa::OpenApi;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+const MAX_SEARCH_LIMIT: usize = 100;
 
 pub struct NotificationApi {
-    notification_service: Arc<dyn NotificationServicePort + Send + Sync>,
+    pub(crate) notification_service: Arc<dyn NotificationServicePort + Send + Sync>,
+    default_recipient: String,
 }
 
 impl NotificationApi {
-    pub fn new(notification_service: Arc<dyn NotificationServicePort + Send + Sync>) -> Self {
-        Self { notification_service }
+    pub fn new(notification_service: Arc<dyn NotificationServicePort + Send + Sync>, default_recipient: String) -> Self {
+        Self { notification_service, default_recipient }
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/notification",
+    tag = "legacy",
+    operation_id = "legacy_create_notification",
+    summary = "Create a notification",
+    request_body = CreateNotificationRequest,
+    responses((status = 200, description = "The notification created", body = NotificationResponse)),
+)]
 pub async fn create_notification(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<CreateNotificationRequest>,
-) -> Result<Json<NotificationResponse>, ApplicationError> {
-    let notification = state.notification_api.notification_service.create_notification(payload.message).await?;
-    Ok(Json(notification.into()))
+) -> Result<VersionedJson, ApplicationError> {
+    let command = payload.into_command(&state.notification_api.default_recipient);
+    let notification = state.notification_api.notification_service.create_notification(command).await?;
+    Ok(VersionedJson(notification.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/notification/template",
+    tag = "legacy",
+    operation_id = "legacy_create_notification_from_template",
+    summary = "Create a notification from a template",
+    request_body = CreateNotificationFromTemplateRequest,
+    responses((status = 200, description = "The notification created", body = NotificationResponse)),
+)]
+pub async fn create_notification_from_template(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<CreateNotificationFromTemplateRequest>,
+) -> Result<VersionedJson, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .create_notification_from_template(payload.into())
+        .await?;
+    Ok(VersionedJson(notification.into()))
 }
 
+#[utoipa::path(
+    get,
+    path = "/notification/{uuid}",
+    tag = "legacy",
+    operation_id = "legacy_get_notification",
+    summary = "Get a notification",
+    params(("uuid" = String, Path, description = "Notification id"), Presentation, CacheValidators),
+    responses(
+        (status = 200, description = "The notification", body = NotificationResponse, headers(
+            ("ETag" = String, description = "Version of the notification, left out when `tz` or `age` is asked for"),
+            ("Last-Modified" = String, description = "When the notification last changed, left out when `tz` or `age` is asked for"),
+        )),
+        (status = 304, description = "The copy held is still current"),
+    ),
+    security(("bearer" = [])),
+)]
 pub async fn get_notification(
     State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
     Path(uuid): Path<String>,
-) -> Result<Json<NotificationResponse>, ApplicationError> {
-    let notification = state.notification_api.notification_service.get_notification(uuid).await?;
-    Ok(Json(notification.into()))
+    presentation: Presentation,
+    validators: CacheValidators,
+) -> Result<Conditional<VersionedJson>, ApplicationError> {
+    let notification = state.notification_api.notification_service.get_notification_of(uuid, recipient).await?;
+    let validators = if presentation.is_customised() { validators.untagged() } else { validators };
+    Ok(Conditional(VersionedJson(presentation.present(notification.into())), validators))
 }
 
+#[utoipa::path(
+    get,
+    path = "/notification",
+    tag = "legacy",
+    operation_id = "legacy_get_notifications",
+    summary = "List notifications",
+    params(ListNotificationsQuery, Presentation, AcceptedLanguages, CacheValidators),
+    responses(
+        (status = 200, description = "The notifications, pinned ones first, then oldest first", body = Vec<NotificationResponse>, headers(
+            ("ETag" = String, description = "Weak tag of the listing, left out when `age` is asked for"),
+        )),
+        (status = 304, description = "The listing held is still current"),
+    ),
+    security(("bearer" = [])),
+)]
 pub async fn get_notifications(
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<NotificationResponse>>, ApplicationError> {
-    let notifications = state.notification_api.notification_service.list_notifications().await?;
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    AcceptedLanguages(locales): AcceptedLanguages,
+    Query(query): Query<ListNotificationsQuery>,
+    presentation: Presentation,
+    validators: CacheValidators,
+) -> Result<Conditional<Json<Vec<NotificationResponse>>>, ApplicationError> {
+    let filter = NotificationFilter {
+        archived: query.archived.or(query.updated_since.is_none().then_some(false)),
+        pinned_only: query.pinned,
+        tag: query.tag,
+        updated_since: query.updated_since,
+    };
+    let notifications = state.notification_api.notification_service
+        .list_notifications(recipient, filter, locales)
+        .await?;
+    let validators = if presentation.changes_over_time() { validators.untagged() } else { validators };
     let notifications_response: Vec<NotificationResponse> = notifications.iter()
-        .map(move |notification| { notification.clone().into() }).collect();
+        .map(move |notification| { presentation.present(notification.clone().into()) }).collect();
+
+    Ok(Conditional(Json(notifications_response), validators))
+}
+
+#[utoipa::path(
+    get,
+    path = "/notification/group",
+    tag = "legacy",
+    operation_id = "legacy_get_notification_groups",
+    summary = "List the inbox by group",
+    params(Presentation, AcceptedLanguages),
+    responses((status = 200, description = "The groups, most recent first", body = Vec<NotificationGroupResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_notification_groups(
+    State(state): State<Arc<AppState>>,
+    AcceptedLanguages(locales): AcceptedLanguages,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    presentation: Presentation,
+) -> Result<Json<Vec<NotificationGroupResponse>>, ApplicationError> {
+    let groups = state.notification_api.notification_service.list_notification_groups(recipient, locales).await?;
+    Ok(Json(groups.into_iter()
+        .map(|group| {
+            let group: NotificationGroupResponse = group.into();
+            NotificationGroupResponse { latest: presentation.present(group.latest), ..group }
+        })
+        .collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/notification/search",
+    tag = "legacy",
+    operation_id = "legacy_search_notifications",
+    summary = "Search the recipient's notifications",
+    params(SearchNotificationsQuery, Presentation),
+    responses(
+        (status = 200, description = "The matches, most relevant first", body = Vec<NotificationSearchHitResponse>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn search_notifications(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    Query(query): Query<SearchNotificationsQuery>,
+    presentation: Presentation,
+) -> Result<Json<Vec<NotificationSearchHitResponse>>, ApplicationError> {
+    let filter = NotificationFilter {
+        archived: query.archived,
+        pinned_only: query.pinned,
+        tag: query.tag,
+        updated_since: None,
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_SEARCH_LIMIT).clamp(1, MAX_SEARCH_LIMIT);
+    let hits = state.notification_api.notification_service
+        .search_notifications(recipient, query.q, filter, limit)
+        .await?;
+    Ok(Json(hits.into_iter()
+        .map(|hit| {
+            let hit: NotificationSearchHitResponse = hit.into();
+            NotificationSearchHitResponse { notification: presentation.present(hit.notification), ..hit }
+        })
+        .collect()))
+}
 
-    Ok(Json(notifications_response.into()))
+#[utoipa::path(
+    get,
+    path = "/notification/tags",
+    tag = "legacy",
+    operation_id = "legacy_get_tag_counts",
+    summary = "Count the tags of the inbox",
+    responses((status = 200, description = "The tags, most used first", body = Vec<TagCountResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_tag_counts(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<Vec<TagCountResponse>>, ApplicationError> {
+    let tag_counts = state.notification_api.notification_service.count_tags(recipient).await?;
+    Ok(Json(tag_counts.into_iter().map(|tag_count| tag_count.into()).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/notification/unread",
+    tag = "legacy",
+    operation_id = "legacy_get_unread_count",
+    summary = "Count the unseen notifications of the inbox",
+    responses((status = 200, description = "The unread count", body = UnreadCountResponse)),
+    security(("bearer" = [])),
+)]
+pub async fn get_unread_count(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<UnreadCountResponse>, ApplicationError> {
+    let unread = state.notification_api.notification_service.count_unread(recipient).await?;
+    Ok(Json(UnreadCountResponse { unread }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/notification/seen/{uuid}",
+    tag = "legacy",
+    operation_id = "legacy_mark_as_seen",
+    summary = "Mark a notification as seen",
+    params(("uuid" = String, Path, description = "Notification id"), IfMatch),
+    responses((status = 200, description = "The notification, and whether it changed", body = StateChangeResponse)),
+    security(("bearer" = [])),
+)]
 pub async fn mark_as_seen(
     State(state): State<Arc<AppState>>,
     Path(uuid): Path<String>,
-) -> Result<Json<bool>, ApplicationError> {
-    let result = state.notification_api.notification_service.mark_as_seen(uuid).await?;
-    Ok(Json(result))
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<StateChangeResponse, ApplicationError> {
+    let state_change = state.notification_api.notification_service
+        .mark_as_seen(uuid, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(state_change.into())
 }
 
+#[utoipa::path(
+    post,
+    path = "/notification/deleted/{uuid}",
+    tag = "legacy",
+    operation_id = "legacy_mark_as_deleted",
+    summary = "Mark a notification as deleted",
+    params(("uuid" = String, Path, description = "Notification id"), IfMatch),
+    responses((status = 200, description = "The notification, and whether it changed", body = StateChangeResponse)),
+    security(("bearer" = [])),
+)]
 pub async fn mark_as_deleted(
     State(state): State<Arc<AppState>>,
     Path(uuid): Path<String>,
-) -> Result<Json<bool>, ApplicationError> {
-    let result = state.notification_api.notification_service.mark_as_deleted(uuid).await?;
-    Ok(Json(result))
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<StateChangeResponse, ApplicationError> {
+    let state_change = state.notification_api.notification_service
+        .mark_as_deleted(uuid, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(state_change.into())
+}
+
+#[utoipa::path(
+    get,
+    path = "/notification/{uuid}/history",
+    tag = "legacy",
+    operation_id = "legacy_get_notification_history",
+    summary = "Get the history of a notification",
+    params(("uuid" = String, Path, description = "Notification id")),
+    responses((status = 200, description = "The changes, oldest first", body = Vec<NotificationHistoryResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_notification_history(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<Vec<NotificationHistoryResponse>>, ApplicationError> {
+    let history = state.notification_api.notification_service.get_history(uuid, recipient).await?;
+    Ok(Json(history.into_iter().map(|entry| entry.into()).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/notification/{uuid}/actions/{action_id}",
+    tag = "legacy",
+    operation_id = "legacy_take_action",
+    summary = "Take an action of a notification",
+    params(
+        ("uuid" = String, Path, description = "Notification id"),
+        ("action_id" = String, Path, description = "Action id"),
+        IfMatch,
+    ),
+    responses(
+        (status = 200, description = "The notification", body = NotificationResponse, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn take_action(
+    State(state): State<Arc<AppState>>,
+    Path((uuid, action_id)): Path<(String, String)>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<VersionedJson, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .take_action(uuid, action_id, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(VersionedJson(notification.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/notification/{uuid}/snooze",
+    tag = "legacy",
+    operation_id = "legacy_snooze",
+    summary = "Snooze a notification",
+    params(("uuid" = String, Path, description = "Notification id"), IfMatch),
+    request_body = SnoozeNotificationRequest,
+    responses(
+        (status = 200, description = "The notification", body = NotificationResponse, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn snooze(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+    Json(payload): Json<SnoozeNotificationRequest>,
+) -> Result<VersionedJson, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .snooze(uuid, payload.until, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(VersionedJson(notification.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/notification/{uuid}/tags/{tag}",
+    tag = "legacy",
+    operation_id = "legacy_add_tag",
+    summary = "Tag a notification",
+    params(
+        ("uuid" = String, Path, description = "Notification id"),
+        ("tag" = String, Path, description = "Tag"),
+        IfMatch,
+    ),
+    responses(
+        (status = 200, description = "The notification", body = NotificationResponse, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn add_tag(
+    State(state): State<Arc<AppState>>,
+    Path((uuid, tag)): Path<(String, String)>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<VersionedJson, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .add_tag(uuid, tag, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(VersionedJson(notification.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/notification/{uuid}/tags/{tag}",
+    tag = "legacy",
+    operation_id = "legacy_remove_tag",
+    summary = "Untag a notification",
+    params(
+        ("uuid" = String, Path, description = "Notification id"),
+        ("tag" = String, Path, description = "Tag"),
+        IfMatch,
+    ),
+    responses(
+        (status = 200, description = "The notification", body = NotificationResponse, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn remove_tag(
+    State(state): State<Arc<AppState>>,
+    Path((uuid, tag)): Path<(String, String)>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<VersionedJson, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .remove_tag(uuid, tag, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(VersionedJson(notification.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/notification/pinned/{uuid}",
+    tag = "legacy",
+    operation_id = "legacy_pin",
+    summary = "Pin a notification",
+    params(("uuid" = String, Path, description = "Notification id"), IfMatch),
+    responses(
+        (status = 200, description = "The notification", body = NotificationResponse, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn pin(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<VersionedJson, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .set_pinned(uuid, true, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(VersionedJson(notification.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/notification/pinned/{uuid}",
+    tag = "legacy",
+    operation_id = "legacy_unpin",
+    summary = "Unpin a notification",
+    params(("uuid" = String, Path, description = "Notification id"), IfMatch),
+    responses(
+        (status = 200, description = "The notification", body = NotificationResponse, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn unpin(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<VersionedJson, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .set_pinned(uuid, false, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(VersionedJson(notification.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/notification/archived/{uuid}",
+    tag = "legacy",
+    operation_id = "legacy_archive",
+    summary = "Archive a notification",
+    params(("uuid" = String, Path, description = "Notification id"), IfMatch),
+    responses(
+        (status = 200, description = "The notification", body = NotificationResponse, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn archive(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<VersionedJson, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .set_archived(uuid, true, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(VersionedJson(notification.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/notification/archived/{uuid}",
+    tag = "legacy",
+    operation_id = "legacy_unarchive",
+    summary = "Unarchive a notification",
+    params(("uuid" = String, Path, description = "Notification id"), IfMatch),
+    responses(
+        (status = 200, description = "The notification", body = NotificationResponse, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn unarchive(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<VersionedJson, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .set_archived(uuid, false, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(VersionedJson(notification.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/notification/group/seen/{group_key}",
+    tag = "legacy",
+    operation_id = "legacy_mark_group_as_seen",
+    summary = "Mark a group as seen",
+    params(("group_key" = String, Path, description = "Group key")),
+    responses((status = 200, description = "How many notifications changed", body = usize)),
+    security(("bearer" = [])),
+)]
+pub async fn mark_group_as_seen(
+    State(state): State<Arc<AppState>>,
+    Path(group_key): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<usize>, ApplicationError> {
+    let marked = state.notification_api.notification_service
+        .mark_group_as_seen(recipient, group_key, ChangeSource::Rest)
+        .await?;
+    Ok(Json(marked))
+}
+
+#[utoipa::path(
+    post,
+    path = "/notification/group/deleted/{group_key}",
+    tag = "legacy",
+    operation_id = "legacy_mark_group_as_deleted",
+    summary = "Mark a group as deleted",
+    params(("group_key" = String, Path, description = "Group key")),
+    responses((status = 200, description = "How many notifications changed", body = usize)),
+    security(("bearer" = [])),
+)]
+pub async fn mark_group_as_deleted(
+    State(state): State<Arc<AppState>>,
+    Path(group_key): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<usize>, ApplicationError> {
+    let marked = state.notification_api.notification_service
+        .mark_group_as_deleted(recipient, group_key, ChangeSource::Rest)
+        .await?;
+    Ok(Json(marked))
+}
+
+fn legacy_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(create_notification, get_notifications))
+        .routes(routes!(create_notification_from_template))
+        .routes(routes!(get_notification_groups))
+        .routes(routes!(mark_group_as_seen))
+        .routes(routes!(mark_group_as_deleted))
+        .routes(routes!(search_notifications))
+        .routes(routes!(get_tag_counts))
+        .routes(routes!(get_unread_count))
+        .routes(routes!(get_notification))
+        .routes(routes!(take_action))
+        .routes(routes!(get_notification_history))
+        .routes(routes!(snooze))
+        .routes(routes!(add_tag, remove_tag))
+        .routes(routes!(mark_as_seen))
+        .routes(routes!(mark_as_deleted))
+        .routes(routes!(pin, unpin))
+        .routes(routes!(archive, unarchive))
+}
+
+/// Every route of the API, along with the document describing them.
+pub fn api_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::with_openapi(ApiDoc::openapi())
+        .merge(deprecated(legacy_routes()))
+        .merge(notification_routes())
+        .merge(broadcast_routes())
+        .merge(notification_socket_routes())
+        .merge(notification_stream_routes())
+        .merge(notification_template_routes())
+        .merge(recipient_profile_routes())
+        .merge(topic_routes())
+        .merge(webhook_routes())
 }
 
 pub fn create_router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route("/notification", post(create_notification).get(get_notifications))
-        .route("/notification/{uuid}", get(get_notification))
-        .route("/notification/seen/{uuid}", post(mark_as_seen))
-        .route("/notification/deleted/{uuid}", post(mark_as_deleted))
+    let (router, openapi) = api_routes().split_for_parts();
+    router
+        .merge(docs_routes(openapi::complete(openapi)))
         .with_state(state)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapter::incoming::rest::accept_language::AcceptedLanguages;
     use crate::adapter::incoming::rest::notification_api::NotificationApi;
     use crate::adapter::incoming::rest::request::create_notification::CreateNotificationRequest;
+    use crate::application::command::create_notification_command::CreateNotificationCommand;
+    use crate::application::command::create_notification_from_template_command::CreateNotificationFromTemplateCommand;
     use crate::application::configuration::application_state::AppState;
     use crate::application::service::notification_service::MockNotificationServicePort;
-    use crate::domain::model::notification::Notification;
+    use crate::domain::model::broadcast::Broadcast;
+    use crate::domain::model::notification::{Notification, NotificationKind};
+    use crate::domain::model::notification_action::ActionTaken;
+    use crate::domain::model::notification_event::NotificationEventType;
+    use crate::domain::model::notification_group::NotificationGroup;
+    use crate::domain::model::notification_history::{NotificationHistoryEntry, NotificationState};
+    use crate::domain::model::notification_search::SearchHit;
+    use crate::domain::model::priority::Priority;
+    use crate::domain::model::state_change::StateChange;
+    use crate::domain::model::tag_count::TagCount;
     use axum::extract::State;
+    use axum::http::header::{ETAG, LAST_MODIFIED};
+    use axum::response::IntoResponse;
     use axum::Json;
+    use chrono::Utc;
     use mockall::predicate::eq;
+    use std::collections::HashMap;
     use std::sync::Arc;
     use uuid::Uuid;
 
+    fn app_state(mock_service: MockNotificationServicePort) -> Arc<AppState> {
+        Arc::new(AppState {
+            notification_api: Arc::new(NotificationApi::new(Arc::new(mock_service), "unassigned".to_string())),
+            ..AppState::mocked()
+        })
+    }
+
+    fn recipient() -> AuthenticatedRecipient {
+        AuthenticatedRecipient("recipient".to_string())
+    }
+
+    fn list_query() -> ListNotificationsQuery {
+        ListNotificationsQuery { archived: None, pinned: false, tag: None, updated_since: None }
+    }
+
     #[tokio::test]
     async fn test_create_notification_success() {
         let mut mock_service = MockNotificationServicePort::new();
-        let notification = Notification::new("test message".to_string());
+        let notification = Notification::new("recipient".to_string(), "test message".to_string());
         let request = CreateNotificationRequest {
+            recipient: Some("recipient".to_string()),
             message: "test message".to_string(),
+            category: Some("marketing".to_string()),
+            priority: Priority::Normal,
+            group_key: None,
+            actor: None,
+            actions: vec![],
+            auto_resolve: false,
+            tags: vec![],
         };
 
         mock_service
             .expect_create_notification()
-            .with(eq("test message".to_string()))
+            .with(eq(CreateNotificationCommand {
+                recipient: "recipient".to_string(),
+                message: "test message".to_string(),
+                category: Some("marketing".to_string()),
+                priority: Priority::Normal,
+                group_key: None,
+                actor: None,
+                actions: vec![],
+                auto_resolve: false,
+                tags: vec![],
+            }))
             .returning(move |_| Ok(notification.clone()));
 
-        let app_state = Arc::new(AppState {
-            notification_api: Arc::new(NotificationApi::new(Arc::new(mock_service))),
-        });
+        let app_state = app_state(mock_service);
 
         let result = create_notification(State(app_state), Json(request)).await;
 
@@ -106,25 +702,123 @@ mod tests {
         assert_eq!(result.unwrap().0.message, "test message");
     }
 
+    #[tokio::test]
+    async fn test_create_notification_without_recipient_uses_default() {
+        let mut mock_service = MockNotificationServicePort::new();
+        let request: CreateNotificationRequest = serde_json::from_str(r#"{ "message": "test message" }"#).unwrap();
+
+        mock_service
+            .expect_create_notification()
+            .withf(|command| command.recipient == "unassigned" && command.message == "test message")
+            .times(1)
+            .returning(|command| Ok(Notification::new(command.recipient, command.message)));
+
+        let result = create_notification(State(app_state(mock_service)), Json(request)).await;
+
+        assert_eq!(result.unwrap().0.recipient, "unassigned");
+    }
+
+    #[tokio::test]
+    async fn test_create_notification_from_template_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        let notification = Notification {
+            title: Some("Order 42".to_string()),
+            ..Notification::new("recipient".to_string(), "shipped".to_string())
+        };
+        let request = CreateNotificationFromTemplateRequest {
+            recipient: "recipient".to_string(),
+            template_id: "order_shipped".to_string(),
+            channel: None,
+            category: None,
+            priority: Priority::Normal,
+            group_key: None,
+            actor: None,
+            actions: vec![],
+            auto_resolve: false,
+            tags: vec![],
+            variables: HashMap::from([("order".to_string(), "42".to_string())]),
+        };
+
+        mock_service
+            .expect_create_notification_from_template()
+            .with(eq(CreateNotificationFromTemplateCommand {
+                recipient: "recipient".to_string(),
+                template_id: "order_shipped".to_string(),
+                channel: None,
+                category: None,
+                priority: Priority::Normal,
+                group_key: None,
+                actor: None,
+                actions: vec![],
+                auto_resolve: false,
+                tags: vec![],
+                variables: HashMap::from([("order".to_string(), "42".to_string())]),
+            }))
+            .returning(move |_| Ok(notification.clone()));
+
+        let result = create_notification_from_template(State(app_state(mock_service)), Json(request)).await;
+
+        assert_eq!(result.unwrap().0.title, Some("Order 42".to_string()));
+    }
+
     #[tokio::test]
     async fn test_get_notification_success() {
         let mut mock_service = MockNotificationServicePort::new();
         let uuid = Uuid::new_v4().to_string();
-        let notification = Notification::new("existing message".to_string());
+        let notification = Notification::new("recipient".to_string(), "existing message".to_string());
 
         mock_service
-            .expect_get_notification()
-            .with(eq(uuid.clone()))
-            .returning(move |_| Ok(notification.clone()));
+            .expect_get_notification_of()
+            .with(eq(uuid.clone()), eq("recipient".to_string()))
+            .returning(move |_, _| Ok(notification.clone()));
 
-        let app_state = Arc::new(AppState {
-            notification_api: Arc::new(NotificationApi::new(Arc::new(mock_service))),
-        });
+        let app_state = app_state(mock_service);
 
-        let result = get_notification(State(app_state), Path(uuid)).await;
+        let result = get_notification(State(app_state), recipient(), Path(uuid), Presentation::default(), CacheValidators::default()).await;
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap().0.message, "existing message");
+        assert_eq!(result.unwrap().0.0.message, "existing message");
+    }
+
+    #[tokio::test]
+    async fn test_get_notification_in_time_zone_untagged() {
+        let mut mock_service = MockNotificationServicePort::new();
+        let notification = Notification::new("recipient".to_string(), "existing message".to_string());
+        let uuid = notification.uuid.to_string();
+
+        mock_service
+            .expect_get_notification_of()
+            .returning(move |_, _| Ok(notification.clone()));
+
+        let app_state = app_state(mock_service);
+        let in_time_zone = Query::<Presentation>::try_from_uri(&"/?tz=Europe/Lisbon".parse().unwrap()).unwrap().0;
+
+        let plain = get_notification(State(app_state.clone()), recipient(), Path(uuid.clone()), Presentation::default(), CacheValidators::default())
+            .await.unwrap().into_response();
+        let customised = get_notification(State(app_state), recipient(), Path(uuid), in_time_zone, CacheValidators::default())
+            .await.unwrap().into_response();
+
+        assert!(plain.headers().contains_key(ETAG));
+        assert!(!customised.headers().contains_key(ETAG));
+        assert!(!customised.headers().contains_key(LAST_MODIFIED));
+    }
+
+    #[tokio::test]
+    async fn test_get_notification_of_other_recipient_not_found() {
+        let mut mock_service = MockNotificationServicePort::new();
+        let uuid = Uuid::new_v4().to_string();
+
+        mock_service
+            .expect_get_notification_of()
+            .with(eq(uuid.clone()), eq("someone else".to_string()))
+            .returning(|uuid, _| Err(ApplicationError::NotFound(uuid)));
+
+        let app_state = app_state(mock_service);
+
+        let someone_else = AuthenticatedRecipient("someone else".to_string());
+        let result = get_notification(State(app_state), someone_else, Path(uuid), Presentation::default(), CacheValidators::default()).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
     }
 
     #[tokio::test]
@@ -132,21 +826,21 @@ mod tests {
         let mut mock_service = MockNotificationServicePort::new();
         mock_service
             .expect_list_notifications()
-            .returning(move || Ok(
+            .with(eq("recipient".to_string()), eq(NotificationFilter::default()), eq(vec!["pt-BR".to_string(), "en".to_string()]))
+            .returning(move |_, _, _| Ok(
                 vec![
-                    Notification::new("message 1".to_string()),
-                    Notification::new("message 2".to_string()),
+                    Notification::new("recipient".to_string(), "message 1".to_string()),
+                    Notification::new("recipient".to_string(), "message 2".to_string()),
                 ]
             ));
 
-        let app_state = Arc::new(AppState {
-            notification_api: Arc::new(NotificationApi::new(Arc::new(mock_service))),
-        });
+        let app_state = app_state(mock_service);
 
-        let result = get_notifications(State(app_state)).await;
+        let accepted_languages = AcceptedLanguages(vec!["pt-BR".to_string(), "en".to_string()]);
+        let result = get_notifications(State(app_state), recipient(), accepted_languages, Query(list_query()), Presentation::default(), CacheValidators::default()).await;
 
         assert!(result.is_ok());
-        let response = result.unwrap().0;
+        let response = result.unwrap().0.0;
         assert_eq!(response.len(), 2);
         assert_eq!(response[0].message, "message 1");
         assert_eq!(response[1].message, "message 2");
@@ -157,55 +851,414 @@ mod tests {
         let mut mock_service = MockNotificationServicePort::new();
         mock_service
             .expect_list_notifications()
-            .returning(move || Ok(vec![]));
+            .returning(move |_, _, _| Ok(vec![]));
 
-        let app_state = Arc::new(AppState {
-            notification_api: Arc::new(NotificationApi::new(Arc::new(mock_service))),
-        });
+        let app_state = app_state(mock_service);
 
-        let result = get_notifications(State(app_state)).await;
+        let result = get_notifications(State(app_state), recipient(), AcceptedLanguages::default(), Query(list_query()), Presentation::default(), CacheValidators::default()).await;
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap().0.len(), 0);
+        assert_eq!(result.unwrap().0.0.len(), 0);
     }
 
     #[tokio::test]
     async fn test_mark_as_seen_success() {
         let mut mock_service = MockNotificationServicePort::new();
-        let uuid = Uuid::new_v4().to_string();
+        let notification = Notification { seen: true, version: 2, ..Notification::new("recipient".to_string(), "message".to_string()) };
+        let uuid = notification.uuid.to_string();
 
         mock_service
             .expect_mark_as_seen()
-            .with(eq(uuid.clone()))
-            .returning(move |_| Ok(true));
+            .with(eq(uuid.clone()), eq("recipient".to_string()), eq(None), eq(ChangeSource::Rest))
+            .returning(move |_, _, _, _| Ok(StateChange { notification: notification.clone(), changed: true }));
 
-        let app_state = Arc::new(AppState {
-            notification_api: Arc::new(NotificationApi::new(Arc::new(mock_service))),
-        });
+        let app_state = app_state(mock_service);
 
-        let result = mark_as_seen(State(app_state), Path(uuid)).await;
+        let result = mark_as_seen(State(app_state), Path(uuid), recipient(), IfMatch(None)).await;
 
-        assert!(result.is_ok());
-        assert!(result.unwrap().0);
+        let result = result.unwrap();
+        assert!(result.changed);
+        assert!(result.notification.seen);
+        assert_eq!(result.notification.version, 2);
     }
 
     #[tokio::test]
     async fn test_mark_as_deleted_success() {
         let mut mock_service = MockNotificationServicePort::new();
-        let uuid = Uuid::new_v4().to_string();
+        let mut notification = Notification { version: 1, ..Notification::new("recipient".to_string(), "message".to_string()) };
+        notification.set_as_deleted();
+        let uuid = notification.uuid.to_string();
 
         mock_service
             .expect_mark_as_deleted()
-            .with(eq(uuid.clone()))
-            .returning(move |_| Ok(true));
+            .with(eq(uuid.clone()), eq("recipient".to_string()), eq(None), eq(ChangeSource::Rest))
+            .times(1)
+            .returning(move |_, _, _, _| Ok(StateChange { notification: notification.clone(), changed: true }));
 
-        let app_state = Arc::new(AppState {
-            notification_api: Arc::new(NotificationApi::new(Arc::new(mock_service))),
-        });
+        let app_state = app_state(mock_service);
 
-        let result = mark_as_deleted(State(app_state), Path(uuid)).await;
+        let result = mark_as_deleted(State(app_state), Path(uuid), recipient(), IfMatch(None)).await;
 
-        assert!(result.is_ok());
-        assert!(result.unwrap().0);
+        let result = result.unwrap();
+        assert!(result.changed);
+        assert_eq!(result.notification.recipient, "recipient");
+        assert_eq!(result.notification.message, "message");
+        assert!(result.notification.deleted);
+        assert!(result.notification.deleted_at.is_some());
+        assert_eq!(result.notification.version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_mark_as_deleted_again_unchanged() {
+        let mut mock_service = MockNotificationServicePort::new();
+        let mut notification = Notification::new("recipient".to_string(), "message".to_string());
+        notification.set_as_deleted();
+        let uuid = notification.uuid.to_string();
+
+        mock_service
+            .expect_mark_as_deleted()
+            .with(eq(uuid.clone()), eq("recipient".to_string()), eq(None), eq(ChangeSource::Rest))
+            .returning(move |_, _, _, _| Ok(StateChange { notification: notification.clone(), changed: false }));
+
+        let app_state = app_state(mock_service);
+
+        let result = mark_as_deleted(State(app_state), Path(uuid), recipient(), IfMatch(None)).await;
+
+        let result = result.unwrap();
+        assert!(!result.changed);
+        assert!(result.notification.deleted);
+    }
+
+    #[tokio::test]
+    async fn test_get_notifications_of_recipient_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_list_notifications()
+            .with(eq("recipient".to_string()), eq(NotificationFilter::default()), eq(vec![]))
+            .returning(|recipient, _, _| Ok(vec![
+                Broadcast::new(None, "announcement".to_string()).to_notification(recipient, None),
+            ]));
+
+        let query = list_query();
+        let response = get_notifications(State(app_state(mock_service)), recipient(), AcceptedLanguages::default(), Query(query), Presentation::default(), CacheValidators::default())
+            .await
+            .unwrap()
+            .0.0;
+
+        assert_eq!(response[0].kind, NotificationKind::Broadcast);
+        assert_eq!(response[0].recipient, "recipient");
+    }
+
+    #[tokio::test]
+    async fn test_get_unread_count_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_count_unread()
+            .with(eq("recipient".to_string()))
+            .returning(|_| Ok(3));
+
+        let result = get_unread_count(State(app_state(mock_service)), recipient()).await;
+
+        assert_eq!(result.unwrap().0.unread, 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_notification_groups_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_list_notification_groups()
+            .with(eq("recipient".to_string()), eq(vec![]))
+            .returning(|recipient, _| {
+                let comment = |actor: &str| Notification {
+                    group_key: Some("post-42".to_string()),
+                    actor: Some(actor.to_string()),
+                    ..Notification::new(recipient.clone(), format!("{} commented on your post", actor))
+                };
+                Ok(NotificationGroup::group(vec![comment("ana"), comment("bob")]))
+            });
+
+        let response = get_notification_groups(State(app_state(mock_service)), AcceptedLanguages::default(), recipient(), Presentation::default())
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].group_key, Some("post-42".to_string()));
+        assert_eq!(response[0].count, 2);
+        assert_eq!(response[0].latest.message, "bob commented on your post");
+        assert_eq!(response[0].actors, vec!["bob".to_string(), "ana".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_mark_group_as_seen_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_mark_group_as_seen()
+            .with(eq("recipient".to_string()), eq("post-42".to_string()), eq(ChangeSource::Rest))
+            .returning(|_, _, _| Ok(2));
+
+        let result = mark_group_as_seen(State(app_state(mock_service)), Path("post-42".to_string()), recipient()).await;
+
+        assert_eq!(result.unwrap().0, 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_notification_history_success() {
+        let uuid = Uuid::new_v4().to_string();
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_get_history()
+            .with(eq(uuid.clone()), eq("recipient".to_string()))
+            .returning(|_, recipient| {
+                let mut notification = Notification::new(recipient.clone(), "message".to_string());
+                let from_state = NotificationState::from(&notification);
+                notification.set_as_seen();
+                Ok(vec![NotificationHistoryEntry::new(
+                    notification.uuid, NotificationEventType::Seen, Some(recipient), ChangeSource::WebSocket,
+                    from_state, NotificationState::from(&notification),
+                )])
+            });
+
+        let response = get_notification_history(State(app_state(mock_service)), Path(uuid), recipient())
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].event, NotificationEventType::Seen);
+        assert_eq!(response[0].source, ChangeSource::WebSocket);
+        assert!(!response[0].from_state.seen && response[0].to_state.seen);
+    }
+
+    #[tokio::test]
+    async fn test_take_action_success() {
+        let uuid = Uuid::new_v4().to_string();
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_take_action()
+            .with(eq(uuid.clone()), eq("approve".to_string()), eq("recipient".to_string()), eq(None), eq(ChangeSource::Rest))
+            .returning(|_, action_id, recipient, _, _| Ok(Notification {
+                action_taken: Some(ActionTaken { action_id, date_taken: Utc::now() }),
+                ..Notification::new(recipient, "Approve the request?".to_string())
+            }));
+
+        let response = take_action(State(app_state(mock_service)), Path((uuid, "approve".to_string())), recipient(), IfMatch(None))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.action_taken.map(|taken| taken.action_id), Some("approve".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_snooze_success() {
+        let uuid = Uuid::new_v4().to_string();
+        let until = Utc::now() + chrono::Duration::hours(1);
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_snooze()
+            .with(eq(uuid.clone()), eq(until), eq("recipient".to_string()), eq(None), eq(ChangeSource::Rest))
+            .returning(|_, until, _, _, _| {
+                let mut notification = Notification::new("recipient".to_string(), "message".to_string());
+                notification.snooze(until);
+                Ok(notification)
+            });
+
+        let request = SnoozeNotificationRequest { until };
+        let response = snooze(State(app_state(mock_service)), Path(uuid), recipient(), IfMatch(None), Json(request))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.snoozed_until, Some(until.into()));
+    }
+
+    #[tokio::test]
+    async fn test_get_archived_notifications_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_list_notifications()
+            .with(eq("recipient".to_string()), eq(NotificationFilter { archived: Some(true), ..NotificationFilter::default() }), eq(vec![]))
+            .returning(|recipient, _, _| {
+                let mut notification = Notification::new(recipient, "archived".to_string());
+                notification.set_archived(true);
+                Ok(vec![notification])
+            });
+
+        let query = ListNotificationsQuery { archived: Some(true), ..list_query() };
+        let response = get_notifications(State(app_state(mock_service)), recipient(), AcceptedLanguages::default(), Query(query), Presentation::default(), CacheValidators::default())
+            .await
+            .unwrap()
+            .0.0;
+
+        assert!(response[0].archived);
+    }
+
+    #[tokio::test]
+    async fn test_get_notifications_updated_since_success() {
+        let since = Utc::now();
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_list_notifications()
+            .with(
+                eq("recipient".to_string()),
+                eq(NotificationFilter { archived: None, updated_since: Some(since), ..NotificationFilter::default() }),
+                eq(vec![]),
+            )
+            .returning(|_, _, _| Ok(vec![]));
+
+        let query = ListNotificationsQuery { updated_since: Some(since), ..list_query() };
+        let response = get_notifications(State(app_state(mock_service)), recipient(), AcceptedLanguages::default(), Query(query), Presentation::default(), CacheValidators::default())
+            .await
+            .unwrap()
+            .0.0;
+
+        assert!(response.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_notifications_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_search_notifications()
+            .with(
+                eq("recipient".to_string()),
+                eq("invoice".to_string()),
+                eq(NotificationFilter { archived: None, ..NotificationFilter::default() }),
+                eq(MAX_SEARCH_LIMIT),
+            )
+            .returning(|recipient, _, _, _| Ok(vec![SearchHit {
+                notification: Notification::new(recipient, "Your invoice was paid".to_string()),
+                rank: 1.0,
+                snippet: "Your <mark>invoice</mark> was paid".to_string(),
+            }]));
+
+        let query = SearchNotificationsQuery {
+            q: "invoice".to_string(),
+            archived: None,
+            pinned: false,
+            tag: None,
+            limit: Some(1_000),
+        };
+        let response = search_notifications(State(app_state(mock_service)), recipient(), Query(query), Presentation::default()).await.unwrap().0;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].snippet, "Your <mark>invoice</mark> was paid");
+        assert_eq!(response[0].notification.message, "Your invoice was paid");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_get_notifications_with_tag_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_list_notifications()
+            .with(
+                eq("recipient".to_string()),
+                eq(NotificationFilter { tag: Some("billing".to_string()), ..NotificationFilter::default() }),
+                eq(vec![]),
+            )
+            .returning(|_, _, _| Ok(vec![]));
+
+        let query = ListNotificationsQuery { tag: Some("billing".to_string()), ..list_query() };
+        let result = get_notifications(State(app_state(mock_service)), recipient(), AcceptedLanguages::default(), Query(query), Presentation::default(), CacheValidators::default()).await;
+
+        assert!(result.unwrap().0.0.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_tag_success() {
+        let uuid = Uuid::new_v4().to_string();
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_add_tag()
+            .with(eq(uuid.clone()), eq("billing".to_string()), eq("recipient".to_string()), eq(None), eq(ChangeSource::Rest))
+            .returning(|_, tag, recipient, _, _| {
+                let mut notification = Notification::new(recipient, "message".to_string());
+                notification.add_tag(tag);
+                Ok(notification)
+            });
+
+        let response = add_tag(State(app_state(mock_service)), Path((uuid, "billing".to_string())), recipient(), IfMatch(None))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.tags, vec!["billing".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_get_tag_counts_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_count_tags()
+            .with(eq("recipient".to_string()))
+            .returning(|_| Ok(vec![TagCount { tag: "billing".to_string(), count: 2 }]));
+
+        let response = get_tag_counts(State(app_state(mock_service)), recipient()).await.unwrap().0;
+
+        assert_eq!(response[0].tag, "billing");
+        assert_eq!(response[0].count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_pin_success() {
+        let uuid = Uuid::new_v4().to_string();
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_set_pinned()
+            .with(eq(uuid.clone()), eq(true), eq("recipient".to_string()), eq(None), eq(ChangeSource::Rest))
+            .returning(|_, pinned, recipient, _, _| {
+                let mut notification = Notification::new(recipient, "message".to_string());
+                notification.set_pinned(pinned);
+                Ok(notification)
+            });
+
+        let response = pin(State(app_state(mock_service)), Path(uuid), recipient(), IfMatch(None)).await.unwrap().0;
+
+        assert!(response.pinned);
+    }
+
+    #[tokio::test]
+    async fn test_pin_if_match_success() {
+        let uuid = Uuid::new_v4().to_string();
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_set_pinned()
+            .with(eq(uuid.clone()), eq(true), eq("recipient".to_string()), eq(Some(3)), eq(ChangeSource::Rest))
+            .returning(|_, pinned, _, version, _| {
+                let mut notification = Notification::new("recipient".to_string(), "message".to_string());
+                notification.set_pinned(pinned);
+                notification.version = version.unwrap() + 1;
+                Ok(notification)
+            });
+
+        let response = pin(State(app_state(mock_service)), Path(uuid), recipient(), IfMatch(Some(3)))
+            .await
+            .unwrap()
+            .0;
+
+        assert!(response.pinned);
+        assert_eq!(response.version, 4);
+    }
+
+    #[tokio::test]
+    async fn test_unarchive_success() {
+        let uuid = Uuid::new_v4().to_string();
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service
+            .expect_set_archived()
+            .with(eq(uuid.clone()), eq(false), eq("recipient".to_string()), eq(None), eq(ChangeSource::Rest))
+            .returning(|_, archived, _, _, _| {
+                let mut notification = Notification::new("recipient".to_string(), "message".to_string());
+                notification.set_archived(archived);
+                Ok(notification)
+            });
+
+        let response = unarchive(State(app_state(mock_service)), Path(uuid), recipient(), IfMatch(None))
+            .await
+            .unwrap()
+            .0;
+
+        assert!(!response.archived);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::adapter::incoming::rest::authentication::StreamingRecipient;
+use crate::adapter::incoming::rest::response::notification::NotificationResponse;
+use crate::application::configuration::application_state::AppState;
+use crate::application::service::notification_stream_service::NotificationStreamServicePort;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_stream::{Stream, StreamExt};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+pub struct NotificationStreamApi {
+    notification_stream_service: Arc<dyn NotificationStreamServicePort>,
+}
+
+impl NotificationStreamApi {
+    pub fn new(notification_stream_service: Arc<dyn NotificationStreamServicePort>) -> Self {
+        Self { notification_stream_service }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/notification/stream",
+    tag = "notifications",
+    summary = "Stream the recipient's notification events",
+    params(
+        ("Last-Event-ID" = Option<String>, Header, description = "Id of the last event received, to resume from"),
+        ("access_token" = Option<String>, Query, description = "Token of the recipient, for clients that cannot set headers"),
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Server-sent events, each carrying the notification as data",
+            content_type = "text/event-stream",
+            body = NotificationResponse,
+        ),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn stream_notifications(
+    State(state): State<Arc<AppState>>,
+    StreamingRecipient(recipient): StreamingRecipient,
+    headers: HeaderMap,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let last_event_id = headers.get(LAST_EVENT_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let events = state.notification_stream_api.notification_stream_service
+        .subscribe(recipient, last_event_id)
+        .map(|event| {
+            let data = serde_json::to_string(&NotificationResponse::from(event.notification))
+                .expect("Notification response is serializable");
+            Ok(Event::default().id(event.uuid.to_string()).event(event.event_type.as_str()).data(data))
+        });
+
+    Sse::new(events).keep_alive(KeepAlive::new().interval(HEARTBEAT_INTERVAL).text("heartbeat"))
+}
+
+pub fn notification_stream_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(stream_notifications))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::service::notification_stream_service::MockNotificationStreamServicePort;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+    use axum::body::to_bytes;
+    use axum::response::IntoResponse;
+    use mockall::predicate::eq;
+
+    #[tokio::test]
+    async fn test_stream_notifications_success() {
+        let event = NotificationEvent::new(
+            NotificationEventType::Seen,
+            Notification::new("recipient".to_string(), "streamed message".to_string()),
+        );
+        let expected_id = event.uuid.to_string();
+
+        let mut mock_service = MockNotificationStreamServicePort::new();
+        mock_service
+            .expect_subscribe()
+            .with(eq("recipient".to_string()), eq(Some("last-event-id".to_string())))
+            .returning(move |_, _| Box::pin(tokio_stream::iter(vec![event.clone()])));
+
+        let app_state = Arc::new(AppState {
+            notification_stream_api: Arc::new(NotificationStreamApi::new(Arc::new(mock_service))),
+            ..AppState::mocked()
+        });
+        let mut headers = HeaderMap::new();
+        headers.insert(LAST_EVENT_ID_HEADER, "last-event-id".parse().unwrap());
+
+        let response = stream_notifications(
+            State(app_state),
+            StreamingRecipient("recipient".to_string()),
+            headers,
+        ).await.into_response();
+
+        let body = String::from_utf8(to_bytes(response.into_body(), usize::MAX).await.unwrap().to_vec()).unwrap();
+        assert!(body.contains("event: seen"));
+        assert!(body.contains(&format!("id: {}", expected_id)));
+        assert!(body.contains("\"message\":\"streamed message\""));
+    }
+}
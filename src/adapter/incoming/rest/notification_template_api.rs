@@ -0,0 +1,252 @@
+use crate::adapter::incoming::rest::request::create_notification_template::CreateNotificationTemplateRequest;
+use crate::adapter::incoming::rest::request::render_notification_template::RenderNotificationTemplateRequest;
+use crate::adapter::incoming::rest::request::set_notification_template_variant::SetNotificationTemplateVariantRequest;
+use crate::adapter::incoming::rest::request::update_notification_template::UpdateNotificationTemplateRequest;
+use crate::adapter::incoming::rest::response::notification_template::NotificationTemplateResponse;
+use crate::adapter::incoming::rest::response::rendered_notification_template::RenderedNotificationTemplateResponse;
+use crate::application::configuration::application_state::AppState;
+use crate::application::error::ApplicationError;
+use crate::application::service::notification_template_service::NotificationTemplateServicePort;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub struct NotificationTemplateApi {
+    template_service: Arc<dyn NotificationTemplateServicePort>,
+}
+
+impl NotificationTemplateApi {
+    pub fn new(template_service: Arc<dyn NotificationTemplateServicePort>) -> Self {
+        Self { template_service }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/template",
+    tag = "templates",
+    summary = "Create a template",
+    request_body = CreateNotificationTemplateRequest,
+    responses((status = 201, description = "The template created", body = NotificationTemplateResponse)),
+)]
+pub async fn create_template(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<CreateNotificationTemplateRequest>,
+) -> Result<(StatusCode, Json<NotificationTemplateResponse>), ApplicationError> {
+    let template = state.notification_template_api.template_service
+        .create_template(payload.id, payload.locale, payload.title, payload.body)
+        .await?;
+    Ok((StatusCode::CREATED, Json(template.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/template/{id}",
+    tag = "templates",
+    summary = "Get a template",
+    params(("id" = String, Path, description = "Template id")),
+    responses((status = 200, description = "The template", body = NotificationTemplateResponse)),
+)]
+pub async fn get_template(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<NotificationTemplateResponse>, ApplicationError> {
+    let template = state.notification_template_api.template_service.get_template(id).await?;
+    Ok(Json(template.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/template",
+    tag = "templates",
+    summary = "List templates",
+    responses((status = 200, description = "The templates", body = Vec<NotificationTemplateResponse>)),
+)]
+pub async fn get_templates(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<NotificationTemplateResponse>>, ApplicationError> {
+    let templates = state.notification_template_api.template_service.list_templates().await?;
+    Ok(Json(templates.into_iter().map(|template| template.into()).collect()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/template/{id}",
+    tag = "templates",
+    summary = "Update a template",
+    params(("id" = String, Path, description = "Template id")),
+    request_body = UpdateNotificationTemplateRequest,
+    responses((status = 200, description = "The template", body = NotificationTemplateResponse)),
+)]
+pub async fn update_template(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(payload): Json<UpdateNotificationTemplateRequest>,
+) -> Result<Json<NotificationTemplateResponse>, ApplicationError> {
+    let template = state.notification_template_api.template_service
+        .update_template(id, payload.locale, payload.title, payload.body)
+        .await?;
+    Ok(Json(template.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/template/{id}",
+    tag = "templates",
+    summary = "Delete a template",
+    params(("id" = String, Path, description = "Template id")),
+    responses((status = 204, description = "The template is deleted")),
+)]
+pub async fn delete_template(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApplicationError> {
+    state.notification_template_api.template_service.delete_template(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/template/{id}/variant/{locale}",
+    tag = "templates",
+    summary = "Set a localised variant of a template",
+    params(
+        ("id" = String, Path, description = "Template id"),
+        ("locale" = String, Path, description = "Locale of the variant"),
+    ),
+    request_body = SetNotificationTemplateVariantRequest,
+    responses((status = 200, description = "The template", body = NotificationTemplateResponse)),
+)]
+pub async fn set_variant(
+    State(state): State<Arc<AppState>>,
+    Path((id, locale)): Path<(String, String)>,
+    Json(payload): Json<SetNotificationTemplateVariantRequest>,
+) -> Result<Json<NotificationTemplateResponse>, ApplicationError> {
+    let template = state.notification_template_api.template_service
+        .set_variant(id, locale, payload.title, payload.body)
+        .await?;
+    Ok(Json(template.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/template/{id}/variant/{locale}",
+    tag = "templates",
+    summary = "Delete a localised variant of a template",
+    params(
+        ("id" = String, Path, description = "Template id"),
+        ("locale" = String, Path, description = "Locale of the variant"),
+    ),
+    responses((status = 200, description = "The template", body = NotificationTemplateResponse)),
+)]
+pub async fn delete_variant(
+    State(state): State<Arc<AppState>>,
+    Path((id, locale)): Path<(String, String)>,
+) -> Result<Json<NotificationTemplateResponse>, ApplicationError> {
+    let template = state.notification_template_api.template_service.delete_variant(id, locale).await?;
+    Ok(Json(template.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/template/{id}/render",
+    tag = "templates",
+    summary = "Render a template",
+    params(("id" = String, Path, description = "Template id")),
+    request_body = RenderNotificationTemplateRequest,
+    responses((status = 200, description = "The template rendered", body = RenderedNotificationTemplateResponse)),
+)]
+pub async fn render_template(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(payload): Json<RenderNotificationTemplateRequest>,
+) -> Result<Json<RenderedNotificationTemplateResponse>, ApplicationError> {
+    let rendered = state.notification_template_api.template_service
+        .render_template(id, payload.variables, payload.format, payload.locale)
+        .await?;
+    Ok(Json(rendered.into()))
+}
+
+pub fn notification_template_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(create_template, get_templates))
+        .routes(routes!(get_template, update_template, delete_template))
+        .routes(routes!(set_variant, delete_variant))
+        .routes(routes!(render_template))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::service::notification_template_service::MockNotificationTemplateServicePort;
+    use crate::domain::model::notification_template::{NotificationTemplate, RenderedTemplate, TemplateFormat};
+    use mockall::predicate::eq;
+    use std::collections::HashMap;
+
+    fn app_state(mock_service: MockNotificationTemplateServicePort) -> Arc<AppState> {
+        Arc::new(AppState {
+            notification_template_api: Arc::new(NotificationTemplateApi::new(Arc::new(mock_service))),
+            ..AppState::mocked()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_template_success() {
+        let mut mock_service = MockNotificationTemplateServicePort::new();
+        mock_service
+            .expect_create_template()
+            .with(eq("welcome".to_string()), eq("en".to_string()), eq("Hi".to_string()), eq("Hello {{name}}".to_string()))
+            .returning(|id, locale, title, body| Ok(NotificationTemplate::new(id, locale, title, body)));
+
+        let request = CreateNotificationTemplateRequest {
+            id: "welcome".to_string(),
+            locale: "en".to_string(),
+            title: "Hi".to_string(),
+            body: "Hello {{name}}".to_string(),
+        };
+
+        let (status, Json(response)) = create_template(State(app_state(mock_service)), Json(request)).await.unwrap();
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(response.id, "welcome");
+        assert_eq!(response.version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_render_template_success() {
+        let mut mock_service = MockNotificationTemplateServicePort::new();
+        mock_service
+            .expect_render_template()
+            .with(eq("welcome".to_string()), eq(HashMap::new()), eq(TemplateFormat::Html), eq(Some("pt".to_string())))
+            .returning(|_, _, _, _| Ok(RenderedTemplate { title: "Hi".to_string(), body: "Hello".to_string() }));
+
+        let request = RenderNotificationTemplateRequest {
+            variables: HashMap::new(),
+            format: TemplateFormat::Html,
+            locale: Some("pt".to_string()),
+        };
+
+        let response = render_template(State(app_state(mock_service)), Path("welcome".to_string()), Json(request))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.title, "Hi");
+        assert_eq!(response.body, "Hello");
+    }
+
+    #[tokio::test]
+    async fn test_delete_template_not_found() {
+        let mut mock_service = MockNotificationTemplateServicePort::new();
+        mock_service
+            .expect_delete_template()
+            .returning(|id| Err(ApplicationError::NotFound(id)));
+
+        let result = delete_template(State(app_state(mock_service)), Path("welcome".to_string())).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::application::error::ErrorResponse;
+use axum::Router;
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::path::{Operation, Parameter, ParameterBuilder, ParameterIn, PathItem};
+use utoipa::openapi::response::ResponseBuilder;
+use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
+use utoipa::openapi::{Ref, Required};
+use utoipa::{OpenApi, PartialSchema};
+use utoipa_swagger_ui::SwaggerUi;
+
+pub const OPENAPI_PATH: &str = "/openapi.json";
+pub const DOCS_PATH: &str = "/docs";
+
+/// Root of the API document; the paths are collected from the routers as they are merged.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Notification Center",
+        description = "Creates notifications and delivers them over REST, server-sent events, WebSocket and webhooks.",
+    ),
+    components(schemas(ErrorResponse)),
+    tags(
+        (name = "notifications", description = "Notifications of a recipient, under `/v1`"),
+        (name = "legacy", description = "Notification routes replaced by `/v1`"),
+        (name = "broadcasts", description = "Notifications sent to every recipient"),
+        (name = "templates", description = "Localised templates notifications are created from"),
+        (name = "topics", description = "Topics recipients subscribe to and are published to"),
+        (name = "profile", description = "Profile and delivery settings of the authenticated recipient"),
+        (name = "webhooks", description = "Subscriptions to notification events, managed by administrators"),
+    ),
+)]
+pub struct ApiDoc;
+
+/// Optional string header read by an extractor.
+pub(crate) fn header_parameter(name: &str, description: &str) -> Parameter {
+    ParameterBuilder::new()
+        .name(name)
+        .parameter_in(ParameterIn::Header)
+        .required(Required::False)
+        .description(Some(description))
+        .schema(Some(String::schema()))
+        .build()
+}
+
+pub(crate) fn operations_mut(path_item: &mut PathItem) -> impl Iterator<Item = &mut Operation> {
+    [
+        &mut path_item.get,
+        &mut path_item.put,
+        &mut path_item.post,
+        &mut path_item.delete,
+        &mut path_item.patch,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Completes the document collected from the routers, whose handlers only describe their successful responses:
+/// every operation may also answer with an [`ErrorResponse`], and the bearer scheme is declared.
+pub fn complete(mut openapi: utoipa::openapi::OpenApi) -> utoipa::openapi::OpenApi {
+    let error = ContentBuilder::new().schema(Some(Ref::from_schema_name("ErrorResponse"))).build();
+    for path_item in openapi.paths.paths.values_mut() {
+        for operation in operations_mut(path_item) {
+            for (status, description) in [("4XX", "The request was refused"), ("5XX", "The request failed")] {
+                let response = ResponseBuilder::new().description(description).content("application/json", error.clone());
+                operation.responses.responses.entry(status.to_string()).or_insert(response.build().into());
+            }
+        }
+    }
+
+    let bearer = Http::builder()
+        .scheme(HttpAuthScheme::Bearer)
+        .description(Some("Token of the recipient; the event stream and socket also accept it as the `access_token` query parameter"))
+        .build();
+    openapi.components.get_or_insert_with(Default::default)
+        .add_security_scheme("bearer", SecurityScheme::Http(bearer));
+    // Filled in from the crate manifest, which declares none.
+    openapi.info.license = None;
+    openapi
+}
+
+/// Serves the document at [`OPENAPI_PATH`] and an interactive documentation of it at [`DOCS_PATH`].
+pub fn docs_routes<S>(openapi: utoipa::openapi::OpenApi) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    SwaggerUi::new(DOCS_PATH).url(OPENAPI_PATH, openapi).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::incoming::rest::notification_api::{api_routes, create_router};
+    use crate::application::configuration::application_state::AppState;
+    use std::sync::Arc;
+    use tokio::net::TcpListener;
+    use utoipa::openapi::Deprecated;
+
+    const COMMITTED_DOCUMENT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/openapi.json");
+
+    #[test]
+    fn test_committed_document_matches_routes() {
+        let generated = complete(api_routes().into_openapi()).to_pretty_json().unwrap() + "\n";
+
+        if std::env::var_os("UPDATE_OPENAPI").is_some() {
+            std::fs::write(COMMITTED_DOCUMENT, &generated).unwrap();
+        }
+        let committed = std::fs::read_to_string(COMMITTED_DOCUMENT).unwrap_or_default();
+        assert!(
+            committed == generated,
+            "openapi.json is out of date with the routes; regenerate it with `UPDATE_OPENAPI=1 cargo test`",
+        );
+    }
+
+    #[test]
+    fn test_every_operation_documents_errors() {
+        let openapi = complete(api_routes().into_openapi());
+
+        for (path, path_item) in openapi.paths.paths.iter() {
+            let mut path_item = path_item.clone();
+            for operation in operations_mut(&mut path_item) {
+                assert!(operation.responses.responses.contains_key("4XX"), "{path} does not document its errors");
+                assert!(operation.tags.as_ref().is_some_and(|tags| !tags.is_empty()), "{path} has no tag");
+            }
+        }
+    }
+
+    #[test]
+    fn test_legacy_routes_are_deprecated() {
+        let openapi = api_routes().into_openapi();
+
+        let legacy = openapi.paths.paths.get("/notification/{uuid}").and_then(|path_item| path_item.get.as_ref()).unwrap();
+        let v1 = openapi.paths.paths.get("/v1/notifications/{uuid}").and_then(|path_item| path_item.get.as_ref()).unwrap();
+        assert!(matches!(legacy.deprecated, Some(Deprecated::True)));
+        assert!(v1.deprecated.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_serve_document() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, create_router(Arc::new(AppState::mocked()))).await.unwrap() });
+
+        let served: serde_json::Value = reqwest::get(format!("http://{}{}", address, OPENAPI_PATH)).await.unwrap()
+            .json().await.unwrap();
+        let docs = reqwest::get(format!("http://{}{}/", address, DOCS_PATH)).await.unwrap();
+
+        assert_eq!(served, serde_json::to_value(complete(api_routes().into_openapi())).unwrap());
+        assert!(docs.status().is_success());
+    }
+}
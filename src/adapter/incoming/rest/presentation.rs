@@ -0,0 +1,82 @@
+use crate::adapter::incoming::rest::response::notification::NotificationResponse;
+use crate::application::error::ApplicationError;
+use crate::application::service::clock_service::ClockService;
+use axum::extract::{FromRequestParts, Query};
+use axum::http::request::Parts;
+use chrono_tz::Tz;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// How the client wants the notifications read presented, from the `tz` and `age` query parameters.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct Presentation {
+    /// IANA time zone, such as `Europe/Lisbon`, to give times in instead of UTC.
+    #[serde(default)]
+    #[param(value_type = Option<String>)]
+    tz: Option<Tz>,
+    /// Adds the `age` of each notification, in seconds.
+    #[serde(default)]
+    age: bool,
+}
+
+impl Presentation {
+    pub fn present(&self, notification: NotificationResponse) -> NotificationResponse {
+        notification.presented(self.tz, self.age.then(ClockService::now))
+    }
+
+    /// Whether the notifications presented differ from one request to the next, as their `age` does.
+    pub fn changes_over_time(&self) -> bool {
+        self.age
+    }
+
+    /// Whether a notification is presented other than as stored, so that its version alone does not tell whether a
+    /// copy of it is current.
+    pub fn is_customised(&self) -> bool {
+        self.tz.is_some() || self.age
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Presentation {
+    type Rejection = ApplicationError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Query::<Presentation>::try_from_uri(&parts.uri)
+            .map(|Query(presentation)| presentation)
+            .map_err(|rejection| ApplicationError::BadRequest(rejection.body_text()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification::Notification;
+    use axum::http::Request;
+
+    async fn presentation(uri: &str) -> Result<Presentation, ApplicationError> {
+        let (mut parts, _) = Request::builder().uri(uri).body(()).unwrap().into_parts();
+        Presentation::from_request_parts(&mut parts, &()).await
+    }
+
+    #[tokio::test]
+    async fn test_present_in_time_zone_with_age() {
+        let presentation = presentation("/notification?recipient=bob&tz=America/New_York&age=true").await.unwrap();
+        let notification = Notification::new("bob".to_string(), "message".to_string());
+        let date_created = notification.date_created;
+
+        let result = presentation.present(notification.into());
+
+        assert_eq!(result.date_created.time, date_created);
+        assert_eq!(serde_json::to_value(result.date_created).unwrap(), serde_json::to_value(
+            date_created.with_timezone(&chrono_tz::America::New_York).to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false)
+        ).unwrap());
+        assert!(result.age.is_some_and(|age| age >= 0));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_time_zone_error() {
+        let result = presentation("/notification?tz=Mars/Olympus").await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+}
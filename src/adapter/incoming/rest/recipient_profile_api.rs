@@ -0,0 +1,292 @@
+use crate::adapter::incoming::rest::authentication::AuthenticatedRecipient;
+use crate::adapter::incoming::rest::request::update_digest_settings::UpdateDigestSettingsRequest;
+use crate::adapter::incoming::rest::request::update_preferences::UpdatePreferencesRequest;
+use crate::adapter::incoming::rest::request::update_quiet_hours::UpdateQuietHoursRequest;
+use crate::adapter::incoming::rest::request::update_recipient_profile::UpdateRecipientProfileRequest;
+use crate::adapter::incoming::rest::response::digest::DigestSettingsResponse;
+use crate::adapter::incoming::rest::response::preference::PreferenceResponse;
+use crate::adapter::incoming::rest::response::quiet_hours::QuietHoursResponse;
+use crate::adapter::incoming::rest::response::recipient_profile::RecipientProfileResponse;
+use crate::application::configuration::application_state::AppState;
+use crate::application::error::ApplicationError;
+use crate::application::service::recipient_profile_service::RecipientProfileServicePort;
+use axum::extract::State;
+use axum::Json;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub struct RecipientProfileApi {
+    profile_service: Arc<dyn RecipientProfileServicePort>,
+}
+
+impl RecipientProfileApi {
+    pub fn new(profile_service: Arc<dyn RecipientProfileServicePort>) -> Self {
+        Self { profile_service }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/profile",
+    tag = "profile",
+    summary = "Get the recipient's profile",
+    responses((status = 200, description = "The profile", body = RecipientProfileResponse)),
+    security(("bearer" = [])),
+)]
+pub async fn get_profile(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<RecipientProfileResponse>, ApplicationError> {
+    let profile = state.recipient_profile_api.profile_service.get_profile(recipient).await?;
+    Ok(Json(profile.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/profile",
+    tag = "profile",
+    summary = "Update the recipient's profile",
+    request_body = UpdateRecipientProfileRequest,
+    responses((status = 200, description = "The profile", body = RecipientProfileResponse)),
+    security(("bearer" = [])),
+)]
+pub async fn update_profile(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    Json(payload): Json<UpdateRecipientProfileRequest>,
+) -> Result<Json<RecipientProfileResponse>, ApplicationError> {
+    let profile = state.recipient_profile_api.profile_service.update_profile(recipient, payload.locale).await?;
+    Ok(Json(profile.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/profile/preferences",
+    tag = "profile",
+    summary = "Get the recipient's preferences",
+    responses((status = 200, description = "The preferences", body = Vec<PreferenceResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_preferences(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<Vec<PreferenceResponse>>, ApplicationError> {
+    let preferences = state.recipient_profile_api.profile_service.get_preferences(recipient).await?;
+    Ok(Json(preferences.into_iter().map(|preference| preference.into()).collect()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/profile/preferences",
+    tag = "profile",
+    summary = "Update the recipient's preferences",
+    request_body = UpdatePreferencesRequest,
+    responses((status = 200, description = "The preferences", body = Vec<PreferenceResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn update_preferences(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    Json(payload): Json<UpdatePreferencesRequest>,
+) -> Result<Json<Vec<PreferenceResponse>>, ApplicationError> {
+    let preferences = state.recipient_profile_api.profile_service
+        .update_preferences(recipient, payload.preferences)
+        .await?;
+    Ok(Json(preferences.into_iter().map(|preference| preference.into()).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/profile/quiet-hours",
+    tag = "profile",
+    summary = "Get the recipient's quiet hours",
+    responses((status = 200, description = "The quiet hours", body = QuietHoursResponse)),
+    security(("bearer" = [])),
+)]
+pub async fn get_quiet_hours(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<QuietHoursResponse>, ApplicationError> {
+    let quiet_hours = state.recipient_profile_api.profile_service.get_quiet_hours(recipient).await?;
+    Ok(Json(quiet_hours.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/profile/quiet-hours",
+    tag = "profile",
+    summary = "Update the recipient's quiet hours",
+    request_body = UpdateQuietHoursRequest,
+    responses((status = 200, description = "The quiet hours", body = QuietHoursResponse)),
+    security(("bearer" = [])),
+)]
+pub async fn update_quiet_hours(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    Json(payload): Json<UpdateQuietHoursRequest>,
+) -> Result<Json<QuietHoursResponse>, ApplicationError> {
+    let quiet_hours = state.recipient_profile_api.profile_service
+        .update_quiet_hours(recipient, payload.into())
+        .await?;
+    Ok(Json(quiet_hours.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/profile/digest",
+    tag = "profile",
+    summary = "Get the recipient's digest settings",
+    responses((status = 200, description = "The digest settings", body = DigestSettingsResponse)),
+    security(("bearer" = [])),
+)]
+pub async fn get_digest_settings(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<DigestSettingsResponse>, ApplicationError> {
+    let frequency = state.recipient_profile_api.profile_service.get_digest_frequency(recipient).await?;
+    Ok(Json(DigestSettingsResponse { frequency }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/profile/digest",
+    tag = "profile",
+    summary = "Update the recipient's digest settings",
+    request_body = UpdateDigestSettingsRequest,
+    responses((status = 200, description = "The digest settings", body = DigestSettingsResponse)),
+    security(("bearer" = [])),
+)]
+pub async fn update_digest_settings(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    Json(payload): Json<UpdateDigestSettingsRequest>,
+) -> Result<Json<DigestSettingsResponse>, ApplicationError> {
+    let frequency = state.recipient_profile_api.profile_service
+        .update_digest_frequency(recipient, payload.frequency)
+        .await?;
+    Ok(Json(DigestSettingsResponse { frequency }))
+}
+
+pub fn recipient_profile_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_profile, update_profile))
+        .routes(routes!(get_preferences, update_preferences))
+        .routes(routes!(get_quiet_hours, update_quiet_hours))
+        .routes(routes!(get_digest_settings, update_digest_settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::service::recipient_profile_service::MockRecipientProfileServicePort;
+    use crate::domain::model::digest::DigestFrequency;
+    use crate::domain::model::preference::{Channel, ChannelPreference, EffectivePreference};
+    use crate::domain::model::recipient_profile::RecipientProfile;
+    use mockall::predicate::eq;
+
+    #[tokio::test]
+    async fn test_update_profile_success() {
+        let mut mock_service = MockRecipientProfileServicePort::new();
+        mock_service
+            .expect_update_profile()
+            .with(eq("recipient".to_string()), eq(Some("pt-BR".to_string())))
+            .returning(|recipient, locale| {
+                let mut profile = RecipientProfile::new(recipient);
+                profile.set_locale(locale);
+                Ok(profile)
+            });
+
+        let app_state = Arc::new(AppState {
+            recipient_profile_api: Arc::new(RecipientProfileApi::new(Arc::new(mock_service))),
+            ..AppState::mocked()
+        });
+        let request = UpdateRecipientProfileRequest { locale: Some("pt-BR".to_string()) };
+
+        let response = update_profile(State(app_state), AuthenticatedRecipient("recipient".to_string()), Json(request))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.locale, Some("pt-BR".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_update_preferences_success() {
+        let preference = ChannelPreference { category: "marketing".to_string(), channel: Channel::Email, enabled: false };
+        let mut mock_service = MockRecipientProfileServicePort::new();
+        mock_service
+            .expect_update_preferences()
+            .with(eq("recipient".to_string()), eq(vec![preference.clone()]))
+            .returning(|_, preferences| Ok(preferences.into_iter()
+                .map(|preference| EffectivePreference {
+                    category: preference.category,
+                    channel: preference.channel,
+                    enabled: preference.enabled,
+                    default: false,
+                })
+                .collect()));
+
+        let app_state = Arc::new(AppState {
+            recipient_profile_api: Arc::new(RecipientProfileApi::new(Arc::new(mock_service))),
+            ..AppState::mocked()
+        });
+        let request = UpdatePreferencesRequest { preferences: vec![preference] };
+
+        let response = update_preferences(State(app_state), AuthenticatedRecipient("recipient".to_string()), Json(request))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.len(), 1);
+        assert!(!response[0].enabled);
+        assert!(!response[0].default);
+    }
+
+    #[tokio::test]
+    async fn test_update_quiet_hours_success() {
+        let mut mock_service = MockRecipientProfileServicePort::new();
+        mock_service
+            .expect_update_quiet_hours()
+            .withf(|recipient, quiet_hours| recipient == "recipient" && !quiet_hours.allow_urgent)
+            .returning(|_, quiet_hours| Ok(quiet_hours));
+
+        let app_state = Arc::new(AppState {
+            recipient_profile_api: Arc::new(RecipientProfileApi::new(Arc::new(mock_service))),
+            ..AppState::mocked()
+        });
+        let request: UpdateQuietHoursRequest = serde_json::from_str(
+            r#"{ "window": { "start": "22:00:00", "end": "07:00:00", "time_zone": "Europe/Lisbon" }, "allow_urgent": false }"#,
+        ).unwrap();
+
+        let response = update_quiet_hours(State(app_state), AuthenticatedRecipient("recipient".to_string()), Json(request))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.window.unwrap().time_zone, chrono_tz::Europe::Lisbon);
+        assert!(!response.allow_urgent);
+    }
+
+    #[tokio::test]
+    async fn test_update_digest_settings_success() {
+        let mut mock_service = MockRecipientProfileServicePort::new();
+        mock_service
+            .expect_update_digest_frequency()
+            .with(eq("recipient".to_string()), eq(Some(DigestFrequency::Hourly)))
+            .returning(|_, frequency| Ok(frequency));
+
+        let app_state = Arc::new(AppState {
+            recipient_profile_api: Arc::new(RecipientProfileApi::new(Arc::new(mock_service))),
+            ..AppState::mocked()
+        });
+        let request: UpdateDigestSettingsRequest = serde_json::from_str(r#"{ "frequency": "hourly" }"#).unwrap();
+
+        let response = update_digest_settings(State(app_state), AuthenticatedRecipient("recipient".to_string()), Json(request))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.frequency, Some(DigestFrequency::Hourly));
+    }
+}
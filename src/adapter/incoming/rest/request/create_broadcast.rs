@@ -0,0 +1,26 @@
+use crate::application::command::create_broadcast_command::CreateBroadcastCommand;
+use crate::domain::model::priority::Priority;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateBroadcastRequest {
+    #[serde(default)]
+    pub(crate) title: Option<String>,
+    pub(crate) message: String,
+    #[serde(default)]
+    pub(crate) category: Option<String>,
+    #[serde(default)]
+    pub(crate) priority: Priority,
+}
+
+impl From<CreateBroadcastRequest> for CreateBroadcastCommand {
+    fn from(request: CreateBroadcastRequest) -> Self {
+        Self {
+            title: request.title,
+            message: request.message,
+            category: request.category,
+            priority: request.priority,
+        }
+    }
+}
@@ -1,6 +1,44 @@
+use crate::application::command::create_notification_command::CreateNotificationCommand;
+use crate::domain::model::notification_action::NotificationAction;
+use crate::domain::model::priority::Priority;
 use serde::Deserialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateNotificationRequest {
+    /// Left out by clients written before notifications had recipients; their notifications go to the configured
+    /// default recipient.
+    #[serde(default)]
+    pub(crate) recipient: Option<String>,
     pub(crate) message: String,
-}
\ No newline at end of file
+    #[serde(default)]
+    pub(crate) category: Option<String>,
+    #[serde(default)]
+    pub(crate) priority: Priority,
+    #[serde(default)]
+    pub(crate) group_key: Option<String>,
+    #[serde(default)]
+    pub(crate) actor: Option<String>,
+    #[serde(default)]
+    pub(crate) actions: Vec<NotificationAction>,
+    #[serde(default)]
+    pub(crate) auto_resolve: bool,
+    #[serde(default)]
+    pub(crate) tags: Vec<String>,
+}
+
+impl CreateNotificationRequest {
+    pub fn into_command(self, default_recipient: &str) -> CreateNotificationCommand {
+        CreateNotificationCommand {
+            recipient: self.recipient.unwrap_or_else(|| default_recipient.to_string()),
+            message: self.message,
+            category: self.category,
+            priority: self.priority,
+            group_key: self.group_key,
+            actor: self.actor,
+            actions: self.actions,
+            auto_resolve: self.auto_resolve,
+            tags: self.tags,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::application::command::create_notification_from_template_command::CreateNotificationFromTemplateCommand;
+use crate::domain::model::notification_action::NotificationAction;
+use crate::domain::model::preference::Channel;
+use crate::domain::model::priority::Priority;
+use serde::Deserialize;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateNotificationFromTemplateRequest {
+    pub(crate) recipient: String,
+    pub(crate) template_id: String,
+    /// Channel the content is meant for: variables are HTML escaped for `email` and substituted verbatim otherwise.
+    #[serde(default)]
+    pub(crate) channel: Option<Channel>,
+    #[serde(default)]
+    pub(crate) category: Option<String>,
+    #[serde(default)]
+    pub(crate) priority: Priority,
+    #[serde(default)]
+    pub(crate) group_key: Option<String>,
+    #[serde(default)]
+    pub(crate) actor: Option<String>,
+    #[serde(default)]
+    pub(crate) actions: Vec<NotificationAction>,
+    #[serde(default)]
+    pub(crate) auto_resolve: bool,
+    #[serde(default)]
+    pub(crate) tags: Vec<String>,
+    #[serde(default)]
+    pub(crate) variables: HashMap<String, String>,
+}
+
+impl From<CreateNotificationFromTemplateRequest> for CreateNotificationFromTemplateCommand {
+    fn from(request: CreateNotificationFromTemplateRequest) -> Self {
+        Self {
+            recipient: request.recipient,
+            template_id: request.template_id,
+            channel: request.channel,
+            category: request.category,
+            priority: request.priority,
+            group_key: request.group_key,
+            actor: request.actor,
+            actions: request.actions,
+            auto_resolve: request.auto_resolve,
+            tags: request.tags,
+            variables: request.variables,
+        }
+    }
+}
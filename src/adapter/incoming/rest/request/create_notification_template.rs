@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateNotificationTemplateRequest {
+    pub(crate) id: String,
+    pub(crate) locale: String,
+    pub(crate) title: String,
+    pub(crate) body: String,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTopicRequest {
+    pub(crate) id: String,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+}
@@ -0,0 +1,14 @@
+use crate::domain::model::notification_event::NotificationEventType;
+use crate::domain::model::preference::Channel;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWebhookSubscriptionRequest {
+    pub(crate) url: String,
+    pub(crate) secret: Option<String>,
+    #[serde(default)]
+    pub(crate) events: Vec<NotificationEventType>,
+    #[serde(default)]
+    pub(crate) channel: Option<Channel>,
+}
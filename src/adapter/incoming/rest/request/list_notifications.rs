@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListNotificationsQuery {
+    /// Lists the archived notifications instead of the inbox, or only the inbox; by default the inbox, unless
+    /// listing changes with `updated_since`, which includes both.
+    #[serde(default)]
+    pub(crate) archived: Option<bool>,
+    #[serde(default)]
+    pub(crate) pinned: bool,
+    #[serde(default)]
+    pub(crate) tag: Option<String>,
+    /// Lists only the notifications changed after this time, whatever their state, to sync incrementally.
+    #[serde(default)]
+    pub(crate) updated_since: Option<DateTime<Utc>>,
+}
@@ -1 +1,19 @@
-pub mod create_notification;
\ No newline at end of file
+pub mod create_broadcast;
+pub mod create_notification;
+pub mod create_notification_from_template;
+pub mod create_notification_template;
+pub mod create_topic;
+pub mod create_webhook_subscription;
+pub mod list_notifications;
+pub mod list_topic_subscribers;
+pub mod publish_to_topic;
+pub mod search_notifications;
+pub mod render_notification_template;
+pub mod set_notification_template_variant;
+pub mod snooze_notification;
+pub mod update_digest_settings;
+pub mod update_notification;
+pub mod update_notification_template;
+pub mod update_preferences;
+pub mod update_quiet_hours;
+pub mod update_recipient_profile;
@@ -0,0 +1,12 @@
+use crate::domain::model::priority::Priority;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PublishToTopicRequest {
+    pub(crate) message: String,
+    #[serde(default)]
+    pub(crate) category: Option<String>,
+    #[serde(default)]
+    pub(crate) priority: Priority,
+}
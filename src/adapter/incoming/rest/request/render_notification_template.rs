@@ -0,0 +1,13 @@
+use crate::domain::model::notification_template::TemplateFormat;
+use serde::Deserialize;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RenderNotificationTemplateRequest {
+    #[serde(default)]
+    pub(crate) variables: HashMap<String, String>,
+    #[serde(default)]
+    pub(crate) format: TemplateFormat,
+    pub(crate) locale: Option<String>,
+}
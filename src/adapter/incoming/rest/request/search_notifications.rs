@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchNotificationsQuery {
+    pub(crate) q: String,
+    /// Searches only the archived notifications, or only the others; both when omitted.
+    #[serde(default)]
+    pub(crate) archived: Option<bool>,
+    #[serde(default)]
+    pub(crate) pinned: bool,
+    #[serde(default)]
+    pub(crate) tag: Option<String>,
+    #[serde(default)]
+    pub(crate) limit: Option<usize>,
+}
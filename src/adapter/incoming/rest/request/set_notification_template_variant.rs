@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetNotificationTemplateVariantRequest {
+    pub(crate) title: String,
+    pub(crate) body: String,
+}
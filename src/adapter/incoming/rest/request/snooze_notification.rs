@@ -0,0 +1,8 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SnoozeNotificationRequest {
+    pub(crate) until: DateTime<Utc>,
+}
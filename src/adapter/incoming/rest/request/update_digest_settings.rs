@@ -0,0 +1,10 @@
+use crate::domain::model::digest::DigestFrequency;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateDigestSettingsRequest {
+    /// Turns digests off when omitted.
+    #[serde(default)]
+    pub(crate) frequency: Option<DigestFrequency>,
+}
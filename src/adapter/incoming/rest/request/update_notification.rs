@@ -0,0 +1,29 @@
+use crate::application::command::update_notification_command::UpdateNotificationCommand;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Unknown fields are refused rather than ignored, so that a misspelt change is not taken for an empty one.
+#[derive(Debug, PartialEq, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateNotificationRequest {
+    #[serde(default)]
+    pub(crate) seen: Option<bool>,
+    #[serde(default)]
+    pub(crate) pinned: Option<bool>,
+    #[serde(default)]
+    pub(crate) archived: Option<bool>,
+    #[serde(default)]
+    pub(crate) snoozed_until: Option<DateTime<Utc>>,
+}
+
+impl From<UpdateNotificationRequest> for UpdateNotificationCommand {
+    fn from(request: UpdateNotificationRequest) -> Self {
+        Self {
+            seen: request.seen,
+            pinned: request.pinned,
+            archived: request.archived,
+            snoozed_until: request.snoozed_until,
+        }
+    }
+}
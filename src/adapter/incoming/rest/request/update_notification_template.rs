@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateNotificationTemplateRequest {
+    pub(crate) locale: String,
+    pub(crate) title: String,
+    pub(crate) body: String,
+}
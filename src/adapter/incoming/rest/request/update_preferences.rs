@@ -0,0 +1,8 @@
+use crate::domain::model::preference::ChannelPreference;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdatePreferencesRequest {
+    pub(crate) preferences: Vec<ChannelPreference>,
+}
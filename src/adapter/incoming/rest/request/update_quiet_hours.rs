@@ -0,0 +1,28 @@
+use crate::domain::model::quiet_hours::{QuietHours, QuietWindow};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateQuietHoursRequest {
+    #[serde(default)]
+    pub(crate) window: Option<QuietWindow>,
+    #[serde(default)]
+    pub(crate) do_not_disturb_until: Option<DateTime<Utc>>,
+    #[serde(default = "allow_urgent")]
+    pub(crate) allow_urgent: bool,
+}
+
+fn allow_urgent() -> bool {
+    QuietHours::default().allow_urgent
+}
+
+impl From<UpdateQuietHoursRequest> for QuietHours {
+    fn from(request: UpdateQuietHoursRequest) -> Self {
+        Self {
+            window: request.window,
+            do_not_disturb_until: request.do_not_disturb_until,
+            allow_urgent: request.allow_urgent,
+        }
+    }
+}
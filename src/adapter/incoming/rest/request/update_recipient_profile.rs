@@ -0,0 +1,7 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateRecipientProfileRequest {
+    pub(crate) locale: Option<String>,
+}
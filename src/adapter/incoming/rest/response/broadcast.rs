@@ -0,0 +1,28 @@
+use crate::domain::model::broadcast::Broadcast;
+use crate::domain::model::priority::Priority;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BroadcastResponse {
+    uuid: String,
+    pub(crate) category: String,
+    pub(crate) priority: Priority,
+    pub(crate) title: Option<String>,
+    pub(crate) message: String,
+    pub(crate) date_created: DateTime<Utc>,
+}
+
+impl From<Broadcast> for BroadcastResponse {
+    fn from(broadcast: Broadcast) -> Self {
+        Self {
+            uuid: broadcast.uuid.to_string(),
+            category: broadcast.category,
+            priority: broadcast.priority,
+            title: broadcast.title,
+            message: broadcast.message,
+            date_created: broadcast.date_created,
+        }
+    }
+}
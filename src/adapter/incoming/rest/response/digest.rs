@@ -0,0 +1,35 @@
+use crate::domain::model::digest::{Digest, DigestFrequency, DigestStatus};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DigestSettingsResponse {
+    pub(crate) frequency: Option<DigestFrequency>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DigestResponse {
+    uuid: String,
+    pub(crate) recipient: String,
+    pub(crate) status: DigestStatus,
+    /// Uuids of the notifications included.
+    pub(crate) notifications: Vec<String>,
+    pub(crate) due_at: DateTime<Utc>,
+    pub(crate) date_created: DateTime<Utc>,
+    pub(crate) sent_at: Option<DateTime<Utc>>,
+}
+
+impl From<Digest> for DigestResponse {
+    fn from(digest: Digest) -> Self {
+        Self {
+            uuid: digest.uuid.to_string(),
+            recipient: digest.recipient,
+            status: digest.status,
+            notifications: digest.notifications.iter().map(|notification| notification.uuid.to_string()).collect(),
+            due_at: digest.due_at,
+            date_created: digest.date_created,
+            sent_at: digest.sent_at,
+        }
+    }
+}
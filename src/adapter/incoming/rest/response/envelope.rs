@@ -0,0 +1,14 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Body of every `/v1` response, holding the payload under `data` as errors are held under `error`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Envelope<T> {
+    pub(crate) data: T,
+}
+
+impl<T> Envelope<T> {
+    pub fn new(data: T) -> Self {
+        Self { data }
+    }
+}
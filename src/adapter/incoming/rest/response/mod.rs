@@ -1 +1,20 @@
-pub mod notification;
\ No newline at end of file
+pub mod broadcast;
+pub mod digest;
+pub mod envelope;
+pub mod notification;
+pub mod notification_group;
+pub mod notification_history;
+pub mod notification_search_hit;
+pub mod notification_template;
+pub mod preference;
+pub mod quiet_hours;
+pub mod recipient_profile;
+pub mod rendered_notification_template;
+pub mod state_change;
+pub mod tag_count;
+pub mod timestamp;
+pub mod topic;
+pub mod topic_publication;
+pub mod unread_count;
+pub mod webhook_delivery_attempt;
+pub mod webhook_subscription;
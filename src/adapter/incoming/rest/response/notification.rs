@@ -1,21 +1,87 @@
-use crate::domain::model::notification::Notification;
+use crate::adapter::incoming::rest::response::timestamp::Timestamp;
+use crate::domain::model::notification::{Notification, NotificationKind};
+use crate::domain::model::notification_action::{ActionTaken, NotificationAction};
+use crate::domain::model::priority::Priority;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct NotificationResponse {
     uuid: String,
+    pub(crate) kind: NotificationKind,
+    pub(crate) recipient: String,
+    pub(crate) category: String,
+    pub(crate) priority: Priority,
+    pub(crate) group_key: Option<String>,
+    pub(crate) actor: Option<String>,
+    pub(crate) title: Option<String>,
     pub(crate) message: String,
-    seen: bool,
-    deleted: bool,
+    pub(crate) locale: Option<String>,
+    pub(crate) actions: Vec<NotificationAction>,
+    pub(crate) auto_resolve: bool,
+    pub(crate) action_taken: Option<ActionTaken>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) snoozed_until: Option<Timestamp>,
+    pub(crate) seen: bool,
+    pub(crate) deleted: bool,
+    pub(crate) pinned: bool,
+    pub(crate) archived: bool,
+    pub(crate) version: u64,
+    pub(crate) seen_at: Option<Timestamp>,
+    pub(crate) deleted_at: Option<Timestamp>,
+    pub(crate) date_created: Timestamp,
+    pub(crate) last_updated: Timestamp,
+    /// Seconds since the notification was created, only when asked for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) age: Option<i64>,
+}
+
+impl NotificationResponse {
+    /// Gives the times of the notification in `time_zone`, and its `age` at `now` when given.
+    pub fn presented(self, time_zone: Option<Tz>, now: Option<DateTime<Utc>>) -> Self {
+        let in_time_zone = |timestamp: Timestamp| timestamp.in_time_zone(time_zone);
+        Self {
+            snoozed_until: self.snoozed_until.map(in_time_zone),
+            seen_at: self.seen_at.map(in_time_zone),
+            deleted_at: self.deleted_at.map(in_time_zone),
+            date_created: in_time_zone(self.date_created),
+            last_updated: in_time_zone(self.last_updated),
+            age: now.map(|now| (now - self.date_created.time).num_seconds().max(0)),
+            ..self
+        }
+    }
 }
 
 impl From<Notification> for NotificationResponse {
     fn from(notification: Notification) -> Self {
         Self {
             uuid: notification.uuid.to_string(),
+            kind: notification.kind,
+            recipient: notification.recipient,
+            category: notification.category,
+            priority: notification.priority,
+            group_key: notification.group_key,
+            actor: notification.actor,
+            title: notification.title,
             message: notification.message,
+            locale: notification.locale,
+            actions: notification.actions,
+            auto_resolve: notification.auto_resolve,
+            action_taken: notification.action_taken,
+            tags: notification.tags,
+            snoozed_until: notification.snoozed_until.map(Timestamp::from),
             seen: notification.seen,
             deleted: notification.deleted,
+            pinned: notification.pinned,
+            archived: notification.archived,
+            version: notification.version,
+            seen_at: notification.seen_at.map(Timestamp::from),
+            deleted_at: notification.deleted_at.map(Timestamp::from),
+            date_created: notification.date_created.into(),
+            last_updated: notification.last_updated.into(),
+            age: None,
         }
     }
 }
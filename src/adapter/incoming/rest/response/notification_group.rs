@@ -0,0 +1,27 @@
+use crate::adapter::incoming::rest::response::notification::NotificationResponse;
+use crate::domain::model::notification_group::NotificationGroup;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationGroupResponse {
+    pub(crate) group_key: Option<String>,
+    pub(crate) count: usize,
+    pub(crate) unseen: usize,
+    pub(crate) pinned: bool,
+    pub(crate) latest: NotificationResponse,
+    pub(crate) actors: Vec<String>,
+}
+
+impl From<NotificationGroup> for NotificationGroupResponse {
+    fn from(group: NotificationGroup) -> Self {
+        Self {
+            group_key: group.group_key,
+            count: group.count,
+            unseen: group.unseen,
+            pinned: group.pinned,
+            latest: group.latest.into(),
+            actors: group.actors,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::domain::model::notification_event::NotificationEventType;
+use crate::domain::model::notification_history::{ChangeSource, NotificationHistoryEntry, NotificationState};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationHistoryResponse {
+    uuid: String,
+    pub(crate) event: NotificationEventType,
+    pub(crate) changed_by: Option<String>,
+    pub(crate) source: ChangeSource,
+    pub(crate) from_state: NotificationState,
+    pub(crate) to_state: NotificationState,
+    date_created: DateTime<Utc>,
+}
+
+impl From<NotificationHistoryEntry> for NotificationHistoryResponse {
+    fn from(entry: NotificationHistoryEntry) -> Self {
+        Self {
+            uuid: entry.uuid.to_string(),
+            event: entry.event_type,
+            changed_by: entry.changed_by,
+            source: entry.source,
+            from_state: entry.from_state,
+            to_state: entry.to_state,
+            date_created: entry.date_created,
+        }
+    }
+}
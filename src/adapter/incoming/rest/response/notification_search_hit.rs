@@ -0,0 +1,21 @@
+use crate::adapter::incoming::rest::response::notification::NotificationResponse;
+use crate::domain::model::notification_search::SearchHit;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationSearchHitResponse {
+    pub(crate) notification: NotificationResponse,
+    pub(crate) rank: f64,
+    pub(crate) snippet: String,
+}
+
+impl From<SearchHit> for NotificationSearchHitResponse {
+    fn from(hit: SearchHit) -> Self {
+        Self {
+            notification: hit.notification.into(),
+            rank: hit.rank,
+            snippet: hit.snippet,
+        }
+    }
+}
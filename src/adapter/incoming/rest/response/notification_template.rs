@@ -0,0 +1,31 @@
+use crate::domain::model::notification_template::{NotificationTemplate, TemplateVariant};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationTemplateResponse {
+    pub(crate) id: String,
+    pub(crate) version: u32,
+    pub(crate) locale: String,
+    pub(crate) title: String,
+    pub(crate) body: String,
+    pub(crate) variants: Vec<TemplateVariant>,
+    pub(crate) date_created: DateTime<Utc>,
+    pub(crate) last_updated: DateTime<Utc>,
+}
+
+impl From<NotificationTemplate> for NotificationTemplateResponse {
+    fn from(template: NotificationTemplate) -> Self {
+        Self {
+            id: template.id,
+            version: template.version,
+            locale: template.locale,
+            title: template.title,
+            body: template.body,
+            variants: template.variants,
+            date_created: template.date_created,
+            last_updated: template.last_updated,
+        }
+    }
+}
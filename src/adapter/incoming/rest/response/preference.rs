@@ -0,0 +1,23 @@
+use crate::domain::model::preference::{Channel, EffectivePreference};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PreferenceResponse {
+    pub(crate) category: String,
+    pub(crate) channel: Channel,
+    pub(crate) enabled: bool,
+    /// Whether `enabled` is the category default rather than a choice of the recipient.
+    pub(crate) default: bool,
+}
+
+impl From<EffectivePreference> for PreferenceResponse {
+    fn from(preference: EffectivePreference) -> Self {
+        Self {
+            category: preference.category,
+            channel: preference.channel,
+            enabled: preference.enabled,
+            default: preference.default,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::domain::model::quiet_hours::{QuietHours, QuietWindow};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuietHoursResponse {
+    pub(crate) window: Option<QuietWindow>,
+    pub(crate) do_not_disturb_until: Option<DateTime<Utc>>,
+    pub(crate) allow_urgent: bool,
+}
+
+impl From<QuietHours> for QuietHoursResponse {
+    fn from(quiet_hours: QuietHours) -> Self {
+        Self {
+            window: quiet_hours.window,
+            do_not_disturb_until: quiet_hours.do_not_disturb_until,
+            allow_urgent: quiet_hours.allow_urgent,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::domain::model::recipient_profile::RecipientProfile;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RecipientProfileResponse {
+    pub(crate) recipient: String,
+    pub(crate) locale: Option<String>,
+    pub(crate) last_updated: DateTime<Utc>,
+}
+
+impl From<RecipientProfile> for RecipientProfileResponse {
+    fn from(profile: RecipientProfile) -> Self {
+        Self {
+            recipient: profile.recipient,
+            locale: profile.locale,
+            last_updated: profile.last_updated,
+        }
+    }
+}
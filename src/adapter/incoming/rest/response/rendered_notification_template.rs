@@ -0,0 +1,15 @@
+use crate::domain::model::notification_template::RenderedTemplate;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RenderedNotificationTemplateResponse {
+    pub(crate) title: String,
+    pub(crate) body: String,
+}
+
+impl From<RenderedTemplate> for RenderedNotificationTemplateResponse {
+    fn from(rendered: RenderedTemplate) -> Self {
+        Self { title: rendered.title, body: rendered.body }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::adapter::incoming::rest::response::notification::NotificationResponse;
+use crate::domain::model::state_change::StateChange;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The notification marked, along with `changed`, false when it already was in the state asked for.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StateChangeResponse {
+    #[serde(flatten)]
+    pub(crate) notification: NotificationResponse,
+    pub(crate) changed: bool,
+}
+
+impl From<StateChange> for StateChangeResponse {
+    fn from(state_change: StateChange) -> Self {
+        Self {
+            notification: state_change.notification.into(),
+            changed: state_change.changed,
+        }
+    }
+}
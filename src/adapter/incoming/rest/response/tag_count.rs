@@ -0,0 +1,15 @@
+use crate::domain::model::tag_count::TagCount;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TagCountResponse {
+    pub(crate) tag: String,
+    pub(crate) count: usize,
+}
+
+impl From<TagCount> for TagCountResponse {
+    fn from(tag_count: TagCount) -> Self {
+        Self { tag: tag_count.tag, count: tag_count.count }
+    }
+}
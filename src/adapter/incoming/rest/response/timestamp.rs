@@ -0,0 +1,65 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use chrono_tz::Tz;
+use serde::{Serialize, Serializer};
+use utoipa::openapi::schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+/// A time serialised as RFC 3339, in UTC unless the client asked for its own time zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timestamp {
+    pub(crate) time: DateTime<Utc>,
+    time_zone: Option<Tz>,
+}
+
+impl Timestamp {
+    pub fn in_time_zone(self, time_zone: Option<Tz>) -> Self {
+        Self { time_zone, ..self }
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(time: DateTime<Utc>) -> Self {
+        Self { time, time_zone: None }
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.time_zone {
+            Some(time_zone) => {
+                let local = self.time.with_timezone(&time_zone);
+                serializer.serialize_str(&local.to_rfc3339_opts(SecondsFormat::AutoSi, false))
+            }
+            None => self.time.serialize(serializer),
+        }
+    }
+}
+
+impl PartialSchema for Timestamp {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)))
+            .into()
+    }
+}
+
+impl ToSchema for Timestamp {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_in_time_zone() {
+        let time = DateTime::parse_from_rfc3339("2024-07-01T10:00:00.250Z").unwrap().with_timezone(&Utc);
+
+        let utc = serde_json::to_value(Timestamp::from(time)).unwrap();
+        let lisbon = serde_json::to_value(Timestamp::from(time).in_time_zone(Some(chrono_tz::Europe::Lisbon))).unwrap();
+
+        assert_eq!(utc, serde_json::to_value(time).unwrap());
+        assert_eq!(utc, "2024-07-01T10:00:00.250Z");
+        assert_eq!(lisbon, "2024-07-01T11:00:00.250+01:00");
+    }
+}
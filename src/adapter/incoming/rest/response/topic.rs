@@ -0,0 +1,38 @@
+use crate::domain::model::topic::{Topic, TopicSubscription};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TopicResponse {
+    pub(crate) id: String,
+    pub(crate) description: Option<String>,
+    pub(crate) date_created: DateTime<Utc>,
+}
+
+impl From<Topic> for TopicResponse {
+    fn from(topic: Topic) -> Self {
+        Self {
+            id: topic.id,
+            description: topic.description,
+            date_created: topic.date_created,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TopicSubscriptionResponse {
+    pub(crate) topic_id: String,
+    pub(crate) recipient: String,
+    pub(crate) date_created: DateTime<Utc>,
+}
+
+impl From<TopicSubscription> for TopicSubscriptionResponse {
+    fn from(subscription: TopicSubscription) -> Self {
+        Self {
+            topic_id: subscription.topic_id,
+            recipient: subscription.recipient,
+            date_created: subscription.date_created,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::domain::model::priority::Priority;
+use crate::domain::model::topic_publication::{PublicationStatus, TopicPublication};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TopicPublicationResponse {
+    uuid: String,
+    pub(crate) topic_id: String,
+    pub(crate) message: String,
+    pub(crate) category: String,
+    pub(crate) priority: Priority,
+    pub(crate) status: PublicationStatus,
+    pub(crate) total_recipients: usize,
+    pub(crate) processed_recipients: usize,
+    pub(crate) failed_recipients: usize,
+    pub(crate) date_created: DateTime<Utc>,
+    pub(crate) last_updated: DateTime<Utc>,
+}
+
+impl From<TopicPublication> for TopicPublicationResponse {
+    fn from(publication: TopicPublication) -> Self {
+        Self {
+            uuid: publication.uuid.to_string(),
+            topic_id: publication.topic_id,
+            message: publication.message,
+            category: publication.category,
+            priority: publication.priority,
+            status: publication.status,
+            total_recipients: publication.total_recipients,
+            processed_recipients: publication.processed_recipients,
+            failed_recipients: publication.failed_recipients,
+            date_created: publication.date_created,
+            last_updated: publication.last_updated,
+        }
+    }
+}
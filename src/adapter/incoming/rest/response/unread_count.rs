@@ -0,0 +1,7 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UnreadCountResponse {
+    pub(crate) unread: usize,
+}
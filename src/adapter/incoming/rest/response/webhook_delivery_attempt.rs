@@ -0,0 +1,34 @@
+use crate::domain::model::notification_event::NotificationEventType;
+use crate::domain::model::webhook_delivery_attempt::WebhookDeliveryAttempt;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryAttemptResponse {
+    uuid: String,
+    event_uuid: String,
+    event_type: NotificationEventType,
+    pub(crate) attempt: u32,
+    pub(crate) success: bool,
+    status_code: Option<u16>,
+    error: Option<String>,
+    suppression_reason: Option<String>,
+    attempted_at: DateTime<Utc>,
+}
+
+impl From<WebhookDeliveryAttempt> for WebhookDeliveryAttemptResponse {
+    fn from(attempt: WebhookDeliveryAttempt) -> Self {
+        Self {
+            success: attempt.is_success(),
+            uuid: attempt.uuid.to_string(),
+            event_uuid: attempt.event_uuid.to_string(),
+            event_type: attempt.event_type,
+            attempt: attempt.attempt,
+            status_code: attempt.status_code,
+            error: attempt.error,
+            suppression_reason: attempt.suppression_reason,
+            attempted_at: attempt.attempted_at,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::domain::model::notification_event::NotificationEventType;
+use crate::domain::model::preference::Channel;
+use crate::domain::model::webhook_subscription::WebhookSubscription;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookSubscriptionResponse {
+    pub(crate) uuid: String,
+    pub(crate) url: String,
+    pub(crate) events: Vec<NotificationEventType>,
+    pub(crate) channel: Option<Channel>,
+    pub(crate) date_created: DateTime<Utc>,
+    /// Only returned when the subscription is created, so it can be stored by the integrator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) secret: Option<String>,
+}
+
+impl WebhookSubscriptionResponse {
+    pub fn with_secret(subscription: WebhookSubscription) -> Self {
+        let secret = subscription.secret.clone();
+        Self { secret: Some(secret), ..subscription.into() }
+    }
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            uuid: subscription.uuid.to_string(),
+            url: subscription.url,
+            events: subscription.events,
+            channel: subscription.channel,
+            date_created: subscription.date_created,
+            secret: None,
+        }
+    }
+}
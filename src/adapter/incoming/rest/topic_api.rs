@@ -0,0 +1,348 @@
+use crate::adapter::incoming::rest::authentication::AuthenticatedRecipient;
+use crate::adapter::incoming::rest::request::create_topic::CreateTopicRequest;
+use crate::adapter::incoming::rest::request::list_topic_subscribers::ListTopicSubscribersQuery;
+use crate::adapter::incoming::rest::request::publish_to_topic::PublishToTopicRequest;
+use crate::adapter::incoming::rest::response::topic::{TopicResponse, TopicSubscriptionResponse};
+use crate::adapter::incoming::rest::response::topic_publication::TopicPublicationResponse;
+use crate::application::command::publish_to_topic_command::PublishToTopicCommand;
+use crate::application::configuration::application_state::AppState;
+use crate::application::error::ApplicationError;
+use crate::application::service::topic_service::TopicServicePort;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+const DEFAULT_SUBSCRIBERS_LIMIT: usize = 100;
+const MAX_SUBSCRIBERS_LIMIT: usize = 1000;
+
+pub struct TopicApi {
+    topic_service: Arc<dyn TopicServicePort>,
+}
+
+impl TopicApi {
+    pub fn new(topic_service: Arc<dyn TopicServicePort>) -> Self {
+        Self { topic_service }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/topic",
+    tag = "topics",
+    summary = "Create a topic",
+    request_body = CreateTopicRequest,
+    responses((status = 201, description = "The topic created", body = TopicResponse)),
+)]
+pub async fn create_topic(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<CreateTopicRequest>,
+) -> Result<(StatusCode, Json<TopicResponse>), ApplicationError> {
+    let topic = state.topic_api.topic_service.create_topic(payload.id, payload.description).await?;
+    Ok((StatusCode::CREATED, Json(topic.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/topic/{id}",
+    tag = "topics",
+    summary = "Get a topic",
+    params(("id" = String, Path, description = "Topic id")),
+    responses((status = 200, description = "The topic", body = TopicResponse)),
+)]
+pub async fn get_topic(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<TopicResponse>, ApplicationError> {
+    let topic = state.topic_api.topic_service.get_topic(id).await?;
+    Ok(Json(topic.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/topic",
+    tag = "topics",
+    summary = "List topics",
+    responses((status = 200, description = "The topics", body = Vec<TopicResponse>)),
+)]
+pub async fn get_topics(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<TopicResponse>>, ApplicationError> {
+    let topics = state.topic_api.topic_service.list_topics().await?;
+    Ok(Json(topics.into_iter().map(|topic| topic.into()).collect()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/topic/{id}",
+    tag = "topics",
+    summary = "Delete a topic",
+    params(("id" = String, Path, description = "Topic id")),
+    responses((status = 204, description = "The topic is deleted")),
+)]
+pub async fn delete_topic(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApplicationError> {
+    state.topic_api.topic_service.delete_topic(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/topic/{id}/subscriber",
+    tag = "topics",
+    summary = "List the subscribers of a topic",
+    params(("id" = String, Path, description = "Topic id"), ListTopicSubscribersQuery),
+    responses(
+        (status = 200, description = "A page of subscriptions, by recipient", body = Vec<TopicSubscriptionResponse>),
+    ),
+)]
+pub async fn get_subscribers(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<ListTopicSubscribersQuery>,
+) -> Result<Json<Vec<TopicSubscriptionResponse>>, ApplicationError> {
+    let limit = query.limit.unwrap_or(DEFAULT_SUBSCRIBERS_LIMIT).clamp(1, MAX_SUBSCRIBERS_LIMIT);
+    let subscriptions = state.topic_api.topic_service.list_subscribers(id, query.after, limit).await?;
+    Ok(Json(subscriptions.into_iter().map(|subscription| subscription.into()).collect()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/topic/{id}/subscriber/{recipient}",
+    tag = "topics",
+    summary = "Subscribe a recipient to a topic",
+    params(("id" = String, Path, description = "Topic id"), ("recipient" = String, Path, description = "Recipient id")),
+    responses((status = 200, description = "The subscription", body = TopicSubscriptionResponse)),
+)]
+pub async fn add_subscriber(
+    State(state): State<Arc<AppState>>,
+    Path((id, recipient)): Path<(String, String)>,
+) -> Result<Json<TopicSubscriptionResponse>, ApplicationError> {
+    let subscription = state.topic_api.topic_service.subscribe(id, recipient).await?;
+    Ok(Json(subscription.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/topic/{id}/subscriber/{recipient}",
+    tag = "topics",
+    summary = "Unsubscribe a recipient from a topic",
+    params(("id" = String, Path, description = "Topic id"), ("recipient" = String, Path, description = "Recipient id")),
+    responses((status = 204, description = "The recipient is unsubscribed")),
+)]
+pub async fn remove_subscriber(
+    State(state): State<Arc<AppState>>,
+    Path((id, recipient)): Path<(String, String)>,
+) -> Result<StatusCode, ApplicationError> {
+    state.topic_api.topic_service.unsubscribe(id, recipient).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/profile/topics",
+    tag = "topics",
+    summary = "List the recipient's subscriptions",
+    responses((status = 200, description = "The subscriptions", body = Vec<TopicSubscriptionResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_own_subscriptions(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+) -> Result<Json<Vec<TopicSubscriptionResponse>>, ApplicationError> {
+    let subscriptions = state.topic_api.topic_service.list_subscriptions(recipient).await?;
+    Ok(Json(subscriptions.into_iter().map(|subscription| subscription.into()).collect()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/profile/topics/{id}",
+    tag = "topics",
+    summary = "Subscribe the recipient to a topic",
+    params(("id" = String, Path, description = "Topic id")),
+    responses((status = 200, description = "The subscription", body = TopicSubscriptionResponse)),
+    security(("bearer" = [])),
+)]
+pub async fn subscribe(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    Path(id): Path<String>,
+) -> Result<Json<TopicSubscriptionResponse>, ApplicationError> {
+    let subscription = state.topic_api.topic_service.subscribe(id, recipient).await?;
+    Ok(Json(subscription.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/profile/topics/{id}",
+    tag = "topics",
+    summary = "Unsubscribe the recipient from a topic",
+    params(("id" = String, Path, description = "Topic id")),
+    responses((status = 204, description = "The recipient is unsubscribed")),
+    security(("bearer" = [])),
+)]
+pub async fn unsubscribe(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApplicationError> {
+    state.topic_api.topic_service.unsubscribe(id, recipient).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/topic/{id}/publication",
+    tag = "topics",
+    summary = "Publish to the subscribers of a topic",
+    params(("id" = String, Path, description = "Topic id")),
+    request_body = PublishToTopicRequest,
+    responses((status = 202, description = "The publication accepted", body = TopicPublicationResponse)),
+)]
+/// Answers once the publication is accepted; its progress is followed through `get_publication`.
+pub async fn publish(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(payload): Json<PublishToTopicRequest>,
+) -> Result<(StatusCode, Json<TopicPublicationResponse>), ApplicationError> {
+    let command = PublishToTopicCommand {
+        topic_id: id,
+        message: payload.message,
+        category: payload.category,
+        priority: payload.priority,
+    };
+    let publication = state.topic_api.topic_service.publish(command).await?;
+    Ok((StatusCode::ACCEPTED, Json(publication.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/topic/{id}/publication/{uuid}",
+    tag = "topics",
+    summary = "Get the progress of a publication",
+    params(("id" = String, Path, description = "Topic id"), ("uuid" = String, Path, description = "Publication id")),
+    responses((status = 200, description = "The publication", body = TopicPublicationResponse)),
+)]
+pub async fn get_publication(
+    State(state): State<Arc<AppState>>,
+    Path((id, uuid)): Path<(String, String)>,
+) -> Result<Json<TopicPublicationResponse>, ApplicationError> {
+    let publication = state.topic_api.topic_service.get_publication(id, uuid).await?;
+    Ok(Json(publication.into()))
+}
+
+pub fn topic_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(create_topic, get_topics))
+        .routes(routes!(get_topic, delete_topic))
+        .routes(routes!(get_subscribers))
+        .routes(routes!(add_subscriber, remove_subscriber))
+        .routes(routes!(publish))
+        .routes(routes!(get_publication))
+        .routes(routes!(get_own_subscriptions))
+        .routes(routes!(subscribe, unsubscribe))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::service::topic_service::MockTopicServicePort;
+    use crate::domain::model::priority::Priority;
+    use crate::domain::model::topic::TopicSubscription;
+    use crate::domain::model::topic_publication::{PublicationStatus, TopicPublication};
+    use mockall::predicate::eq;
+
+    fn app_state(mock_service: MockTopicServicePort) -> Arc<AppState> {
+        Arc::new(AppState {
+            topic_api: Arc::new(TopicApi::new(Arc::new(mock_service))),
+            ..AppState::mocked()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_publish_success() {
+        let mut mock_service = MockTopicServicePort::new();
+        mock_service
+            .expect_publish()
+            .with(eq(PublishToTopicCommand {
+                topic_id: "project-x".to_string(),
+                message: "Release 1.0 is out".to_string(),
+                category: None,
+                priority: Priority::High,
+            }))
+            .returning(|command| Ok(TopicPublication::new(
+                command.topic_id, command.message, "general".to_string(), command.priority, 2,
+            )));
+
+        let request = PublishToTopicRequest {
+            message: "Release 1.0 is out".to_string(),
+            category: None,
+            priority: Priority::High,
+        };
+
+        let (status, response) = publish(State(app_state(mock_service)), Path("project-x".to_string()), Json(request))
+            .await
+            .unwrap();
+
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert_eq!(response.status, PublicationStatus::Pending);
+        assert_eq!(response.total_recipients, 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_subscribers_clamps_limit() {
+        let mut mock_service = MockTopicServicePort::new();
+        mock_service
+            .expect_list_subscribers()
+            .with(eq("project-x".to_string()), eq(Some("alice".to_string())), eq(MAX_SUBSCRIBERS_LIMIT))
+            .returning(|topic_id, _, _| Ok(vec![TopicSubscription::new(topic_id, "bob".to_string())]));
+
+        let query = ListTopicSubscribersQuery { after: Some("alice".to_string()), limit: Some(1_000_000) };
+
+        let response = get_subscribers(State(app_state(mock_service)), Path("project-x".to_string()), Query(query))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].recipient, "bob");
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_success() {
+        let mut mock_service = MockTopicServicePort::new();
+        mock_service
+            .expect_subscribe()
+            .with(eq("project-x".to_string()), eq("alice".to_string()))
+            .returning(|topic_id, recipient| Ok(TopicSubscription::new(topic_id, recipient)));
+
+        let response = subscribe(
+            State(app_state(mock_service)),
+            AuthenticatedRecipient("alice".to_string()),
+            Path("project-x".to_string()),
+        ).await.unwrap().0;
+
+        assert_eq!(response.topic_id, "project-x");
+        assert_eq!(response.recipient, "alice");
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_not_subscribed_error() {
+        let mut mock_service = MockTopicServicePort::new();
+        mock_service
+            .expect_unsubscribe()
+            .returning(|topic_id, _| Err(ApplicationError::NotFound(topic_id)));
+
+        let result = unsubscribe(
+            State(app_state(mock_service)),
+            AuthenticatedRecipient("alice".to_string()),
+            Path("project-x".to_string()),
+        ).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+}
@@ -0,0 +1 @@
+pub mod notification_api;
\ No newline at end of file
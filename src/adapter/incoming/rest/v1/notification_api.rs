@@ -0,0 +1,532 @@
+use crate::adapter::incoming::rest::accept_language::AcceptedLanguages;
+use crate::adapter::incoming::rest::authentication::AuthenticatedRecipient;
+use crate::adapter::incoming::rest::etag::{CacheValidators, Conditional, IfMatch, VersionedJson};
+use crate::adapter::incoming::rest::notification_api;
+use crate::adapter::incoming::rest::presentation::Presentation;
+use crate::adapter::incoming::rest::request::create_notification::CreateNotificationRequest;
+use crate::adapter::incoming::rest::request::create_notification_from_template::CreateNotificationFromTemplateRequest;
+use crate::adapter::incoming::rest::request::list_notifications::ListNotificationsQuery;
+use crate::adapter::incoming::rest::request::search_notifications::SearchNotificationsQuery;
+use crate::adapter::incoming::rest::request::update_notification::UpdateNotificationRequest;
+use crate::adapter::incoming::rest::response::envelope::Envelope;
+use crate::adapter::incoming::rest::response::notification::NotificationResponse;
+use crate::adapter::incoming::rest::response::notification_group::NotificationGroupResponse;
+use crate::adapter::incoming::rest::response::notification_history::NotificationHistoryResponse;
+use crate::adapter::incoming::rest::response::notification_search_hit::NotificationSearchHitResponse;
+use crate::adapter::incoming::rest::response::tag_count::TagCountResponse;
+use crate::adapter::incoming::rest::response::unread_count::UnreadCountResponse;
+use crate::application::configuration::application_state::AppState;
+use crate::application::error::ApplicationError;
+use crate::domain::model::notification_history::ChangeSource;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+#[utoipa::path(
+    post,
+    path = "/v1/notifications",
+    tag = "notifications",
+    summary = "Create a notification",
+    request_body = CreateNotificationRequest,
+    responses(
+        (status = 201, description = "The notification created", body = Envelope<NotificationResponse>, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+)]
+pub async fn create_notification(
+    State(state): State<Arc<AppState>>,
+    payload: Json<CreateNotificationRequest>,
+) -> Result<(StatusCode, Envelope<VersionedJson>), ApplicationError> {
+    let notification = notification_api::create_notification(State(state), payload).await?;
+    Ok((StatusCode::CREATED, Envelope::new(notification)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/notifications/from-template",
+    tag = "notifications",
+    summary = "Create a notification from a template",
+    request_body = CreateNotificationFromTemplateRequest,
+    responses(
+        (status = 201, description = "The notification created", body = Envelope<NotificationResponse>, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+)]
+pub async fn create_notification_from_template(
+    State(state): State<Arc<AppState>>,
+    payload: Json<CreateNotificationFromTemplateRequest>,
+) -> Result<(StatusCode, Envelope<VersionedJson>), ApplicationError> {
+    let notification = notification_api::create_notification_from_template(State(state), payload).await?;
+    Ok((StatusCode::CREATED, Envelope::new(notification)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/notifications/{uuid}",
+    tag = "notifications",
+    summary = "Get a notification",
+    params(("uuid" = String, Path, description = "Notification id"), Presentation, CacheValidators),
+    responses(
+        (status = 200, description = "The notification", body = Envelope<NotificationResponse>, headers(
+            ("ETag" = String, description = "Version of the notification, left out when `tz` or `age` is asked for"),
+        )),
+        (status = 304, description = "The copy held is still current"),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_notification(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    Path(uuid): Path<String>,
+    presentation: Presentation,
+    validators: CacheValidators,
+) -> Result<Conditional<Envelope<VersionedJson>>, ApplicationError> {
+    let notification = state.notification_api.notification_service.get_notification_of(uuid, recipient).await?;
+    let validators = if presentation.is_customised() { validators.untagged() } else { validators };
+    Ok(Conditional(Envelope::new(VersionedJson(presentation.present(notification.into()))), validators))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/notifications",
+    tag = "notifications",
+    summary = "List notifications",
+    params(ListNotificationsQuery, Presentation, AcceptedLanguages, CacheValidators),
+    responses(
+        (
+            status = 200,
+            description = "The notifications, pinned ones first, then oldest first",
+            body = Envelope<Vec<NotificationResponse>>,
+            headers(("ETag" = String, description = "Weak tag of the listing, left out when `age` is asked for")),
+        ),
+        (status = 304, description = "The listing held is still current"),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_notifications(
+    State(state): State<Arc<AppState>>,
+    recipient: AuthenticatedRecipient,
+    accepted_languages: AcceptedLanguages,
+    query: Query<ListNotificationsQuery>,
+    presentation: Presentation,
+    validators: CacheValidators,
+) -> Result<Conditional<Json<Envelope<Vec<NotificationResponse>>>>, ApplicationError> {
+    let validators = if presentation.changes_over_time() { validators.untagged() } else { validators };
+    let Conditional(Json(notifications), _) = notification_api::get_notifications(
+        State(state), recipient, accepted_languages, query, presentation, CacheValidators::default(),
+    ).await?;
+    Ok(Conditional(Json(Envelope::new(notifications)), validators))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/v1/notifications/{uuid}",
+    tag = "notifications",
+    summary = "Update a notification",
+    params(("uuid" = String, Path, description = "Notification id"), IfMatch),
+    request_body = UpdateNotificationRequest,
+    responses(
+        (status = 200, description = "The notification", body = Envelope<NotificationResponse>, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+/// Applies the changes given, out of `seen`, `pinned`, `archived` and `snoozed_until`, and returns the notification
+/// as updated.
+pub async fn update_notification(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+    Json(payload): Json<UpdateNotificationRequest>,
+) -> Result<Envelope<VersionedJson>, ApplicationError> {
+    let notification = state.notification_api.notification_service
+        .update_notification(uuid, payload.into(), recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(Envelope::new(VersionedJson(notification.into())))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/notifications/{uuid}",
+    tag = "notifications",
+    summary = "Delete a notification",
+    params(("uuid" = String, Path, description = "Notification id"), IfMatch),
+    responses((status = 204, description = "The notification is deleted")),
+    security(("bearer" = [])),
+)]
+/// Deleted notifications are kept, marked as deleted, so they can still be synced and audited.
+pub async fn delete_notification(
+    State(state): State<Arc<AppState>>,
+    Path(uuid): Path<String>,
+    AuthenticatedRecipient(recipient): AuthenticatedRecipient,
+    IfMatch(expected_version): IfMatch,
+) -> Result<StatusCode, ApplicationError> {
+    state.notification_api.notification_service
+        .mark_as_deleted(uuid, recipient, expected_version, ChangeSource::Rest)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/notifications/search",
+    tag = "notifications",
+    summary = "Search the recipient's notifications",
+    params(SearchNotificationsQuery, Presentation),
+    responses(
+        (
+            status = 200,
+            description = "The matches, most relevant first",
+            body = Envelope<Vec<NotificationSearchHitResponse>>,
+        ),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn search_notifications(
+    state: State<Arc<AppState>>,
+    recipient: AuthenticatedRecipient,
+    query: Query<SearchNotificationsQuery>,
+    presentation: Presentation,
+) -> Result<Json<Envelope<Vec<NotificationSearchHitResponse>>>, ApplicationError> {
+    let Json(hits) = notification_api::search_notifications(state, recipient, query, presentation).await?;
+    Ok(Json(Envelope::new(hits)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/notifications/tags",
+    tag = "notifications",
+    summary = "Count the tags of the inbox",
+    responses((status = 200, description = "The tags, most used first", body = Envelope<Vec<TagCountResponse>>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_tag_counts(
+    state: State<Arc<AppState>>,
+    recipient: AuthenticatedRecipient,
+) -> Result<Json<Envelope<Vec<TagCountResponse>>>, ApplicationError> {
+    let Json(tag_counts) = notification_api::get_tag_counts(state, recipient).await?;
+    Ok(Json(Envelope::new(tag_counts)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/notifications/unread-count",
+    tag = "notifications",
+    summary = "Count the unseen notifications of the inbox",
+    responses((status = 200, description = "The unread count", body = Envelope<UnreadCountResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_unread_count(
+    state: State<Arc<AppState>>,
+    recipient: AuthenticatedRecipient,
+) -> Result<Json<Envelope<UnreadCountResponse>>, ApplicationError> {
+    let Json(unread_count) = notification_api::get_unread_count(state, recipient).await?;
+    Ok(Json(Envelope::new(unread_count)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/notifications/{uuid}/history",
+    tag = "notifications",
+    summary = "Get the history of a notification",
+    params(("uuid" = String, Path, description = "Notification id")),
+    responses(
+        (status = 200, description = "The changes, oldest first", body = Envelope<Vec<NotificationHistoryResponse>>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_notification_history(
+    state: State<Arc<AppState>>,
+    uuid: Path<String>,
+    recipient: AuthenticatedRecipient,
+) -> Result<Json<Envelope<Vec<NotificationHistoryResponse>>>, ApplicationError> {
+    let Json(history) = notification_api::get_notification_history(state, uuid, recipient).await?;
+    Ok(Json(Envelope::new(history)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/notifications/{uuid}/actions/{action_id}",
+    tag = "notifications",
+    summary = "Take an action of a notification",
+    params(
+        ("uuid" = String, Path, description = "Notification id"),
+        ("action_id" = String, Path, description = "Action id"),
+        IfMatch,
+    ),
+    responses(
+        (status = 200, description = "The notification", body = Envelope<NotificationResponse>, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn take_action(
+    state: State<Arc<AppState>>,
+    path: Path<(String, String)>,
+    recipient: AuthenticatedRecipient,
+    if_match: IfMatch,
+) -> Result<Envelope<VersionedJson>, ApplicationError> {
+    Ok(Envelope::new(notification_api::take_action(state, path, recipient, if_match).await?))
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/notifications/{uuid}/tags/{tag}",
+    tag = "notifications",
+    summary = "Tag a notification",
+    params(
+        ("uuid" = String, Path, description = "Notification id"),
+        ("tag" = String, Path, description = "Tag"),
+        IfMatch,
+    ),
+    responses(
+        (status = 200, description = "The notification", body = Envelope<NotificationResponse>, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn add_tag(
+    state: State<Arc<AppState>>,
+    path: Path<(String, String)>,
+    recipient: AuthenticatedRecipient,
+    if_match: IfMatch,
+) -> Result<Envelope<VersionedJson>, ApplicationError> {
+    Ok(Envelope::new(notification_api::add_tag(state, path, recipient, if_match).await?))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/notifications/{uuid}/tags/{tag}",
+    tag = "notifications",
+    summary = "Untag a notification",
+    params(
+        ("uuid" = String, Path, description = "Notification id"),
+        ("tag" = String, Path, description = "Tag"),
+        IfMatch,
+    ),
+    responses(
+        (status = 200, description = "The notification", body = Envelope<NotificationResponse>, headers(
+            ("ETag" = String, description = "Version of the notification"),
+        )),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn remove_tag(
+    state: State<Arc<AppState>>,
+    path: Path<(String, String)>,
+    recipient: AuthenticatedRecipient,
+    if_match: IfMatch,
+) -> Result<Envelope<VersionedJson>, ApplicationError> {
+    Ok(Envelope::new(notification_api::remove_tag(state, path, recipient, if_match).await?))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/notification-groups",
+    tag = "notifications",
+    summary = "List the inbox by group",
+    params(Presentation, AcceptedLanguages),
+    responses(
+        (status = 200, description = "The groups, most recent first", body = Envelope<Vec<NotificationGroupResponse>>),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn get_notification_groups(
+    state: State<Arc<AppState>>,
+    accepted_languages: AcceptedLanguages,
+    recipient: AuthenticatedRecipient,
+    presentation: Presentation,
+) -> Result<Json<Envelope<Vec<NotificationGroupResponse>>>, ApplicationError> {
+    let Json(groups) = notification_api::get_notification_groups(state, accepted_languages, recipient, presentation).await?;
+    Ok(Json(Envelope::new(groups)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/v1/notification-groups/{group_key}",
+    tag = "notifications",
+    summary = "Mark a group as seen",
+    params(("group_key" = String, Path, description = "Group key")),
+    request_body = UpdateNotificationRequest,
+    responses((status = 200, description = "How many notifications changed", body = Envelope<usize>)),
+    security(("bearer" = [])),
+)]
+/// Groups can only be marked as seen, with `{ "seen": true }`; returns how many notifications changed.
+pub async fn update_notification_group(
+    state: State<Arc<AppState>>,
+    group_key: Path<String>,
+    recipient: AuthenticatedRecipient,
+    Json(payload): Json<UpdateNotificationRequest>,
+) -> Result<Json<Envelope<usize>>, ApplicationError> {
+    let only_seen = UpdateNotificationRequest { seen: Some(true), pinned: None, archived: None, snoozed_until: None };
+    if payload != only_seen {
+        return Err(ApplicationError::BadRequest("Groups can only be updated with { \"seen\": true }".to_string()));
+    }
+    let Json(marked) = notification_api::mark_group_as_seen(state, group_key, recipient).await?;
+    Ok(Json(Envelope::new(marked)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/notification-groups/{group_key}",
+    tag = "notifications",
+    summary = "Delete a group",
+    params(("group_key" = String, Path, description = "Group key")),
+    responses((status = 200, description = "How many notifications changed", body = Envelope<usize>)),
+    security(("bearer" = [])),
+)]
+pub async fn delete_notification_group(
+    state: State<Arc<AppState>>,
+    group_key: Path<String>,
+    recipient: AuthenticatedRecipient,
+) -> Result<Json<Envelope<usize>>, ApplicationError> {
+    let Json(marked) = notification_api::mark_group_as_deleted(state, group_key, recipient).await?;
+    Ok(Json(Envelope::new(marked)))
+}
+
+pub fn notification_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(create_notification, get_notifications))
+        .routes(routes!(create_notification_from_template))
+        .routes(routes!(search_notifications))
+        .routes(routes!(get_tag_counts))
+        .routes(routes!(get_unread_count))
+        .routes(routes!(get_notification, update_notification, delete_notification))
+        .routes(routes!(take_action))
+        .routes(routes!(get_notification_history))
+        .routes(routes!(add_tag, remove_tag))
+        .routes(routes!(get_notification_groups))
+        .routes(routes!(update_notification_group, delete_notification_group))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::incoming::rest::notification_api::{create_router, NotificationApi};
+    use crate::application::command::update_notification_command::UpdateNotificationCommand;
+    use crate::application::service::notification_service::MockNotificationServicePort;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::state_change::StateChange;
+    use mockall::predicate::eq;
+    use tokio::net::TcpListener;
+    use uuid::Uuid;
+
+    fn app_state(mock_service: MockNotificationServicePort) -> Arc<AppState> {
+        Arc::new(AppState {
+            notification_api: Arc::new(NotificationApi::new(Arc::new(mock_service), "unassigned".to_string())),
+            ..AppState::mocked()
+        })
+    }
+
+    fn recipient() -> AuthenticatedRecipient {
+        AuthenticatedRecipient("recipient".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_get_notification_of_authenticated_recipient() {
+        let mut mock_service = MockNotificationServicePort::new();
+        let notification = Notification::new("recipient".to_string(), "message".to_string());
+        let uuid = notification.uuid.to_string();
+
+        mock_service
+            .expect_get_notification_of()
+            .with(eq(uuid.clone()), eq("recipient".to_string()))
+            .returning(move |_, _| Ok(notification.clone()));
+
+        let result = get_notification(
+            State(app_state(mock_service)), recipient(), Path(uuid), Presentation::default(), CacheValidators::default(),
+        ).await.unwrap();
+
+        assert_eq!(result.0.data.0.recipient, "recipient");
+    }
+
+    #[tokio::test]
+    async fn test_update_notification_success() {
+        let mut mock_service = MockNotificationServicePort::new();
+        let uuid = Uuid::new_v4().to_string();
+        let command = UpdateNotificationCommand { pinned: Some(true), ..UpdateNotificationCommand::default() };
+
+        mock_service
+            .expect_update_notification()
+            .with(eq(uuid.clone()), eq(command), eq("recipient".to_string()), eq(Some(3)), eq(ChangeSource::Rest))
+            .returning(|_, _, recipient, _, _| {
+                Ok(Notification { pinned: true, version: 4, ..Notification::new(recipient, "message".to_string()) })
+            });
+
+        let payload = UpdateNotificationRequest { seen: None, pinned: Some(true), archived: None, snoozed_until: None };
+        let result = update_notification(
+            State(app_state(mock_service)),
+            Path(uuid),
+            recipient(),
+            IfMatch(Some(3)),
+            Json(payload),
+        ).await.unwrap();
+
+        assert!(result.data.0.pinned);
+        assert_eq!(result.data.0.version, 4);
+    }
+
+    #[tokio::test]
+    async fn test_delete_notification_no_content() {
+        let mut mock_service = MockNotificationServicePort::new();
+        let uuid = Uuid::new_v4().to_string();
+
+        mock_service
+            .expect_mark_as_deleted()
+            .with(eq(uuid.clone()), eq("recipient".to_string()), eq(None), eq(ChangeSource::Rest))
+            .returning(|_, recipient, _, _| {
+                let notification = Notification::new(recipient, "message".to_string());
+                Ok(StateChange { notification, changed: true })
+            });
+
+        let result = delete_notification(
+            State(app_state(mock_service)), Path(uuid), recipient(), IfMatch(None),
+        ).await;
+
+        assert_eq!(result.unwrap(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_update_notification_group_only_marks_as_seen() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service.expect_mark_group_as_seen().never();
+
+        let payload = UpdateNotificationRequest { seen: Some(true), pinned: Some(true), archived: None, snoozed_until: None };
+        let result = update_notification_group(
+            State(app_state(mock_service)),
+            Path("group".to_string()),
+            recipient(),
+            Json(payload),
+        ).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_inbox_routes_require_authentication() {
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service.expect_list_notifications().never();
+        mock_service.expect_set_pinned().never();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, create_router(app_state(mock_service))).await.unwrap() });
+
+        let client = reqwest::Client::new();
+        let listed = client.get(format!("http://{}/v1/notifications?recipient=recipient", address)).send().await.unwrap();
+        let pinned = client.post(format!("http://{}/notification/pinned/{}?recipient=recipient", address, Uuid::new_v4()))
+            .send().await.unwrap();
+
+        assert_eq!(listed.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(pinned.status(), StatusCode::UNAUTHORIZED);
+    }
+}
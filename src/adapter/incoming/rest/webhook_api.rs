@@ -0,0 +1,287 @@
+use crate::adapter::incoming::rest::authentication::AuthenticatedAdministrator;
+use crate::adapter::incoming::rest::request::create_webhook_subscription::CreateWebhookSubscriptionRequest;
+use crate::adapter::incoming::rest::response::digest::DigestResponse;
+use crate::adapter::incoming::rest::response::webhook_delivery_attempt::WebhookDeliveryAttemptResponse;
+use crate::adapter::incoming::rest::response::webhook_subscription::WebhookSubscriptionResponse;
+use crate::application::configuration::application_state::AppState;
+use crate::application::error::ApplicationError;
+use crate::application::service::webhook_service::WebhookServicePort;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+pub struct WebhookApi {
+    webhook_service: Arc<dyn WebhookServicePort>,
+}
+
+impl WebhookApi {
+    pub fn new(webhook_service: Arc<dyn WebhookServicePort>) -> Self {
+        Self { webhook_service }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/webhook",
+    tag = "webhooks",
+    summary = "Subscribe a webhook",
+    request_body = CreateWebhookSubscriptionRequest,
+    responses(
+        (status = 201, description = "The subscription created, with its secret", body = WebhookSubscriptionResponse),
+    ),
+    security(("bearer" = [])),
+)]
+pub async fn create_subscription(
+    State(state): State<Arc<AppState>>,
+    _administrator: AuthenticatedAdministrator,
+    Json(payload): Json<CreateWebhookSubscriptionRequest>,
+) -> Result<(StatusCode, Json<WebhookSubscriptionResponse>), ApplicationError> {
+    let subscription = state.webhook_api.webhook_service
+        .create_subscription(payload.url, payload.secret, payload.events, payload.channel)
+        .await?;
+    Ok((StatusCode::CREATED, Json(WebhookSubscriptionResponse::with_secret(subscription))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/webhook/{uuid}",
+    tag = "webhooks",
+    summary = "Get a webhook subscription",
+    params(("uuid" = String, Path, description = "Subscription id")),
+    responses((status = 200, description = "The subscription", body = WebhookSubscriptionResponse)),
+    security(("bearer" = [])),
+)]
+pub async fn get_subscription(
+    State(state): State<Arc<AppState>>,
+    _administrator: AuthenticatedAdministrator,
+    Path(uuid): Path<String>,
+) -> Result<Json<WebhookSubscriptionResponse>, ApplicationError> {
+    let subscription = state.webhook_api.webhook_service.get_subscription(uuid).await?;
+    Ok(Json(subscription.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/webhook",
+    tag = "webhooks",
+    summary = "List webhook subscriptions",
+    responses((status = 200, description = "The subscriptions", body = Vec<WebhookSubscriptionResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_subscriptions(
+    State(state): State<Arc<AppState>>,
+    _administrator: AuthenticatedAdministrator,
+) -> Result<Json<Vec<WebhookSubscriptionResponse>>, ApplicationError> {
+    let subscriptions = state.webhook_api.webhook_service.list_subscriptions().await?;
+    Ok(Json(subscriptions.into_iter().map(|subscription| subscription.into()).collect()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/webhook/{uuid}",
+    tag = "webhooks",
+    summary = "Delete a webhook subscription",
+    params(("uuid" = String, Path, description = "Subscription id")),
+    responses((status = 204, description = "The subscription is deleted")),
+    security(("bearer" = [])),
+)]
+pub async fn delete_subscription(
+    State(state): State<Arc<AppState>>,
+    _administrator: AuthenticatedAdministrator,
+    Path(uuid): Path<String>,
+) -> Result<StatusCode, ApplicationError> {
+    state.webhook_api.webhook_service.delete_subscription(uuid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/webhook/{uuid}/attempts",
+    tag = "webhooks",
+    summary = "List the delivery attempts of a subscription",
+    params(("uuid" = String, Path, description = "Subscription id")),
+    responses((status = 200, description = "The attempts", body = Vec<WebhookDeliveryAttemptResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_delivery_attempts(
+    State(state): State<Arc<AppState>>,
+    _administrator: AuthenticatedAdministrator,
+    Path(uuid): Path<String>,
+) -> Result<Json<Vec<WebhookDeliveryAttemptResponse>>, ApplicationError> {
+    let attempts = state.webhook_api.webhook_service.list_delivery_attempts(uuid).await?;
+    Ok(Json(attempts.into_iter().map(|attempt| attempt.into()).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/webhook/{uuid}/digests",
+    tag = "webhooks",
+    summary = "List the digests sent to a subscription",
+    params(("uuid" = String, Path, description = "Subscription id")),
+    responses((status = 200, description = "The digests", body = Vec<DigestResponse>)),
+    security(("bearer" = [])),
+)]
+pub async fn get_digests(
+    State(state): State<Arc<AppState>>,
+    _administrator: AuthenticatedAdministrator,
+    Path(uuid): Path<String>,
+) -> Result<Json<Vec<DigestResponse>>, ApplicationError> {
+    let digests = state.webhook_api.webhook_service.list_digests(uuid).await?;
+    Ok(Json(digests.into_iter().map(|digest| digest.into()).collect()))
+}
+
+pub fn webhook_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(create_subscription, get_subscriptions))
+        .routes(routes!(get_subscription, delete_subscription))
+        .routes(routes!(get_delivery_attempts))
+        .routes(routes!(get_digests))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::incoming::rest::notification_api::create_router;
+    use crate::application::service::authentication_service::MockAuthenticationServicePort;
+    use crate::application::service::webhook_service::MockWebhookServicePort;
+    use crate::domain::model::digest::{Digest, DigestStatus};
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::NotificationEventType;
+    use crate::domain::model::webhook_delivery_attempt::WebhookDeliveryAttempt;
+    use crate::domain::model::webhook_subscription::WebhookSubscription;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+    use tokio::net::TcpListener;
+    use uuid::Uuid;
+
+    fn app_state(mock_service: MockWebhookServicePort) -> Arc<AppState> {
+        Arc::new(AppState {
+            webhook_api: Arc::new(WebhookApi::new(Arc::new(mock_service))),
+            ..AppState::mocked()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_subscription_returns_secret() {
+        let mut mock_service = MockWebhookServicePort::new();
+        mock_service
+            .expect_create_subscription()
+            .with(eq("https://example.com/hook".to_string()), eq(None), eq(vec![NotificationEventType::Seen]), eq(None))
+            .returning(|url, _, events, _| Ok(WebhookSubscription::new(url, "generated".to_string(), events)));
+
+        let request = CreateWebhookSubscriptionRequest {
+            url: "https://example.com/hook".to_string(),
+            secret: None,
+            events: vec![NotificationEventType::Seen],
+            channel: None,
+        };
+
+        let (status, Json(response)) = create_subscription(State(app_state(mock_service)), AuthenticatedAdministrator, Json(request)).await.unwrap();
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(response.url, "https://example.com/hook");
+        assert_eq!(response.secret, Some("generated".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_subscriptions_hides_secret() {
+        let mut mock_service = MockWebhookServicePort::new();
+        mock_service
+            .expect_list_subscriptions()
+            .returning(|| Ok(vec![
+                WebhookSubscription::new("https://example.com/hook".to_string(), "secret".to_string(), vec![]),
+            ]));
+
+        let response = get_subscriptions(State(app_state(mock_service)), AuthenticatedAdministrator).await.unwrap().0;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].secret, None);
+    }
+
+    #[tokio::test]
+    async fn test_delete_subscription_success() {
+        let uuid = Uuid::new_v4().to_string();
+        let mut mock_service = MockWebhookServicePort::new();
+        mock_service
+            .expect_delete_subscription()
+            .with(eq(uuid.clone()))
+            .returning(|_| Ok(()));
+
+        let result = delete_subscription(State(app_state(mock_service)), AuthenticatedAdministrator, Path(uuid)).await;
+
+        assert_eq!(result.unwrap(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_get_delivery_attempts_success() {
+        let uuid = Uuid::new_v4();
+        let mut mock_service = MockWebhookServicePort::new();
+        mock_service
+            .expect_list_delivery_attempts()
+            .with(eq(uuid.to_string()))
+            .returning(move |_| Ok(vec![
+                WebhookDeliveryAttempt::new(uuid, Uuid::new_v4(), NotificationEventType::Created, 1, Some(500), None),
+                WebhookDeliveryAttempt::new(uuid, Uuid::new_v4(), NotificationEventType::Created, 2, Some(200), None),
+            ]));
+
+        let response = get_delivery_attempts(State(app_state(mock_service)), AuthenticatedAdministrator, Path(uuid.to_string())).await.unwrap().0;
+
+        assert_eq!(response.len(), 2);
+        assert!(!response[0].success);
+        assert!(response[1].success);
+        assert_eq!(response[1].attempt, 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_digests_success() {
+        let uuid = Uuid::new_v4();
+        let mut mock_service = MockWebhookServicePort::new();
+        mock_service
+            .expect_list_digests()
+            .with(eq(uuid.to_string()))
+            .returning(move |_| {
+                let mut digest = Digest::new(uuid, "recipient".to_string(), Utc::now());
+                digest.notifications.push(Notification::new("recipient".to_string(), "message".to_string()));
+                Ok(vec![digest])
+            });
+
+        let response = get_digests(State(app_state(mock_service)), AuthenticatedAdministrator, Path(uuid.to_string())).await.unwrap().0;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].recipient, "recipient");
+        assert_eq!(response[0].status, DigestStatus::Open);
+        assert_eq!(response[0].notifications.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_routes_require_an_administrator() {
+        let mut mock_service = MockWebhookServicePort::new();
+        mock_service.expect_create_subscription().never();
+        mock_service.expect_list_subscriptions().never();
+        let mut authentication_service = MockAuthenticationServicePort::new();
+        authentication_service.expect_authenticate()
+            .returning(|token| Ok(token.to_string()));
+        authentication_service.expect_is_administrator()
+            .returning(|_| false);
+        let state = Arc::new(AppState {
+            authentication_service: Arc::new(authentication_service),
+            ..Arc::into_inner(app_state(mock_service)).unwrap()
+        });
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, create_router(state)).await.unwrap() });
+
+        let client = reqwest::Client::new();
+        let anonymous = client.post(format!("http://{}/webhook", address))
+            .json(&serde_json::json!({ "url": "https://example.com/hook" }))
+            .send().await.unwrap();
+        let recipient = client.get(format!("http://{}/webhook", address)).bearer_auth("recipient").send().await.unwrap();
+
+        assert_eq!(anonymous.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(recipient.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1 @@
+pub mod sns_envelope;
\ No newline at end of file
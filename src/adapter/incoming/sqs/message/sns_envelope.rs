@@ -0,0 +1,55 @@
+use crate::adapter::incoming::rest::request::create_notification::CreateNotificationRequest;
+use serde::Deserialize;
+
+const SNS_NOTIFICATION_TYPE: &str = "Notification";
+
+/// Envelope SNS wraps around a message when the queue subscription does not use raw delivery.
+#[derive(Debug, Deserialize)]
+pub struct SnsEnvelope {
+    #[serde(rename = "Type")]
+    pub message_type: String,
+    #[serde(rename = "Message")]
+    pub message: String,
+}
+
+pub fn parse_create_notification(body: &str) -> Result<CreateNotificationRequest, serde_json::Error> {
+    match serde_json::from_str::<SnsEnvelope>(body) {
+        Ok(envelope) if envelope.message_type == SNS_NOTIFICATION_TYPE => serde_json::from_str(&envelope.message),
+        _ => serde_json::from_str(body),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_raw_message_success() {
+        let body = json!({ "recipient": "recipient", "message": "raw message" }).to_string();
+
+        let result = parse_create_notification(&body).unwrap();
+
+        assert_eq!(result.message, "raw message");
+    }
+
+    #[test]
+    fn test_parse_sns_envelope_success() {
+        let body = json!({
+            "Type": "Notification",
+            "MessageId": "a8b1c3d4",
+            "TopicArn": "arn:aws:sns:us-east-1:000000000000:notification-create",
+            "Message": json!({ "recipient": "recipient", "message": "wrapped message" }).to_string(),
+        }).to_string();
+
+        let result = parse_create_notification(&body).unwrap();
+
+        assert_eq!(result.message, "wrapped message");
+    }
+
+    #[test]
+    fn test_parse_invalid_message_error() {
+        assert!(parse_create_notification("not json").is_err());
+        assert!(parse_create_notification(&json!({ "recipient": "missing message" }).to_string()).is_err());
+    }
+}
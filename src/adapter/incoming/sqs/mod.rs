@@ -0,0 +1,3 @@
+pub mod message;
+pub mod notification_consumer;
+pub mod sqs_client;
\ No newline at end of file
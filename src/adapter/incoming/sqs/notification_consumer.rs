@@ -0,0 +1,257 @@
+use crate::adapter::incoming::sqs::message::sns_envelope::parse_create_notification;
+use crate::adapter::incoming::sqs::sqs_client::{QueueMessage, SqsClient};
+use crate::application::configuration::structs::SqsSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::notification_service::NotificationServicePort;
+use std::sync::Arc;
+use std::time::Duration;
+
+const POLL_ERROR_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Long-polls the queue subscribed to the notification-creation topic and creates a notification per message.
+///
+/// A message is only deleted once the notification has been persisted. Messages that cannot be parsed, or that
+/// keep failing after `max_receive_count` deliveries, are forwarded to the dead-letter queue.
+pub struct NotificationConsumer {
+    notification_service: Arc<dyn NotificationServicePort + Send + Sync>,
+    sqs_client: Arc<dyn SqsClient>,
+    settings: SqsSettings,
+    default_recipient: String,
+}
+
+impl NotificationConsumer {
+    pub fn new(
+        notification_service: Arc<dyn NotificationServicePort + Send + Sync>,
+        sqs_client: Arc<dyn SqsClient>,
+        settings: SqsSettings,
+        default_recipient: String,
+    ) -> Self {
+        Self { notification_service, sqs_client, settings, default_recipient }
+    }
+
+    pub async fn run(&self) {
+        loop {
+            if let Err(error) = self.poll().await {
+                eprintln!("Failed to poll {}: {}", self.settings.queue_url, error);
+                tokio::time::sleep(POLL_ERROR_BACKOFF).await;
+            }
+        }
+    }
+
+    pub async fn poll(&self) -> Result<usize, ApplicationError> {
+        let messages = self.sqs_client.receive_messages(
+            &self.settings.queue_url,
+            self.settings.max_number_of_messages,
+            self.settings.wait_time_seconds,
+        ).await?;
+
+        let received = messages.len();
+        for message in messages {
+            let message_id = message.message_id.clone();
+            // A message that could not be acknowledged is delivered again, so the rest of the batch goes on.
+            if let Err(error) = self.handle_message(message).await {
+                eprintln!("Failed to handle message {}: {}", message_id, error);
+            }
+        }
+
+        Ok(received)
+    }
+
+    async fn handle_message(&self, message: QueueMessage) -> Result<(), ApplicationError> {
+        let request = match parse_create_notification(&message.body) {
+            Ok(request) => request,
+            Err(error) => {
+                eprintln!("Message {} is not a valid notification request: {}", message.message_id, error);
+                return self.dead_letter(&message).await;
+            }
+        };
+
+        match self.notification_service.create_notification(request.into_command(&self.default_recipient)).await {
+            Ok(_) => self.sqs_client.delete_message(&self.settings.queue_url, &message.receipt_handle).await,
+            Err(error) if message.receive_count >= self.settings.max_receive_count => {
+                eprintln!("Message {} failed {} times: {}", message.message_id, message.receive_count, error);
+                self.dead_letter(&message).await
+            }
+            // Left on the queue so it becomes visible again once the visibility timeout expires.
+            Err(_) => Ok(()),
+        }
+    }
+
+    async fn dead_letter(&self, message: &QueueMessage) -> Result<(), ApplicationError> {
+        self.sqs_client.send_message(&self.settings.dead_letter_queue_url, &message.body).await?;
+        self.sqs_client.delete_message(&self.settings.queue_url, &message.receipt_handle).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::incoming::sqs::sqs_client::MockSqsClient;
+    use crate::application::command::create_notification_command::CreateNotificationCommand;
+    use crate::application::service::notification_service::MockNotificationServicePort;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::priority::Priority;
+    use mockall::predicate::eq;
+    use serde_json::json;
+
+    fn settings() -> SqsSettings {
+        SqsSettings {
+            enabled: true,
+            endpoint: "http://localhost:9324".to_string(),
+            queue_url: "queue".to_string(),
+            dead_letter_queue_url: "dead-letter-queue".to_string(),
+            wait_time_seconds: 0,
+            max_number_of_messages: 10,
+            max_receive_count: 3,
+        }
+    }
+
+    fn queue_message(body: String, receive_count: u32) -> QueueMessage {
+        QueueMessage {
+            message_id: "message-id".to_string(),
+            receipt_handle: "receipt-handle".to_string(),
+            body,
+            receive_count,
+        }
+    }
+
+    fn consumer(
+        mock_service: MockNotificationServicePort,
+        mock_client: MockSqsClient,
+    ) -> NotificationConsumer {
+        NotificationConsumer::new(Arc::new(mock_service), Arc::new(mock_client), settings(), "unassigned".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_poll_creates_notification_and_acknowledges() {
+        let body = json!({ "recipient": "recipient", "message": "queued message" }).to_string();
+
+        let mut mock_client = MockSqsClient::new();
+        mock_client.expect_receive_messages()
+            .times(1)
+            .returning(move |_, _, _| Ok(vec![queue_message(body.clone(), 1)]));
+        mock_client.expect_delete_message()
+            .with(eq("queue"), eq("receipt-handle"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_client.expect_send_message().never();
+
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service.expect_create_notification()
+            .with(eq(CreateNotificationCommand {
+                recipient: "recipient".to_string(),
+                message: "queued message".to_string(),
+                category: None,
+                priority: Priority::Normal,
+                group_key: None,
+                actor: None,
+                actions: vec![],
+                auto_resolve: false,
+                tags: vec![],
+            }))
+            .times(1)
+            .returning(|command| Ok(Notification::new(command.recipient, command.message)));
+
+        let result = consumer(mock_service, mock_client).poll().await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_poll_routes_poison_message_to_dead_letter_queue() {
+        let mut mock_client = MockSqsClient::new();
+        mock_client.expect_receive_messages()
+            .returning(|_, _, _| Ok(vec![queue_message("not json".to_string(), 1)]));
+        mock_client.expect_send_message()
+            .with(eq("dead-letter-queue"), eq("not json"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_client.expect_delete_message()
+            .with(eq("queue"), eq("receipt-handle"))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service.expect_create_notification().never();
+
+        let result = consumer(mock_service, mock_client).poll().await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_poll_does_not_acknowledge_when_persistence_fails() {
+        let body = json!({ "recipient": "recipient", "message": "queued message" }).to_string();
+
+        let mut mock_client = MockSqsClient::new();
+        mock_client.expect_receive_messages()
+            .returning(move |_, _, _| Ok(vec![queue_message(body.clone(), 1)]));
+        mock_client.expect_delete_message().never();
+        mock_client.expect_send_message().never();
+
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service.expect_create_notification()
+            .times(1)
+            .returning(|_| Err(ApplicationError::InternalError("database down".to_string())));
+
+        let result = consumer(mock_service, mock_client).poll().await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_poll_dead_letters_after_max_receive_count() {
+        let body = json!({ "recipient": "recipient", "message": "queued message" }).to_string();
+        let expected_body = body.clone();
+
+        let mut mock_client = MockSqsClient::new();
+        mock_client.expect_receive_messages()
+            .returning(move |_, _, _| Ok(vec![queue_message(body.clone(), 3)]));
+        mock_client.expect_send_message()
+            .withf(move |queue_url, body| queue_url == "dead-letter-queue" && body == expected_body)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_client.expect_delete_message()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service.expect_create_notification()
+            .returning(|_| Err(ApplicationError::InternalError("database down".to_string())));
+
+        let result = consumer(mock_service, mock_client).poll().await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_poll_handles_rest_of_batch_after_failure() {
+        let mut mock_client = MockSqsClient::new();
+        mock_client.expect_receive_messages()
+            .returning(|_, _, _| Ok(vec![queue_message("not json".to_string(), 1), queue_message("not json".to_string(), 1)]));
+        mock_client.expect_send_message()
+            .times(1)
+            .returning(|_, _| Err(ApplicationError::InternalError("unreachable".to_string())));
+        mock_client.expect_send_message()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_client.expect_delete_message()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let result = consumer(MockNotificationServicePort::new(), mock_client).poll().await;
+
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_poll_receive_error() {
+        let mut mock_client = MockSqsClient::new();
+        mock_client.expect_receive_messages()
+            .returning(|_, _, _| Err(ApplicationError::InternalError("unreachable".to_string())));
+
+        let result = consumer(MockNotificationServicePort::new(), mock_client).poll().await;
+
+        assert!(result.is_err());
+    }
+}
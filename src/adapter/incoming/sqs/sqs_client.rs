@@ -0,0 +1,197 @@
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const CONTENT_TYPE: &str = "application/x-amz-json-1.0";
+const TARGET_PREFIX: &str = "AmazonSQS";
+const APPROXIMATE_RECEIVE_COUNT: &str = "ApproximateReceiveCount";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueMessage {
+    pub message_id: String,
+    pub receipt_handle: String,
+    pub body: String,
+    pub receive_count: u32,
+}
+
+#[automock]
+#[async_trait]
+pub trait SqsClient: Send + Sync {
+    async fn receive_messages(
+        &self,
+        queue_url: &str,
+        max_number_of_messages: u8,
+        wait_time_seconds: u8,
+    ) -> Result<Vec<QueueMessage>, ApplicationError>;
+
+    async fn delete_message(&self, queue_url: &str, receipt_handle: &str) -> Result<(), ApplicationError>;
+
+    async fn send_message(&self, queue_url: &str, body: &str) -> Result<(), ApplicationError>;
+}
+
+/// Talks to SQS (or a local emulator such as ElasticMQ or LocalStack) through the AWS JSON protocol.
+pub struct HttpSqsClient {
+    http_client: reqwest::Client,
+    endpoint: String,
+}
+
+impl HttpSqsClient {
+    pub fn new(endpoint: String) -> Self {
+        Self { http_client: reqwest::Client::new(), endpoint }
+    }
+
+    async fn call<T: DeserializeOwned>(&self, action: &str, payload: Value) -> Result<T, ApplicationError> {
+        let response = self.http_client.post(&self.endpoint)
+            .header(reqwest::header::CONTENT_TYPE, CONTENT_TYPE)
+            .header("X-Amz-Target", format!("{}.{}", TARGET_PREFIX, action))
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|error| ApplicationError::InternalError(format!("SQS {} failed: {}", action, error)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ApplicationError::InternalError(format!("SQS {} returned {}: {}", action, status, body)));
+        }
+
+        response.json::<T>()
+            .await
+            .map_err(|error| ApplicationError::InternalError(format!("SQS {} returned an invalid body: {}", action, error)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ReceiveMessageResponse {
+    #[serde(rename = "Messages", default)]
+    messages: Vec<ReceivedMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReceivedMessage {
+    #[serde(rename = "MessageId")]
+    message_id: String,
+    #[serde(rename = "ReceiptHandle")]
+    receipt_handle: String,
+    #[serde(rename = "Body")]
+    body: String,
+    #[serde(rename = "Attributes", default)]
+    attributes: HashMap<String, String>,
+}
+
+impl From<ReceivedMessage> for QueueMessage {
+    fn from(message: ReceivedMessage) -> Self {
+        let receive_count = message.attributes.get(APPROXIMATE_RECEIVE_COUNT)
+            .and_then(|count| count.parse().ok())
+            .unwrap_or(1);
+
+        Self {
+            message_id: message.message_id,
+            receipt_handle: message.receipt_handle,
+            body: message.body,
+            receive_count,
+        }
+    }
+}
+
+#[async_trait]
+impl SqsClient for HttpSqsClient {
+    async fn receive_messages(
+        &self,
+        queue_url: &str,
+        max_number_of_messages: u8,
+        wait_time_seconds: u8,
+    ) -> Result<Vec<QueueMessage>, ApplicationError> {
+        let payload = json!({
+            "QueueUrl": queue_url,
+            "MaxNumberOfMessages": max_number_of_messages,
+            "WaitTimeSeconds": wait_time_seconds,
+            "AttributeNames": [APPROXIMATE_RECEIVE_COUNT],
+            "MessageSystemAttributeNames": [APPROXIMATE_RECEIVE_COUNT],
+        });
+
+        let response: ReceiveMessageResponse = self.call("ReceiveMessage", payload).await?;
+        Ok(response.messages.into_iter().map(QueueMessage::from).collect())
+    }
+
+    async fn delete_message(&self, queue_url: &str, receipt_handle: &str) -> Result<(), ApplicationError> {
+        let payload = json!({ "QueueUrl": queue_url, "ReceiptHandle": receipt_handle });
+        self.call::<Value>("DeleteMessage", payload).await?;
+        Ok(())
+    }
+
+    async fn send_message(&self, queue_url: &str, body: &str) -> Result<(), ApplicationError> {
+        let payload = json!({ "QueueUrl": queue_url, "MessageBody": body });
+        self.call::<Value>("SendMessage", payload).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderMap;
+    use axum::routing::post;
+    use axum::{Json, Router};
+    use tokio::net::TcpListener;
+
+    async fn start_emulator() -> String {
+        async fn handle(headers: HeaderMap, body: String) -> Json<Value> {
+            let payload: Value = serde_json::from_str(&body).unwrap();
+            match headers.get("X-Amz-Target").and_then(|target| target.to_str().ok()) {
+                Some("AmazonSQS.ReceiveMessage") => Json(json!({
+                    "Messages": [{
+                        "MessageId": "message-id",
+                        "ReceiptHandle": "receipt-handle",
+                        "Body": payload["QueueUrl"],
+                        "Attributes": { "ApproximateReceiveCount": "3" },
+                    }]
+                })),
+                _ => Json(json!({})),
+            }
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, Router::new().route("/", post(handle))).await.unwrap();
+        });
+
+        format!("http://{}/", address)
+    }
+
+    #[tokio::test]
+    async fn test_receive_messages_success() {
+        let client = HttpSqsClient::new(start_emulator().await);
+
+        let result = client.receive_messages("queue-url", 10, 0).await.unwrap();
+
+        assert_eq!(result, vec![QueueMessage {
+            message_id: "message-id".to_string(),
+            receipt_handle: "receipt-handle".to_string(),
+            body: "queue-url".to_string(),
+            receive_count: 3,
+        }]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_and_send_message_success() {
+        let client = HttpSqsClient::new(start_emulator().await);
+
+        assert!(client.delete_message("queue-url", "receipt-handle").await.is_ok());
+        assert!(client.send_message("queue-url", "body").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_receive_messages_unreachable_error() {
+        let client = HttpSqsClient::new("http://127.0.0.1:1/".to_string());
+
+        let result = client.receive_messages("queue-url", 10, 0).await;
+
+        assert!(matches!(result, Err(ApplicationError::InternalError(_))));
+    }
+}
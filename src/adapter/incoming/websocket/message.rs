@@ -0,0 +1,102 @@
+use crate::adapter::incoming::rest::response::notification::NotificationResponse;
+use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+use serde::{Deserialize, Serialize};
+
+pub const PROTOCOL_VERSION: u8 = 1;
+
+/// Command sent by a client, e.g. `{ "version": 1, "type": "mark_seen", "request_id": "1", "uuid": "..." }`.
+#[derive(Debug, Deserialize)]
+pub struct ClientEnvelope {
+    pub version: u8,
+    #[serde(flatten)]
+    pub message: ClientMessage,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    MarkSeen { request_id: String, uuid: String },
+    MarkDeleted { request_id: String, uuid: String },
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServerEnvelope {
+    pub version: u8,
+    #[serde(flatten)]
+    pub message: ServerMessage,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Event {
+        event_id: String,
+        event: NotificationEventType,
+        notification: Box<NotificationResponse>,
+    },
+    Ack {
+        request_id: String,
+        success: bool,
+        /// False when the notification was already in the state asked for.
+        changed: bool,
+    },
+    Error {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
+        error: String,
+    },
+}
+
+impl From<ServerMessage> for ServerEnvelope {
+    fn from(message: ServerMessage) -> Self {
+        Self { version: PROTOCOL_VERSION, message }
+    }
+}
+
+impl From<NotificationEvent> for ServerMessage {
+    fn from(event: NotificationEvent) -> Self {
+        ServerMessage::Event {
+            event_id: event.uuid.to_string(),
+            event: event.event_type,
+            notification: Box::new(event.notification.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_deserialize_client_message() {
+        let envelope: ClientEnvelope = serde_json::from_value(json!({
+            "version": 1,
+            "type": "mark_deleted",
+            "request_id": "42",
+            "uuid": "some-uuid",
+        })).unwrap();
+
+        assert_eq!(envelope.version, 1);
+        assert_eq!(envelope.message, ClientMessage::MarkDeleted {
+            request_id: "42".to_string(),
+            uuid: "some-uuid".to_string(),
+        });
+    }
+
+    #[test]
+    fn test_deserialize_unknown_client_message_error() {
+        let result = serde_json::from_value::<ClientEnvelope>(json!({ "version": 1, "type": "archive" }));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_serialize_server_message() {
+        let envelope = ServerEnvelope::from(ServerMessage::Ack { request_id: "42".to_string(), success: true, changed: false });
+
+        let result: Value = serde_json::to_value(envelope).unwrap();
+
+        assert_eq!(result, json!({ "version": 1, "type": "ack", "request_id": "42", "success": true, "changed": false }));
+    }
+}
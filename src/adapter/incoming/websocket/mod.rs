@@ -0,0 +1,2 @@
+pub mod message;
+pub mod notification_socket_api;
\ No newline at end of file
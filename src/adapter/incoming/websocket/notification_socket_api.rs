@@ -0,0 +1,308 @@
+use crate::adapter::incoming::rest::authentication::StreamingRecipient;
+use crate::adapter::incoming::websocket::message::{ClientEnvelope, ClientMessage, ServerEnvelope, ServerMessage, PROTOCOL_VERSION};
+use crate::application::configuration::application_state::AppState;
+use crate::application::service::notification_service::NotificationServicePort;
+use crate::application::service::notification_stream_service::NotificationStreamServicePort;
+use crate::domain::model::notification_history::ChangeSource;
+use axum::extract::ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::response::Response;
+use futures_util::stream::SplitSink;
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use std::sync::Arc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{mpsc, oneshot};
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+/// Messages queued for a connection before it is considered a slow consumer and closed.
+const OUTBOUND_CAPACITY: usize = 64;
+
+pub struct NotificationSocketApi {
+    notification_service: Arc<dyn NotificationServicePort + Send + Sync>,
+    notification_stream_service: Arc<dyn NotificationStreamServicePort>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ConnectParameters {
+    /// Id of the last event received, to resume from.
+    last_event_id: Option<String>,
+}
+
+impl NotificationSocketApi {
+    pub fn new(
+        notification_service: Arc<dyn NotificationServicePort + Send + Sync>,
+        notification_stream_service: Arc<dyn NotificationStreamServicePort>,
+    ) -> Self {
+        Self { notification_service, notification_stream_service }
+    }
+
+    pub async fn handle(&self, recipient: &str, text: &str) -> ServerMessage {
+        let envelope = match serde_json::from_str::<ClientEnvelope>(text) {
+            Ok(envelope) if envelope.version == PROTOCOL_VERSION => envelope,
+            Ok(envelope) => return ServerMessage::Error {
+                request_id: None,
+                error: format!("Unsupported protocol version {}, expected {}", envelope.version, PROTOCOL_VERSION),
+            },
+            Err(error) => return ServerMessage::Error { request_id: None, error: format!("Invalid message: {}", error) },
+        };
+
+        let (request_id, result) = match envelope.message {
+            ClientMessage::MarkSeen { request_id, uuid } => {
+                let result = self.notification_service
+                    .mark_as_seen(uuid, recipient.to_string(), None, ChangeSource::WebSocket)
+                    .await;
+                (request_id, result)
+            }
+            ClientMessage::MarkDeleted { request_id, uuid } => {
+                let result = self.notification_service
+                    .mark_as_deleted(uuid, recipient.to_string(), None, ChangeSource::WebSocket)
+                    .await;
+                (request_id, result)
+            }
+        };
+
+        match result {
+            Ok(state_change) => ServerMessage::Ack { request_id, success: true, changed: state_change.changed },
+            Err(error) => ServerMessage::Error { request_id: Some(request_id), error: error.to_string() },
+        }
+    }
+}
+
+fn to_text(message: ServerMessage) -> Message {
+    let text = serde_json::to_string(&ServerEnvelope::from(message)).expect("Server message is serializable");
+    Message::Text(text.into())
+}
+
+#[utoipa::path(
+    get,
+    path = "/notification/socket",
+    tag = "notifications",
+    summary = "Open a WebSocket for the recipient's notification events and commands",
+    params(
+        ConnectParameters,
+        ("access_token" = Option<String>, Query, description = "Token of the recipient, for clients that cannot set headers"),
+    ),
+    responses((status = 101, description = "Switching to the WebSocket protocol")),
+    security(("bearer" = [])),
+)]
+pub async fn connect(
+    State(state): State<Arc<AppState>>,
+    StreamingRecipient(recipient): StreamingRecipient,
+    Query(parameters): Query<ConnectParameters>,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let socket_api = state.notification_socket_api.clone();
+    upgrade.on_upgrade(move |socket| serve(socket, socket_api, recipient, parameters.last_event_id))
+}
+
+async fn serve(socket: WebSocket, socket_api: Arc<NotificationSocketApi>, recipient: String, last_event_id: Option<String>) {
+    let (sink, mut source) = socket.split();
+    let (outbound_sender, outbound_receiver) = mpsc::channel(OUTBOUND_CAPACITY);
+    let (close_sender, close_receiver) = oneshot::channel();
+    let writer = tokio::spawn(write(sink, outbound_receiver, close_receiver));
+
+    let mut events = socket_api.notification_stream_service.subscribe(recipient.clone(), last_event_id);
+
+    loop {
+        tokio::select! {
+            event = events.next() => {
+                let Some(event) = event else {
+                    let _ = close_sender.send(CloseFrame {
+                        code: close_code::AGAIN,
+                        reason: "Event stream ended, reconnect with last_event_id to resume".into(),
+                    });
+                    break;
+                };
+                // Events are never awaited on, so a client that stops reading cannot stall the event stream.
+                if let Err(TrySendError::Full(_)) = outbound_sender.try_send(to_text(event.into())) {
+                    let _ = close_sender.send(CloseFrame {
+                        code: close_code::AGAIN,
+                        reason: "Slow consumer, reconnect with last_event_id to resume".into(),
+                    });
+                    break;
+                }
+            }
+            incoming = source.next() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    let reply = socket_api.handle(&recipient, text.as_str()).await;
+                    // Awaiting here stops reading commands until the client catches up with the replies.
+                    if outbound_sender.send(to_text(reply)).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+
+    drop(outbound_sender);
+    let _ = writer.await;
+}
+
+async fn write(
+    mut sink: SplitSink<WebSocket, Message>,
+    mut outbound: mpsc::Receiver<Message>,
+    mut close: oneshot::Receiver<CloseFrame>,
+) {
+    loop {
+        tokio::select! {
+            // The close frame is sent before the outbound queue is dropped, so it must be looked at first.
+            biased;
+            Ok(frame) = &mut close => {
+                let _ = sink.send(Message::Close(Some(frame))).await;
+                break;
+            }
+            message = outbound.recv() => match message {
+                Some(message) => if sink.send(message).await.is_err() { break },
+                None => break,
+            },
+        }
+    }
+}
+
+pub fn notification_socket_routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(connect))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::incoming::rest::notification_api::create_router;
+    use crate::application::error::ApplicationError;
+    use crate::application::service::authentication_service::MockAuthenticationServicePort;
+    use crate::application::service::notification_service::MockNotificationServicePort;
+    use crate::application::service::notification_stream_service::MockNotificationStreamServicePort;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+    use crate::domain::model::state_change::StateChange;
+    use mockall::predicate::eq;
+    use serde_json::{json, Value};
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::BroadcastStream;
+    use tokio_tungstenite::tungstenite;
+
+    fn socket_api(notification_service: MockNotificationServicePort) -> NotificationSocketApi {
+        NotificationSocketApi::new(Arc::new(notification_service), Arc::new(MockNotificationStreamServicePort::new()))
+    }
+
+    #[tokio::test]
+    async fn test_handle_mark_seen_success() {
+        let notification = Notification::new("recipient".to_string(), "message".to_string());
+        let uuid = notification.uuid.to_string();
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service.expect_mark_as_seen()
+            .with(eq(uuid.clone()), eq("recipient".to_string()), eq(None), eq(ChangeSource::WebSocket))
+            .times(1)
+            .returning(move |_, _, _, _| Ok(StateChange { notification: notification.clone(), changed: true }));
+
+        let command = json!({ "version": 1, "type": "mark_seen", "request_id": "1", "uuid": uuid }).to_string();
+        let result = socket_api(mock_service).handle("recipient", &command).await;
+
+        assert!(matches!(result, ServerMessage::Ack { request_id, success: true, changed: true } if request_id == "1"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_other_recipient_notification_error() {
+        let notification = Notification::new("someone else".to_string(), "message".to_string());
+        let uuid = notification.uuid.to_string();
+        let mut mock_service = MockNotificationServicePort::new();
+        mock_service.expect_mark_as_deleted()
+            .with(eq(uuid.clone()), eq("recipient".to_string()), eq(None), eq(ChangeSource::WebSocket))
+            .returning(|uuid, _, _, _| Err(ApplicationError::NotFound(format!("Notification {} not found", uuid))));
+
+        let command = json!({ "version": 1, "type": "mark_deleted", "request_id": "2", "uuid": uuid }).to_string();
+        let result = socket_api(mock_service).handle("recipient", &command).await;
+
+        assert!(matches!(result, ServerMessage::Error { request_id: Some(request_id), .. } if request_id == "2"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unsupported_version_error() {
+        let command = json!({ "version": 2, "type": "mark_seen", "request_id": "1", "uuid": "uuid" }).to_string();
+
+        let result = socket_api(MockNotificationServicePort::new()).handle("recipient", &command).await;
+
+        assert!(matches!(result, ServerMessage::Error { request_id: None, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_socket_pushes_events_and_answers_commands() {
+        let notification = Notification::new("recipient".to_string(), "message".to_string());
+        let uuid = notification.uuid.to_string();
+        let (event_sender, _) = tokio::sync::broadcast::channel(16);
+        let subscribe_sender = event_sender.clone();
+
+        let mut authentication_service = MockAuthenticationServicePort::new();
+        authentication_service.expect_authenticate().returning(|_| Ok("recipient".to_string()));
+
+        let mut stream_service = MockNotificationStreamServicePort::new();
+        stream_service.expect_subscribe()
+            .with(eq("recipient".to_string()), eq(None))
+            .returning(move |_, _| {
+                Box::pin(BroadcastStream::new(subscribe_sender.subscribe()).filter_map(|event| async move { event.ok() }))
+            });
+
+        let mut mock_service = MockNotificationServicePort::new();
+        let seen = notification.clone();
+        mock_service.expect_mark_as_seen().returning(move |_, _, _, _| Ok(StateChange { notification: seen.clone(), changed: true }));
+
+        let app_state = Arc::new(AppState {
+            authentication_service: Arc::new(authentication_service),
+            notification_socket_api: Arc::new(NotificationSocketApi::new(Arc::new(mock_service), Arc::new(stream_service))),
+            ..AppState::mocked()
+        });
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, create_router(app_state)).await.unwrap() });
+
+        let url = format!("ws://{}/notification/socket?access_token=token", address);
+        let (mut client, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+        event_sender.send(NotificationEvent::new(NotificationEventType::Created, notification)).unwrap();
+        let event: Value = serde_json::from_str(client.next().await.unwrap().unwrap().to_text().unwrap()).unwrap();
+        assert_eq!(event["version"], 1);
+        assert_eq!(event["type"], "event");
+        assert_eq!(event["event"], "created");
+        assert_eq!(event["notification"]["uuid"], uuid.as_str());
+
+        let command = json!({ "version": 1, "type": "mark_seen", "request_id": "7", "uuid": uuid }).to_string();
+        client.send(tungstenite::Message::text(command)).await.unwrap();
+        let reply: Value = serde_json::from_str(client.next().await.unwrap().unwrap().to_text().unwrap()).unwrap();
+        assert_eq!(reply, json!({ "version": 1, "type": "ack", "request_id": "7", "success": true, "changed": true }));
+    }
+
+    #[tokio::test]
+    async fn test_socket_closed_when_event_stream_ends() {
+        let mut authentication_service = MockAuthenticationServicePort::new();
+        authentication_service.expect_authenticate().returning(|_| Ok("recipient".to_string()));
+
+        let mut stream_service = MockNotificationStreamServicePort::new();
+        stream_service.expect_subscribe().returning(|_, _| Box::pin(tokio_stream::empty()));
+
+        let app_state = Arc::new(AppState {
+            authentication_service: Arc::new(authentication_service),
+            notification_socket_api: Arc::new(NotificationSocketApi::new(
+                Arc::new(MockNotificationServicePort::new()),
+                Arc::new(stream_service),
+            )),
+            ..AppState::mocked()
+        });
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, create_router(app_state)).await.unwrap() });
+
+        let url = format!("ws://{}/notification/socket?access_token=token", address);
+        let (mut client, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+        let message = client.next().await.unwrap().unwrap();
+        assert!(matches!(message, tungstenite::Message::Close(Some(frame)) if u16::from(frame.code) == close_code::AGAIN));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::application::error::ApplicationError;
+use crate::domain::model::notification_event::NotificationEvent;
+use crate::port::outgoing::event::notification_event_publisher_port::NotificationEventPublisherPort;
+use crate::port::outgoing::event::notification_event_stream_port::NotificationEventStreamPort;
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+
+const CHANNEL_CAPACITY: usize = 1024;
+const REPLAY_CAPACITY: usize = 1024;
+
+/// Fans notification events out to every in-process subscriber, keeping the latest ones for replay.
+pub struct InProcessEventBus {
+    sender: broadcast::Sender<NotificationEvent>,
+    recent_events: Mutex<VecDeque<NotificationEvent>>,
+}
+
+impl InProcessEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender, recent_events: Mutex::new(VecDeque::with_capacity(REPLAY_CAPACITY)) }
+    }
+}
+
+impl Default for InProcessEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NotificationEventPublisherPort for InProcessEventBus {
+    async fn publish(&self, event: NotificationEvent) -> Result<(), ApplicationError> {
+        // The lock keeps the replay buffer in the same order subscribers receive events.
+        let mut recent_events = self.recent_events.lock().unwrap();
+        if recent_events.len() == REPLAY_CAPACITY {
+            recent_events.pop_front();
+        }
+        recent_events.push_back(event.clone());
+
+        // Sending only fails when nobody is subscribed, in which case there is nobody to notify.
+        let _ = self.sender.send(event);
+        Ok(())
+    }
+}
+
+impl NotificationEventStreamPort for InProcessEventBus {
+    fn subscribe(&self) -> broadcast::Receiver<NotificationEvent> {
+        self.sender.subscribe()
+    }
+
+    fn recent_events(&self) -> Vec<NotificationEvent> {
+        self.recent_events.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::NotificationEventType;
+
+    fn event() -> NotificationEvent {
+        NotificationEvent::new(
+            NotificationEventType::Created,
+            Notification::new("recipient".to_string(), "message".to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_publish_reaches_subscribers() {
+        let event_bus = InProcessEventBus::new();
+        let mut receiver = event_bus.subscribe();
+        let event = event();
+
+        event_bus.publish(event.clone()).await.unwrap();
+
+        assert_eq!(receiver.recv().await.unwrap().uuid, event.uuid);
+    }
+
+    #[tokio::test]
+    async fn test_publish_without_subscribers_success() {
+        let event_bus = InProcessEventBus::new();
+
+        assert!(event_bus.publish(event()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_recent_events_keeps_latest_events() {
+        let event_bus = InProcessEventBus::new();
+        let events: Vec<NotificationEvent> = (0..REPLAY_CAPACITY + 1).map(|_| event()).collect();
+
+        for event in &events {
+            event_bus.publish(event.clone()).await.unwrap();
+        }
+
+        let recent_events = event_bus.recent_events();
+        assert_eq!(recent_events.len(), REPLAY_CAPACITY);
+        assert_eq!(recent_events[0].uuid, events[1].uuid);
+        assert_eq!(recent_events[REPLAY_CAPACITY - 1].uuid, events[REPLAY_CAPACITY].uuid);
+    }
+}
@@ -0,0 +1 @@
+pub mod in_process_event_bus;
\ No newline at end of file
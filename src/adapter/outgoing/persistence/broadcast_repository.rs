@@ -0,0 +1,163 @@
+use crate::adapter::outgoing::persistence::entity::broadcast_entity::{BroadcastEntity, BroadcastReceiptEntity};
+use crate::adapter::outgoing::persistence::entity::notification_history_entity::NotificationHistoryEntity;
+use crate::adapter::outgoing::persistence::entity::outbox_event_entity::OutboxEventEntity;
+use crate::adapter::outgoing::persistence::notification_repository::InMemoryNotificationRepository;
+use crate::application::error::ApplicationError;
+use crate::port::outgoing::persistence::broadcast_repository_port::BroadcastRepositoryPort;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct InMemoryBroadcastRepository {
+    broadcasts: RwLock<HashMap<Uuid, BroadcastEntity>>,
+    /// Receipts by broadcast and recipient.
+    receipts: RwLock<HashMap<(Uuid, String), BroadcastReceiptEntity>>,
+    /// Append-only, it outlives the broadcasts and receipts it refers to.
+    history: RwLock<Vec<NotificationHistoryEntity>>,
+    /// Shares its outbox, so broadcasts queue their events where the relay reads them.
+    notification_repository: InMemoryNotificationRepository,
+}
+
+impl InMemoryBroadcastRepository {
+    pub fn new(notification_repository: InMemoryNotificationRepository) -> Self {
+        Self {
+            broadcasts: RwLock::new(HashMap::new()),
+            receipts: RwLock::new(HashMap::new()),
+            history: RwLock::new(Vec::new()),
+            notification_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl BroadcastRepositoryPort for InMemoryBroadcastRepository {
+    async fn save(&self, broadcast: &BroadcastEntity, outbox_event: &OutboxEventEntity) -> Result<BroadcastEntity, ApplicationError> {
+        let mut broadcasts = self.broadcasts.write().await;
+        broadcasts.insert(broadcast.uuid, broadcast.clone());
+        self.notification_repository.queue(outbox_event).await;
+        Ok(broadcast.clone())
+    }
+
+    async fn get(&self, uuid: &Uuid) -> Result<BroadcastEntity, ApplicationError> {
+        let broadcasts = self.broadcasts.read().await;
+        broadcasts.get(uuid)
+            .cloned()
+            .ok_or_else(|| ApplicationError::NotFound(format!("Broadcast {} not found", uuid)))
+    }
+
+    async fn get_all(&self) -> Result<Vec<BroadcastEntity>, ApplicationError> {
+        let broadcasts = self.broadcasts.read().await;
+        let mut entities: Vec<BroadcastEntity> = broadcasts.values().cloned().collect();
+        entities.sort_by_key(|entity| entity.date_created);
+        Ok(entities)
+    }
+
+    async fn delete(&self, uuid: &Uuid) -> Result<(), ApplicationError> {
+        let mut broadcasts = self.broadcasts.write().await;
+        broadcasts.remove(uuid)
+            .ok_or_else(|| ApplicationError::NotFound(format!("Broadcast {} not found", uuid)))?;
+        self.receipts.write().await.retain(|(broadcast_uuid, _), _| broadcast_uuid != uuid);
+        Ok(())
+    }
+
+    async fn get_receipt(&self, broadcast_uuid: &Uuid, recipient: &str) -> Result<BroadcastReceiptEntity, ApplicationError> {
+        let receipts = self.receipts.read().await;
+        receipts.get(&(*broadcast_uuid, recipient.to_string()))
+            .cloned()
+            .ok_or_else(|| ApplicationError::NotFound(format!("Receipt of broadcast {} for {} not found", broadcast_uuid, recipient)))
+    }
+
+    async fn save_receipt(
+        &self,
+        receipt: &BroadcastReceiptEntity,
+        history_entry: &NotificationHistoryEntity,
+    ) -> Result<BroadcastReceiptEntity, ApplicationError> {
+        let mut receipts = self.receipts.write().await;
+        let mut history = self.history.write().await;
+        receipts.insert((receipt.broadcast_uuid, receipt.recipient.clone()), receipt.clone());
+        history.push(history_entry.clone());
+        Ok(receipt.clone())
+    }
+
+    async fn get_receipt_history(
+        &self,
+        broadcast_uuid: &Uuid,
+        recipient: &str,
+    ) -> Result<Vec<NotificationHistoryEntity>, ApplicationError> {
+        let history = self.history.read().await;
+        Ok(history.iter()
+            .filter(|entry| entry.notification_uuid == *broadcast_uuid && entry.changed_by.as_deref() == Some(recipient))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_receipts_of(&self, recipient: &str) -> Result<Vec<BroadcastReceiptEntity>, ApplicationError> {
+        let receipts = self.receipts.read().await;
+        Ok(receipts.values()
+            .filter(|receipt| receipt.recipient == recipient)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::broadcast::{Broadcast, BroadcastReceipt, EVERY_RECIPIENT};
+    use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+    use crate::domain::model::notification_history::{ChangeSource, NotificationHistoryEntry, NotificationState};
+    use crate::port::outgoing::persistence::outbox_repository_port::OutboxRepositoryPort;
+
+    fn created_event(broadcast: &Broadcast) -> OutboxEventEntity {
+        let notification = broadcast.to_notification(EVERY_RECIPIENT.to_string(), None);
+        NotificationEvent::new(NotificationEventType::Created, notification).into()
+    }
+
+    fn seen_entry(receipt: &BroadcastReceipt) -> NotificationHistoryEntity {
+        NotificationHistoryEntry::new(
+            receipt.broadcast_uuid,
+            NotificationEventType::Seen,
+            Some(receipt.recipient.clone()),
+            ChangeSource::Rest,
+            NotificationState::default(),
+            receipt.into(),
+        ).into()
+    }
+
+    #[tokio::test]
+    async fn test_save_queues_event_in_shared_outbox() {
+        let notification_repository = InMemoryNotificationRepository::new();
+        let repository = InMemoryBroadcastRepository::new(notification_repository.clone());
+        let broadcast = Broadcast::new(None, "message".to_string());
+        let outbox_event = created_event(&broadcast);
+
+        repository.save(&broadcast.clone().into(), &outbox_event).await.unwrap();
+
+        assert!(repository.get(&broadcast.uuid).await.is_ok());
+        assert_eq!(notification_repository.get_pending(10).await.unwrap(), vec![outbox_event]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_receipts() {
+        let repository = InMemoryBroadcastRepository::new(InMemoryNotificationRepository::new());
+        let announcement = Broadcast::new(None, "message".to_string());
+        let broadcast: BroadcastEntity = announcement.clone().into();
+        let receipt = BroadcastReceipt::new(broadcast.uuid, "alice".to_string());
+        let entry = seen_entry(&receipt);
+        let receipt: BroadcastReceiptEntity = receipt.into();
+        repository.save(&broadcast, &created_event(&announcement)).await.unwrap();
+        repository.save_receipt(&receipt, &entry).await.unwrap();
+
+        assert_eq!(repository.get_receipt(&broadcast.uuid, "alice").await.unwrap(), receipt);
+        assert_eq!(repository.get_receipts_of("alice").await.unwrap(), vec![receipt]);
+        assert!(repository.get_receipts_of("bob").await.unwrap().is_empty());
+
+        repository.delete(&broadcast.uuid).await.unwrap();
+
+        assert!(repository.get_receipts_of("alice").await.unwrap().is_empty());
+        assert_eq!(repository.get_receipt_history(&broadcast.uuid, "alice").await.unwrap(), vec![entry]);
+        assert!(matches!(repository.get(&broadcast.uuid).await, Err(ApplicationError::NotFound(_))));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::adapter::outgoing::persistence::entity::digest_entity::DigestEntity;
+use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+use crate::application::error::ApplicationError;
+use crate::domain::model::digest::{Digest, DigestStatus};
+use crate::port::outgoing::persistence::digest_repository_port::DigestRepositoryPort;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct InMemoryDigestRepository {
+    digests: RwLock<HashMap<Uuid, DigestEntity>>,
+}
+
+impl InMemoryDigestRepository {
+    pub fn new() -> Self {
+        Self { digests: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl DigestRepositoryPort for InMemoryDigestRepository {
+    async fn save(&self, digest: &DigestEntity) -> Result<DigestEntity, ApplicationError> {
+        let mut digests = self.digests.write().await;
+        digests.insert(digest.uuid, digest.clone());
+        Ok(digest.clone())
+    }
+
+    async fn append(
+        &self,
+        subscription_uuid: &Uuid,
+        recipient: &str,
+        notification: &NotificationEntity,
+        due_at: DateTime<Utc>,
+    ) -> Result<DigestEntity, ApplicationError> {
+        let mut digests = self.digests.write().await;
+        let open = digests.values_mut().find(|digest| {
+            digest.status == DigestStatus::Open && digest.subscription_uuid == *subscription_uuid && digest.recipient == recipient
+        });
+        let digest = match open {
+            Some(digest) => digest,
+            None => {
+                let digest: DigestEntity = Digest::new(*subscription_uuid, recipient.to_string(), due_at).into();
+                digests.entry(digest.uuid).or_insert(digest)
+            }
+        };
+        digest.notifications.push(notification.clone());
+        Ok(digest.clone())
+    }
+
+    async fn get_due(&self, until: DateTime<Utc>, limit: usize) -> Result<Vec<DigestEntity>, ApplicationError> {
+        let digests = self.digests.read().await;
+        let mut due: Vec<DigestEntity> = digests.values()
+            .filter(|digest| digest.status == DigestStatus::Open && digest.due_at <= until)
+            .cloned()
+            .collect();
+        due.sort_by_key(|digest| digest.due_at);
+        due.truncate(limit);
+        Ok(due)
+    }
+
+    async fn get_by_subscription(&self, subscription_uuid: &Uuid) -> Result<Vec<DigestEntity>, ApplicationError> {
+        let digests = self.digests.read().await;
+        let mut entities: Vec<DigestEntity> = digests.values()
+            .filter(|digest| digest.subscription_uuid == *subscription_uuid)
+            .cloned()
+            .collect();
+        entities.sort_by_key(|digest| std::cmp::Reverse(digest.date_created));
+        Ok(entities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification::Notification;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_append_collects_into_open_digest() {
+        let repository = InMemoryDigestRepository::new();
+        let subscription_uuid = Uuid::new_v4();
+        let now = Utc::now();
+        let notification = || -> NotificationEntity { Notification::new("recipient".to_string(), "message".to_string()).into() };
+
+        let first = repository.append(&subscription_uuid, "recipient", &notification(), now - Duration::minutes(1)).await.unwrap();
+        let second = repository.append(&subscription_uuid, "recipient", &notification(), now + Duration::hours(1)).await.unwrap();
+
+        assert_eq!(second.uuid, first.uuid);
+        assert_eq!(second.notifications.len(), 2);
+        assert_eq!(second.due_at, first.due_at);
+        assert_eq!(repository.get_due(now, 10).await.unwrap(), vec![second.clone()]);
+
+        let mut sent = second;
+        sent.status = DigestStatus::Sent;
+        repository.save(&sent).await.unwrap();
+        let third = repository.append(&subscription_uuid, "recipient", &notification(), now + Duration::hours(1)).await.unwrap();
+
+        assert_ne!(third.uuid, sent.uuid);
+        assert!(repository.get_due(now, 10).await.unwrap().is_empty());
+        assert_eq!(repository.get_by_subscription(&subscription_uuid).await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::domain::model::priority::Priority;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadcastEntity {
+    pub uuid: Uuid,
+    pub category: String,
+    pub priority: Priority,
+    pub title: Option<String>,
+    pub message: String,
+    pub date_created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadcastReceiptEntity {
+    pub broadcast_uuid: Uuid,
+    pub recipient: String,
+    pub seen: bool,
+    pub deleted: bool,
+    pub seen_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub last_updated: DateTime<Utc>,
+}
@@ -0,0 +1,56 @@
+use crate::adapter::outgoing::persistence::entity::broadcast_entity::{BroadcastEntity, BroadcastReceiptEntity};
+use crate::domain::model::broadcast::{Broadcast, BroadcastReceipt};
+
+impl From<Broadcast> for BroadcastEntity {
+    fn from(broadcast: Broadcast) -> Self {
+        Self {
+            uuid: broadcast.uuid,
+            category: broadcast.category,
+            priority: broadcast.priority,
+            title: broadcast.title,
+            message: broadcast.message,
+            date_created: broadcast.date_created,
+        }
+    }
+}
+
+impl From<BroadcastEntity> for Broadcast {
+    fn from(broadcast_entity: BroadcastEntity) -> Self {
+        Self {
+            uuid: broadcast_entity.uuid,
+            category: broadcast_entity.category,
+            priority: broadcast_entity.priority,
+            title: broadcast_entity.title,
+            message: broadcast_entity.message,
+            date_created: broadcast_entity.date_created,
+        }
+    }
+}
+
+impl From<BroadcastReceipt> for BroadcastReceiptEntity {
+    fn from(receipt: BroadcastReceipt) -> Self {
+        Self {
+            broadcast_uuid: receipt.broadcast_uuid,
+            recipient: receipt.recipient,
+            seen: receipt.seen,
+            deleted: receipt.deleted,
+            seen_at: receipt.seen_at,
+            deleted_at: receipt.deleted_at,
+            last_updated: receipt.last_updated,
+        }
+    }
+}
+
+impl From<BroadcastReceiptEntity> for BroadcastReceipt {
+    fn from(receipt_entity: BroadcastReceiptEntity) -> Self {
+        Self {
+            broadcast_uuid: receipt_entity.broadcast_uuid,
+            recipient: receipt_entity.recipient,
+            seen: receipt_entity.seen,
+            deleted: receipt_entity.deleted,
+            seen_at: receipt_entity.seen_at,
+            deleted_at: receipt_entity.deleted_at,
+            last_updated: receipt_entity.last_updated,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+use crate::domain::model::digest::DigestStatus;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigestEntity {
+    pub uuid: Uuid,
+    pub subscription_uuid: Uuid,
+    pub recipient: String,
+    pub notifications: Vec<NotificationEntity>,
+    pub status: DigestStatus,
+    pub due_at: DateTime<Utc>,
+    pub date_created: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
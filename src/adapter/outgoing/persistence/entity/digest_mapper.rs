@@ -0,0 +1,56 @@
+use crate::adapter::outgoing::persistence::entity::digest_entity::DigestEntity;
+use crate::domain::model::digest::Digest;
+
+impl From<Digest> for DigestEntity {
+    fn from(digest: Digest) -> Self {
+        Self {
+            uuid: digest.uuid,
+            subscription_uuid: digest.subscription_uuid,
+            recipient: digest.recipient,
+            notifications: digest.notifications.into_iter().map(|notification| notification.into()).collect(),
+            status: digest.status,
+            due_at: digest.due_at,
+            date_created: digest.date_created,
+            sent_at: digest.sent_at,
+        }
+    }
+}
+
+impl From<DigestEntity> for Digest {
+    fn from(digest_entity: DigestEntity) -> Self {
+        Self {
+            uuid: digest_entity.uuid,
+            subscription_uuid: digest_entity.subscription_uuid,
+            recipient: digest_entity.recipient,
+            notifications: digest_entity.notifications.into_iter().map(|notification| notification.into()).collect(),
+            status: digest_entity.status,
+            due_at: digest_entity.due_at,
+            date_created: digest_entity.date_created,
+            sent_at: digest_entity.sent_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification::Notification;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_digest_round_trip_mapping() {
+        let mut digest = Digest::new(Uuid::new_v4(), "recipient".to_string(), Utc::now());
+        digest.notifications.push(Notification::new("recipient".to_string(), "message".to_string()));
+        digest.complete(true);
+
+        let entity: DigestEntity = digest.clone().into();
+        let result: Digest = entity.into();
+
+        assert_eq!(result.uuid, digest.uuid);
+        assert_eq!(result.subscription_uuid, digest.subscription_uuid);
+        assert_eq!(result.notifications[0].uuid, digest.notifications[0].uuid);
+        assert_eq!(result.status, digest.status);
+        assert_eq!(result.sent_at, digest.sent_at);
+    }
+}
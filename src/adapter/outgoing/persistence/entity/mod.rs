@@ -1,3 +1,51 @@
 pub mod notification_entity;
 
-pub mod notification_mapper;
\ No newline at end of file
+pub mod notification_mapper;
+
+pub mod notification_template_entity;
+
+pub mod notification_template_mapper;
+
+pub mod outbox_event_entity;
+
+pub mod outbox_event_mapper;
+
+pub mod recipient_profile_entity;
+
+pub mod recipient_profile_mapper;
+
+pub mod webhook_subscription_entity;
+
+pub mod webhook_subscription_mapper;
+
+pub mod webhook_delivery_attempt_entity;
+
+pub mod webhook_delivery_attempt_mapper;
+
+pub mod scheduled_delivery_entity;
+
+pub mod scheduled_delivery_mapper;
+
+pub mod topic_entity;
+
+pub mod topic_mapper;
+
+pub mod topic_publication_entity;
+
+pub mod topic_publication_mapper;
+
+pub mod broadcast_entity;
+
+pub mod broadcast_mapper;
+
+pub mod digest_entity;
+
+pub mod digest_mapper;
+
+pub mod notification_search_hit_entity;
+
+pub mod notification_search_hit_mapper;
+
+pub mod notification_history_entity;
+
+pub mod notification_history_mapper;
\ No newline at end of file
@@ -1,12 +1,36 @@
+use crate::domain::model::localisation::LocalizedContent;
+use crate::domain::model::notification::NotificationKind;
+use crate::domain::model::notification_action::{ActionTaken, NotificationAction};
+use crate::domain::model::priority::Priority;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct NotificationEntity {
     pub uuid: Uuid,
+    /// Stored notifications are personal; broadcasts only take this shape in outbox events.
+    pub kind: NotificationKind,
+    pub recipient: String,
+    pub category: String,
+    pub priority: Priority,
+    pub group_key: Option<String>,
+    pub actor: Option<String>,
+    pub title: Option<String>,
     pub message: String,
+    pub locale: Option<String>,
+    pub localizations: Vec<LocalizedContent>,
+    pub actions: Vec<NotificationAction>,
+    pub auto_resolve: bool,
+    pub action_taken: Option<ActionTaken>,
+    pub tags: Vec<String>,
+    pub snoozed_until: Option<DateTime<Utc>>,
     pub seen: bool,
     pub deleted: bool,
+    pub seen_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub pinned: bool,
+    pub archived: bool,
+    pub version: u64,
     pub date_created: DateTime<Utc>,
     pub last_updated: DateTime<Utc>,
 }
@@ -0,0 +1,16 @@
+use crate::domain::model::notification_event::NotificationEventType;
+use crate::domain::model::notification_history::{ChangeSource, NotificationState};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationHistoryEntity {
+    pub uuid: Uuid,
+    pub notification_uuid: Uuid,
+    pub event_type: NotificationEventType,
+    pub changed_by: Option<String>,
+    pub source: ChangeSource,
+    pub from_state: NotificationState,
+    pub to_state: NotificationState,
+    pub date_created: DateTime<Utc>,
+}
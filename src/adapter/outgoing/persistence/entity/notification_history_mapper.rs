@@ -0,0 +1,32 @@
+use crate::adapter::outgoing::persistence::entity::notification_history_entity::NotificationHistoryEntity;
+use crate::domain::model::notification_history::NotificationHistoryEntry;
+
+impl From<NotificationHistoryEntry> for NotificationHistoryEntity {
+    fn from(entry: NotificationHistoryEntry) -> Self {
+        Self {
+            uuid: entry.uuid,
+            notification_uuid: entry.notification_uuid,
+            event_type: entry.event_type,
+            changed_by: entry.changed_by,
+            source: entry.source,
+            from_state: entry.from_state,
+            to_state: entry.to_state,
+            date_created: entry.date_created,
+        }
+    }
+}
+
+impl From<NotificationHistoryEntity> for NotificationHistoryEntry {
+    fn from(entry_entity: NotificationHistoryEntity) -> Self {
+        Self {
+            uuid: entry_entity.uuid,
+            notification_uuid: entry_entity.notification_uuid,
+            event_type: entry_entity.event_type,
+            changed_by: entry_entity.changed_by,
+            source: entry_entity.source,
+            from_state: entry_entity.from_state,
+            to_state: entry_entity.to_state,
+            date_created: entry_entity.date_created,
+        }
+    }
+}
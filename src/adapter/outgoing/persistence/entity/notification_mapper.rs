@@ -5,9 +5,28 @@ impl From<Notification> for NotificationEntity {
     fn from(notification: Notification) -> Self {
         Self {
             uuid: notification.uuid,
+            kind: notification.kind,
+            recipient: notification.recipient,
+            category: notification.category,
+            priority: notification.priority,
+            group_key: notification.group_key,
+            actor: notification.actor,
+            title: notification.title,
             message: notification.message,
+            locale: notification.locale,
+            localizations: notification.localizations,
+            actions: notification.actions,
+            auto_resolve: notification.auto_resolve,
+            action_taken: notification.action_taken,
+            tags: notification.tags,
+            snoozed_until: notification.snoozed_until,
             seen: notification.seen,
             deleted: notification.deleted,
+            seen_at: notification.seen_at,
+            deleted_at: notification.deleted_at,
+            pinned: notification.pinned,
+            archived: notification.archived,
+            version: notification.version,
             date_created: notification.date_created,
             last_updated: notification.last_updated,
         }
@@ -18,9 +37,28 @@ impl From<NotificationEntity> for Notification {
     fn from(notification_entity: NotificationEntity) -> Self {
         Self {
             uuid: notification_entity.uuid,
+            kind: notification_entity.kind,
+            recipient: notification_entity.recipient,
+            category: notification_entity.category,
+            priority: notification_entity.priority,
+            group_key: notification_entity.group_key,
+            actor: notification_entity.actor,
+            title: notification_entity.title,
             message: notification_entity.message,
+            locale: notification_entity.locale,
+            localizations: notification_entity.localizations,
+            actions: notification_entity.actions,
+            auto_resolve: notification_entity.auto_resolve,
+            action_taken: notification_entity.action_taken,
+            tags: notification_entity.tags,
+            snoozed_until: notification_entity.snoozed_until,
             seen: notification_entity.seen,
             deleted: notification_entity.deleted,
+            seen_at: notification_entity.seen_at,
+            deleted_at: notification_entity.deleted_at,
+            pinned: notification_entity.pinned,
+            archived: notification_entity.archived,
+            version: notification_entity.version,
             date_created: notification_entity.date_created,
             last_updated: notification_entity.last_updated,
         }
@@ -30,14 +68,21 @@ impl From<NotificationEntity> for Notification {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification::{Notification, NotificationKind};
+    use crate::domain::model::priority::Priority;
 
     #[test]
     fn test_notification_to_entity_mapping() {
-        let notification = Notification::new("some message".to_string());
+        let notification = Notification::new("recipient".to_string(), "some message".to_string());
         let result: NotificationEntity = notification.clone().into();
 
         assert_eq!(result.uuid, notification.uuid);
+        assert_eq!(result.recipient, notification.recipient);
+        assert_eq!(result.category, notification.category);
+        assert_eq!(result.priority, notification.priority);
+        assert_eq!(result.group_key, notification.group_key);
+        assert_eq!(result.actor, notification.actor);
+        assert_eq!(result.title, notification.title);
         assert_eq!(result.message, notification.message);
         assert_eq!(result.seen, notification.seen);
         assert_eq!(result.deleted, notification.deleted);
@@ -47,13 +92,32 @@ mod tests {
 
     #[test]
     fn test_notification_entity_to_notification_mapping() {
-        let expected_notification = Notification::new("some message".to_string());
+        let expected_notification = Notification::new("recipient".to_string(), "some message".to_string());
 
         let notification_entity = NotificationEntity {
             uuid: expected_notification.uuid,
+            kind: NotificationKind::Personal,
+            recipient: expected_notification.recipient.clone(),
+            category: "marketing".to_string(),
+            priority: Priority::Urgent,
+            group_key: Some("post-42".to_string()),
+            actor: Some("bob".to_string()),
+            title: Some("some title".to_string()),
             message: expected_notification.message,
-            seen: false,
+            locale: Some("en".to_string()),
+            localizations: vec![],
+            actions: vec![],
+            auto_resolve: false,
+            action_taken: None,
+            tags: vec![],
+            snoozed_until: None,
+            seen: true,
             deleted: false,
+            seen_at: Some(expected_notification.last_updated),
+            deleted_at: None,
+            pinned: true,
+            archived: false,
+            version: 3,
             date_created: expected_notification.date_created,
             last_updated: expected_notification.last_updated,
         };
@@ -61,9 +125,20 @@ mod tests {
         let result: Notification = notification_entity.clone().into();
 
         assert_eq!(result.uuid, expected_notification.uuid);
+        assert_eq!(result.recipient, "recipient");
+        assert_eq!(result.category, "marketing");
+        assert_eq!(result.priority, Priority::Urgent);
+        assert_eq!(result.group_key, Some("post-42".to_string()));
+        assert_eq!(result.actor, Some("bob".to_string()));
+        assert_eq!(result.title, Some("some title".to_string()));
         assert_eq!(result.message, "some message");
-        assert_eq!(result.seen, expected_notification.seen);
+        assert_eq!(result.locale, Some("en".to_string()));
+        assert!(result.seen);
+        assert_eq!(result.seen_at, Some(expected_notification.last_updated));
         assert_eq!(result.deleted, expected_notification.deleted);
+        assert!(result.pinned);
+        assert!(!result.archived);
+        assert_eq!(result.version, 3);
         assert_eq!(result.date_created, expected_notification.date_created);
         assert_eq!(result.last_updated, expected_notification.last_updated);
     }
@@ -0,0 +1,9 @@
+use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+
+/// Search result as ranked and highlighted by the store, such as `ts_rank` and `ts_headline` of a tsvector column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationSearchHitEntity {
+    pub notification: NotificationEntity,
+    pub rank: f64,
+    pub snippet: String,
+}
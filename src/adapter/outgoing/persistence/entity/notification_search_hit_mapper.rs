@@ -0,0 +1,22 @@
+use crate::adapter::outgoing::persistence::entity::notification_search_hit_entity::NotificationSearchHitEntity;
+use crate::domain::model::notification_search::SearchHit;
+
+impl From<SearchHit> for NotificationSearchHitEntity {
+    fn from(hit: SearchHit) -> Self {
+        Self {
+            notification: hit.notification.into(),
+            rank: hit.rank,
+            snippet: hit.snippet,
+        }
+    }
+}
+
+impl From<NotificationSearchHitEntity> for SearchHit {
+    fn from(hit_entity: NotificationSearchHitEntity) -> Self {
+        Self {
+            notification: hit_entity.notification.into(),
+            rank: hit_entity.rank,
+            snippet: hit_entity.snippet,
+        }
+    }
+}
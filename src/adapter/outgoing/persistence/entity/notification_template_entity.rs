@@ -0,0 +1,14 @@
+use crate::domain::model::notification_template::TemplateVariant;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationTemplateEntity {
+    pub id: String,
+    pub version: u32,
+    pub locale: String,
+    pub title: String,
+    pub body: String,
+    pub variants: Vec<TemplateVariant>,
+    pub date_created: DateTime<Utc>,
+    pub last_updated: DateTime<Utc>,
+}
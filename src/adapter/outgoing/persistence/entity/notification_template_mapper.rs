@@ -0,0 +1,59 @@
+use crate::adapter::outgoing::persistence::entity::notification_template_entity::NotificationTemplateEntity;
+use crate::domain::model::notification_template::NotificationTemplate;
+
+impl From<NotificationTemplate> for NotificationTemplateEntity {
+    fn from(template: NotificationTemplate) -> Self {
+        Self {
+            id: template.id,
+            version: template.version,
+            locale: template.locale,
+            title: template.title,
+            body: template.body,
+            variants: template.variants,
+            date_created: template.date_created,
+            last_updated: template.last_updated,
+        }
+    }
+}
+
+impl From<NotificationTemplateEntity> for NotificationTemplate {
+    fn from(template_entity: NotificationTemplateEntity) -> Self {
+        Self {
+            id: template_entity.id,
+            version: template_entity.version,
+            locale: template_entity.locale,
+            title: template_entity.title,
+            body: template_entity.body,
+            variants: template_entity.variants,
+            date_created: template_entity.date_created,
+            last_updated: template_entity.last_updated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_template_round_trip_mapping() {
+        let template = NotificationTemplate::new(
+            "welcome".to_string(),
+            "en".to_string(),
+            "Welcome {{name}}".to_string(),
+            "Hello {{name}}".to_string(),
+        );
+
+        let entity: NotificationTemplateEntity = template.clone().into();
+        let result: NotificationTemplate = entity.into();
+
+        assert_eq!(result.id, template.id);
+        assert_eq!(result.version, template.version);
+        assert_eq!(result.locale, template.locale);
+        assert_eq!(result.title, template.title);
+        assert_eq!(result.body, template.body);
+        assert_eq!(result.variants, template.variants);
+        assert_eq!(result.date_created, template.date_created);
+        assert_eq!(result.last_updated, template.last_updated);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+use crate::domain::model::notification_event::NotificationEventType;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxEventEntity {
+    pub uuid: Uuid,
+    pub event_type: NotificationEventType,
+    pub notification: NotificationEntity,
+    pub occurred_at: DateTime<Utc>,
+    /// When the event was published to the stream and socket consumers.
+    pub published_at: Option<DateTime<Utc>>,
+    /// Webhook subscriptions that already accepted the event.
+    pub routed_to: Vec<Uuid>,
+}
@@ -0,0 +1,51 @@
+use crate::adapter::outgoing::persistence::entity::outbox_event_entity::OutboxEventEntity;
+use crate::domain::model::notification_event::NotificationEvent;
+
+impl From<NotificationEvent> for OutboxEventEntity {
+    fn from(event: NotificationEvent) -> Self {
+        Self {
+            uuid: event.uuid,
+            event_type: event.event_type,
+            notification: event.notification.into(),
+            occurred_at: event.occurred_at,
+            published_at: None,
+            routed_to: vec![],
+        }
+    }
+}
+
+impl From<OutboxEventEntity> for NotificationEvent {
+    fn from(outbox_event_entity: OutboxEventEntity) -> Self {
+        Self {
+            uuid: outbox_event_entity.uuid,
+            event_type: outbox_event_entity.event_type,
+            notification: outbox_event_entity.notification.into(),
+            occurred_at: outbox_event_entity.occurred_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::NotificationEventType;
+
+    #[test]
+    fn test_outbox_event_round_trip_mapping() {
+        let event = NotificationEvent::new(
+            NotificationEventType::Deleted,
+            Notification::new("recipient".to_string(), "message".to_string()),
+        );
+
+        let entity: OutboxEventEntity = event.clone().into();
+        let result: NotificationEvent = entity.clone().into();
+
+        assert_eq!(entity.published_at, None);
+        assert!(entity.routed_to.is_empty());
+        assert_eq!(result.uuid, event.uuid);
+        assert_eq!(result.event_type, event.event_type);
+        assert_eq!(result.notification.uuid, event.notification.uuid);
+        assert_eq!(result.occurred_at, event.occurred_at);
+    }
+}
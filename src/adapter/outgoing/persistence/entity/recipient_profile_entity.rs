@@ -0,0 +1,14 @@
+use crate::domain::model::digest::DigestFrequency;
+use crate::domain::model::preference::ChannelPreference;
+use crate::domain::model::quiet_hours::QuietHours;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipientProfileEntity {
+    pub recipient: String,
+    pub locale: Option<String>,
+    pub preferences: Vec<ChannelPreference>,
+    pub quiet_hours: QuietHours,
+    pub digest_frequency: Option<DigestFrequency>,
+    pub last_updated: DateTime<Utc>,
+}
@@ -0,0 +1,28 @@
+use crate::adapter::outgoing::persistence::entity::recipient_profile_entity::RecipientProfileEntity;
+use crate::domain::model::recipient_profile::RecipientProfile;
+
+impl From<RecipientProfile> for RecipientProfileEntity {
+    fn from(profile: RecipientProfile) -> Self {
+        Self {
+            recipient: profile.recipient,
+            locale: profile.locale,
+            preferences: profile.preferences,
+            quiet_hours: profile.quiet_hours,
+            digest_frequency: profile.digest_frequency,
+            last_updated: profile.last_updated,
+        }
+    }
+}
+
+impl From<RecipientProfileEntity> for RecipientProfile {
+    fn from(profile_entity: RecipientProfileEntity) -> Self {
+        Self {
+            recipient: profile_entity.recipient,
+            locale: profile_entity.locale,
+            preferences: profile_entity.preferences,
+            quiet_hours: profile_entity.quiet_hours,
+            digest_frequency: profile_entity.digest_frequency,
+            last_updated: profile_entity.last_updated,
+        }
+    }
+}
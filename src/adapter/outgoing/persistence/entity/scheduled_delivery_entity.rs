@@ -0,0 +1,15 @@
+use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+use crate::domain::model::notification_event::NotificationEventType;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledDeliveryEntity {
+    pub uuid: Uuid,
+    pub subscription_uuid: Uuid,
+    pub event_uuid: Uuid,
+    pub event_type: NotificationEventType,
+    pub notification: NotificationEntity,
+    pub occurred_at: DateTime<Utc>,
+    pub due_at: DateTime<Utc>,
+}
@@ -0,0 +1,61 @@
+use crate::adapter::outgoing::persistence::entity::scheduled_delivery_entity::ScheduledDeliveryEntity;
+use crate::domain::model::notification_event::NotificationEvent;
+use crate::domain::model::scheduled_delivery::ScheduledDelivery;
+
+impl From<ScheduledDelivery> for ScheduledDeliveryEntity {
+    fn from(scheduled_delivery: ScheduledDelivery) -> Self {
+        Self {
+            uuid: scheduled_delivery.uuid,
+            subscription_uuid: scheduled_delivery.subscription_uuid,
+            event_uuid: scheduled_delivery.event.uuid,
+            event_type: scheduled_delivery.event.event_type,
+            notification: scheduled_delivery.event.notification.into(),
+            occurred_at: scheduled_delivery.event.occurred_at,
+            due_at: scheduled_delivery.due_at,
+        }
+    }
+}
+
+impl From<ScheduledDeliveryEntity> for ScheduledDelivery {
+    fn from(scheduled_delivery_entity: ScheduledDeliveryEntity) -> Self {
+        Self {
+            uuid: scheduled_delivery_entity.uuid,
+            subscription_uuid: scheduled_delivery_entity.subscription_uuid,
+            event: NotificationEvent {
+                uuid: scheduled_delivery_entity.event_uuid,
+                event_type: scheduled_delivery_entity.event_type,
+                notification: scheduled_delivery_entity.notification.into(),
+                occurred_at: scheduled_delivery_entity.occurred_at,
+            },
+            due_at: scheduled_delivery_entity.due_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::NotificationEventType;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_scheduled_delivery_round_trip_mapping() {
+        let event = NotificationEvent::new(
+            NotificationEventType::Created,
+            Notification::new("recipient".to_string(), "message".to_string()),
+        );
+        let scheduled_delivery = ScheduledDelivery::new(Uuid::new_v4(), event.clone(), Utc::now());
+
+        let entity: ScheduledDeliveryEntity = scheduled_delivery.clone().into();
+        let result: ScheduledDelivery = entity.into();
+
+        assert_eq!(result.uuid, scheduled_delivery.uuid);
+        assert_eq!(result.subscription_uuid, scheduled_delivery.subscription_uuid);
+        assert_eq!(result.event.uuid, event.uuid);
+        assert_eq!(result.event.event_type, event.event_type);
+        assert_eq!(result.event.notification.uuid, event.notification.uuid);
+        assert_eq!(result.due_at, scheduled_delivery.due_at);
+    }
+}
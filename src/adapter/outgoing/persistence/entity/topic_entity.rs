@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicEntity {
+    pub id: String,
+    pub description: Option<String>,
+    pub date_created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicSubscriptionEntity {
+    pub topic_id: String,
+    pub recipient: String,
+    pub date_created: DateTime<Utc>,
+}
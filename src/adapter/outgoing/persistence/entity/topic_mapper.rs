@@ -0,0 +1,42 @@
+use crate::adapter::outgoing::persistence::entity::topic_entity::{TopicEntity, TopicSubscriptionEntity};
+use crate::domain::model::topic::{Topic, TopicSubscription};
+
+impl From<Topic> for TopicEntity {
+    fn from(topic: Topic) -> Self {
+        Self {
+            id: topic.id,
+            description: topic.description,
+            date_created: topic.date_created,
+        }
+    }
+}
+
+impl From<TopicEntity> for Topic {
+    fn from(topic_entity: TopicEntity) -> Self {
+        Self {
+            id: topic_entity.id,
+            description: topic_entity.description,
+            date_created: topic_entity.date_created,
+        }
+    }
+}
+
+impl From<TopicSubscription> for TopicSubscriptionEntity {
+    fn from(subscription: TopicSubscription) -> Self {
+        Self {
+            topic_id: subscription.topic_id,
+            recipient: subscription.recipient,
+            date_created: subscription.date_created,
+        }
+    }
+}
+
+impl From<TopicSubscriptionEntity> for TopicSubscription {
+    fn from(subscription_entity: TopicSubscriptionEntity) -> Self {
+        Self {
+            topic_id: subscription_entity.topic_id,
+            recipient: subscription_entity.recipient,
+            date_created: subscription_entity.date_created,
+        }
+    }
+}
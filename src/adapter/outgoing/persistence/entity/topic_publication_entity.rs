@@ -0,0 +1,20 @@
+use crate::domain::model::priority::Priority;
+use crate::domain::model::topic_publication::PublicationStatus;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicPublicationEntity {
+    pub uuid: Uuid,
+    pub topic_id: String,
+    pub message: String,
+    pub category: String,
+    pub priority: Priority,
+    pub status: PublicationStatus,
+    pub total_recipients: usize,
+    pub processed_recipients: usize,
+    pub failed_recipients: usize,
+    pub cursor: Option<String>,
+    pub date_created: DateTime<Utc>,
+    pub last_updated: DateTime<Utc>,
+}
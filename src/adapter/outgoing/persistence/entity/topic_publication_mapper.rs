@@ -0,0 +1,65 @@
+use crate::adapter::outgoing::persistence::entity::topic_publication_entity::TopicPublicationEntity;
+use crate::domain::model::topic_publication::TopicPublication;
+
+impl From<TopicPublication> for TopicPublicationEntity {
+    fn from(publication: TopicPublication) -> Self {
+        Self {
+            uuid: publication.uuid,
+            topic_id: publication.topic_id,
+            message: publication.message,
+            category: publication.category,
+            priority: publication.priority,
+            status: publication.status,
+            total_recipients: publication.total_recipients,
+            processed_recipients: publication.processed_recipients,
+            failed_recipients: publication.failed_recipients,
+            cursor: publication.cursor,
+            date_created: publication.date_created,
+            last_updated: publication.last_updated,
+        }
+    }
+}
+
+impl From<TopicPublicationEntity> for TopicPublication {
+    fn from(publication_entity: TopicPublicationEntity) -> Self {
+        Self {
+            uuid: publication_entity.uuid,
+            topic_id: publication_entity.topic_id,
+            message: publication_entity.message,
+            category: publication_entity.category,
+            priority: publication_entity.priority,
+            status: publication_entity.status,
+            total_recipients: publication_entity.total_recipients,
+            processed_recipients: publication_entity.processed_recipients,
+            failed_recipients: publication_entity.failed_recipients,
+            cursor: publication_entity.cursor,
+            date_created: publication_entity.date_created,
+            last_updated: publication_entity.last_updated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::priority::Priority;
+
+    #[test]
+    fn test_topic_publication_round_trip_mapping() {
+        let mut publication = TopicPublication::new(
+            "project-x".to_string(), "message".to_string(), "general".to_string(), Priority::High, 10,
+        );
+        publication.record_batch("alice".to_string(), 1, 0);
+
+        let entity: TopicPublicationEntity = publication.clone().into();
+        let result: TopicPublication = entity.into();
+
+        assert_eq!(result.uuid, publication.uuid);
+        assert_eq!(result.topic_id, "project-x");
+        assert_eq!(result.priority, Priority::High);
+        assert_eq!(result.status, publication.status);
+        assert_eq!(result.total_recipients, 10);
+        assert_eq!(result.processed_recipients, 1);
+        assert_eq!(result.cursor, Some("alice".to_string()));
+    }
+}
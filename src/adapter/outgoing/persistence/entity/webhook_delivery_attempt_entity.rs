@@ -0,0 +1,16 @@
+use crate::domain::model::notification_event::NotificationEventType;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookDeliveryAttemptEntity {
+    pub uuid: Uuid,
+    pub subscription_uuid: Uuid,
+    pub event_uuid: Uuid,
+    pub event_type: NotificationEventType,
+    pub attempt: u32,
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+    pub suppression_reason: Option<String>,
+    pub attempted_at: DateTime<Utc>,
+}
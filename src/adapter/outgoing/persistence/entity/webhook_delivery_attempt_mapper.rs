@@ -0,0 +1,34 @@
+use crate::adapter::outgoing::persistence::entity::webhook_delivery_attempt_entity::WebhookDeliveryAttemptEntity;
+use crate::domain::model::webhook_delivery_attempt::WebhookDeliveryAttempt;
+
+impl From<WebhookDeliveryAttempt> for WebhookDeliveryAttemptEntity {
+    fn from(attempt: WebhookDeliveryAttempt) -> Self {
+        Self {
+            uuid: attempt.uuid,
+            subscription_uuid: attempt.subscription_uuid,
+            event_uuid: attempt.event_uuid,
+            event_type: attempt.event_type,
+            attempt: attempt.attempt,
+            status_code: attempt.status_code,
+            error: attempt.error,
+            suppression_reason: attempt.suppression_reason,
+            attempted_at: attempt.attempted_at,
+        }
+    }
+}
+
+impl From<WebhookDeliveryAttemptEntity> for WebhookDeliveryAttempt {
+    fn from(attempt_entity: WebhookDeliveryAttemptEntity) -> Self {
+        Self {
+            uuid: attempt_entity.uuid,
+            subscription_uuid: attempt_entity.subscription_uuid,
+            event_uuid: attempt_entity.event_uuid,
+            event_type: attempt_entity.event_type,
+            attempt: attempt_entity.attempt,
+            status_code: attempt_entity.status_code,
+            error: attempt_entity.error,
+            suppression_reason: attempt_entity.suppression_reason,
+            attempted_at: attempt_entity.attempted_at,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::domain::model::notification_event::NotificationEventType;
+use crate::domain::model::preference::Channel;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookSubscriptionEntity {
+    pub uuid: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<NotificationEventType>,
+    pub channel: Option<Channel>,
+    pub date_created: DateTime<Utc>,
+}
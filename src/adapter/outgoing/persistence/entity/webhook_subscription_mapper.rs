@@ -0,0 +1,53 @@
+use crate::adapter::outgoing::persistence::entity::webhook_subscription_entity::WebhookSubscriptionEntity;
+use crate::domain::model::webhook_subscription::WebhookSubscription;
+
+impl From<WebhookSubscription> for WebhookSubscriptionEntity {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            uuid: subscription.uuid,
+            url: subscription.url,
+            secret: subscription.secret,
+            events: subscription.events,
+            channel: subscription.channel,
+            date_created: subscription.date_created,
+        }
+    }
+}
+
+impl From<WebhookSubscriptionEntity> for WebhookSubscription {
+    fn from(subscription_entity: WebhookSubscriptionEntity) -> Self {
+        Self {
+            uuid: subscription_entity.uuid,
+            url: subscription_entity.url,
+            secret: subscription_entity.secret,
+            events: subscription_entity.events,
+            channel: subscription_entity.channel,
+            date_created: subscription_entity.date_created,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification_event::NotificationEventType;
+
+    #[test]
+    fn test_webhook_subscription_round_trip_mapping() {
+        let subscription = WebhookSubscription::new(
+            "http://localhost/hook".to_string(),
+            "secret".to_string(),
+            vec![NotificationEventType::Created],
+        );
+
+        let entity: WebhookSubscriptionEntity = subscription.clone().into();
+        let result: WebhookSubscription = entity.into();
+
+        assert_eq!(result.uuid, subscription.uuid);
+        assert_eq!(result.url, subscription.url);
+        assert_eq!(result.secret, subscription.secret);
+        assert_eq!(result.events, subscription.events);
+        assert_eq!(result.channel, subscription.channel);
+        assert_eq!(result.date_created, subscription.date_created);
+    }
+}
@@ -1,2 +1,11 @@
+pub mod broadcast_repository;
+pub mod digest_repository;
 pub mod notification_repository;
+pub mod notification_template_repository;
+pub mod recipient_profile_repository;
+pub mod scheduled_delivery_repository;
+pub mod topic_publication_repository;
+pub mod topic_repository;
+pub mod webhook_delivery_attempt_repository;
+pub mod webhook_subscription_repository;
 pub mod entity;
\ No newline at end of file
@@ -0,0 +1,511 @@
+use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+use crate::adapter::outgoing::persistence::entity::notification_history_entity::NotificationHistoryEntity;
+use crate::adapter::outgoing::persistence::entity::notification_search_hit_entity::NotificationSearchHitEntity;
+use crate::adapter::outgoing::persistence::entity::outbox_event_entity::OutboxEventEntity;
+use crate::application::error::ApplicationError;
+use crate::domain::model::notification::Notification;
+use crate::domain::model::notification_search::{SearchHit, SearchQuery};
+use crate::port::outgoing::persistence::notification_repository_port::NotificationRepositoryPort;
+use crate::port::outgoing::persistence::outbox_repository_port::OutboxRepositoryPort;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::slice;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Notifications and their outbox share one lock, which stands in for a database transaction.
+#[derive(Default)]
+struct Store {
+    notifications: HashMap<Uuid, NotificationEntity>,
+    /// Notifications by recipient and tag, as an index on tags would serve a database.
+    tagged: HashMap<(String, String), HashSet<Uuid>>,
+    /// Events not yet published; a published event is removed rather than kept around to be skipped.
+    outbox: VecDeque<OutboxEventEntity>,
+    history: Vec<NotificationHistoryEntity>,
+}
+
+impl Store {
+    fn index_tags(&mut self, notification: &NotificationEntity) {
+        if let Some(previous) = self.notifications.get(&notification.uuid) {
+            for tag in &previous.tags {
+                let key = (previous.recipient.clone(), tag.clone());
+                if let Some(uuids) = self.tagged.get_mut(&key) {
+                    uuids.remove(&notification.uuid);
+                    if uuids.is_empty() {
+                        self.tagged.remove(&key);
+                    }
+                }
+            }
+        }
+        for tag in &notification.tags {
+            self.tagged.entry((notification.recipient.clone(), tag.clone())).or_default().insert(notification.uuid);
+        }
+    }
+}
+
+/// Clones share the same store, so the outbox relay can read what the service writes.
+#[derive(Clone, Default)]
+pub struct InMemoryNotificationRepository {
+    store: Arc<RwLock<Store>>,
+}
+
+impl InMemoryNotificationRepository {
+    pub fn new() -> Self {
+        Self { store: Arc::new(RwLock::new(Store::default())) }
+    }
+
+    /// Appends an event written along with something other than a notification to the outbox.
+    pub async fn queue(&self, outbox_event: &OutboxEventEntity) {
+        self.store.write().await.outbox.push_back(outbox_event.clone());
+    }
+}
+
+#[async_trait]
+impl NotificationRepositoryPort for InMemoryNotificationRepository {
+    async fn save(
+        &self,
+        notification: &NotificationEntity,
+        outbox_event: &OutboxEventEntity,
+        history_entry: Option<NotificationHistoryEntity>,
+    ) -> Result<NotificationEntity, ApplicationError> {
+        self.save_changes(notification, slice::from_ref(outbox_event), history_entry.into_iter().collect()).await
+    }
+
+    async fn save_changes(
+        &self,
+        notification: &NotificationEntity,
+        outbox_events: &[OutboxEventEntity],
+        history_entries: Vec<NotificationHistoryEntity>,
+    ) -> Result<NotificationEntity, ApplicationError> {
+        let mut store = self.store.write().await;
+        let stored_version = store.notifications.get(&notification.uuid).map(|stored| stored.version);
+        if stored_version.is_some_and(|version| version != notification.version) {
+            return Err(ApplicationError::Conflict(format!("Notification {} was changed concurrently", notification.uuid)));
+        }
+        let saved = NotificationEntity { version: notification.version + 1, ..notification.clone() };
+        store.index_tags(&saved);
+        store.notifications.insert(saved.uuid, saved.clone());
+        for outbox_event in outbox_events {
+            store.outbox.push_back(OutboxEventEntity { notification: saved.clone(), ..outbox_event.clone() });
+        }
+        store.history.extend(history_entries);
+        Ok(saved)
+    }
+
+    async fn get(&self, uuid: &Uuid) -> Result<NotificationEntity, ApplicationError> {
+        let store = self.store.read().await;
+        store.notifications.get(uuid)
+            .cloned()
+            .ok_or_else(|| ApplicationError::NotFound(format!("Notification {} not found", uuid)))
+    }
+
+    async fn get_all_of(&self, recipient: &str) -> Result<Vec<NotificationEntity>, ApplicationError> {
+        let store = self.store.read().await;
+        let mut entities: Vec<NotificationEntity> = store.notifications.values()
+            .filter(|entity| entity.recipient == recipient)
+            .cloned()
+            .collect();
+        entities.sort_by_key(|entity| entity.date_created);
+        Ok(entities)
+    }
+
+    async fn get_tagged_of(&self, recipient: &str, tag: &str) -> Result<Vec<NotificationEntity>, ApplicationError> {
+        let store = self.store.read().await;
+        let mut entities: Vec<NotificationEntity> = store.tagged.get(&(recipient.to_string(), tag.to_string()))
+            .into_iter()
+            .flatten()
+            .filter_map(|uuid| store.notifications.get(uuid))
+            .cloned()
+            .collect();
+        entities.sort_by_key(|entity| entity.date_created);
+        Ok(entities)
+    }
+
+    async fn count_tags_of(&self, recipient: &str) -> Result<Vec<(String, usize)>, ApplicationError> {
+        let store = self.store.read().await;
+        let mut counts: Vec<(String, usize)> = store.tagged.iter()
+            .filter(|((tag_recipient, _), _)| tag_recipient == recipient)
+            .map(|((_, tag), uuids)| {
+                let count = uuids.iter()
+                    .filter_map(|uuid| store.notifications.get(uuid))
+                    .filter(|entity| !entity.deleted && !entity.archived)
+                    .count();
+                (tag.clone(), count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        counts.sort_by(|(first_tag, first_count), (second_tag, second_count)| {
+            second_count.cmp(first_count).then(first_tag.cmp(second_tag))
+        });
+        Ok(counts)
+    }
+
+    async fn get_history_of(&self, uuid: &Uuid) -> Result<Vec<NotificationHistoryEntity>, ApplicationError> {
+        let store = self.store.read().await;
+        Ok(store.history.iter()
+            .filter(|entry| entry.notification_uuid == *uuid)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_snoozed_until(&self, until: DateTime<Utc>, limit: usize) -> Result<Vec<NotificationEntity>, ApplicationError> {
+        let store = self.store.read().await;
+        let mut entities: Vec<NotificationEntity> = store.notifications.values()
+            .filter(|entity| !entity.deleted && entity.snoozed_until.is_some_and(|snoozed_until| snoozed_until <= until))
+            .cloned()
+            .collect();
+        entities.sort_by_key(|entity| entity.snoozed_until);
+        entities.truncate(limit);
+        Ok(entities)
+    }
+
+    /// Scans every notification of the recipient, as there is no index to query.
+    async fn search_of(&self, recipient: &str, query: &str) -> Result<Vec<NotificationSearchHitEntity>, ApplicationError> {
+        let query = SearchQuery::new(query);
+        let store = self.store.read().await;
+        let mut hits: Vec<SearchHit> = store.notifications.values()
+            .filter(|entity| entity.recipient == recipient)
+            .filter_map(|entity| query.search(Notification::from(entity.clone())))
+            .collect();
+        hits.sort_by(|first, second| second.rank.total_cmp(&first.rank)
+            .then(second.notification.date_created.cmp(&first.notification.date_created)));
+        Ok(hits.into_iter().map(|hit| hit.into()).collect())
+    }
+}
+
+#[async_trait]
+impl OutboxRepositoryPort for InMemoryNotificationRepository {
+    async fn get_pending(&self, limit: usize) -> Result<Vec<OutboxEventEntity>, ApplicationError> {
+        let store = self.store.read().await;
+        Ok(store.outbox.iter().take(limit).cloned().collect())
+    }
+
+    async fn save_progress(&self, outbox_event: &OutboxEventEntity) -> Result<(), ApplicationError> {
+        let mut store = self.store.write().await;
+        let stored = store.outbox.iter_mut()
+            .find(|event| event.uuid == outbox_event.uuid)
+            .ok_or_else(|| ApplicationError::NotFound(format!("Outbox event {} not found", outbox_event.uuid)))?;
+        *stored = outbox_event.clone();
+        Ok(())
+    }
+
+    async fn mark_relayed(&self, uuid: &Uuid) -> Result<(), ApplicationError> {
+        let mut store = self.store.write().await;
+        let index = store.outbox.iter()
+            .position(|event| event.uuid == *uuid)
+            .ok_or_else(|| ApplicationError::NotFound(format!("Outbox event {} not found", uuid)))?;
+        store.outbox.remove(index);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::service::clock_service::ClockService;
+    use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+    use crate::domain::model::notification_history::{ChangeSource, NotificationHistoryEntry, NotificationState};
+
+    fn created_event(notification_entity: &NotificationEntity) -> OutboxEventEntity {
+        NotificationEvent::new(NotificationEventType::Created, notification_entity.clone().into()).into()
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let notification_entity: NotificationEntity = Notification::new("recipient".to_string(), "message".to_string()).into();
+
+        let saved = repository.save(&notification_entity, &created_event(&notification_entity), None).await.unwrap();
+        let result = repository.get(&notification_entity.uuid).await.unwrap();
+
+        assert_eq!(saved, NotificationEntity { version: 1, ..notification_entity });
+        assert_eq!(result, saved);
+    }
+
+    #[tokio::test]
+    async fn test_get_not_found() {
+        let repository = InMemoryNotificationRepository::new();
+
+        let result = repository.get(&Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_all_of_oldest_first_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let first: NotificationEntity = Notification::new("recipient".to_string(), "message 1".to_string()).into();
+        let mut second: NotificationEntity = Notification::new("recipient".to_string(), "message 2".to_string()).into();
+        second.date_created = first.date_created + chrono::Duration::seconds(1);
+
+        let second = repository.save(&second, &created_event(&second), None).await.unwrap();
+        let first = repository.save(&first, &created_event(&first), None).await.unwrap();
+        let result = repository.get_all_of("recipient").await.unwrap();
+
+        assert_eq!(result, vec![first, second]);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_of_recipient_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let own: NotificationEntity = Notification::new("recipient".to_string(), "message 1".to_string()).into();
+        let other: NotificationEntity = Notification::new("someone else".to_string(), "message 2".to_string()).into();
+
+        let own = repository.save(&own, &created_event(&own), None).await.unwrap();
+        repository.save(&other, &created_event(&other), None).await.unwrap();
+        let result = repository.get_all_of("recipient").await.unwrap();
+
+        assert_eq!(result, vec![own]);
+    }
+
+    #[tokio::test]
+    async fn test_get_tagged_of_follows_tag_changes_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let mut notification = Notification::new("recipient".to_string(), "message".to_string());
+        notification.add_tag("billing".to_string());
+        let tagged: NotificationEntity = notification.into();
+        let mut other = Notification::new("someone else".to_string(), "message".to_string());
+        other.add_tag("billing".to_string());
+        let other: NotificationEntity = other.into();
+
+        let tagged = repository.save(&tagged, &created_event(&tagged), None).await.unwrap();
+        repository.save(&other, &created_event(&other), None).await.unwrap();
+        let before = repository.get_tagged_of("recipient", "billing").await.unwrap();
+        let mut notification = Notification::from(tagged.clone());
+        notification.remove_tag("billing");
+        notification.add_tag("work".to_string());
+        let retagged: NotificationEntity = notification.into();
+        let retagged = repository.save(&retagged, &created_event(&retagged), None).await.unwrap();
+
+        assert_eq!(before, vec![tagged]);
+        assert!(repository.get_tagged_of("recipient", "billing").await.unwrap().is_empty());
+        assert_eq!(repository.get_tagged_of("recipient", "work").await.unwrap(), vec![retagged]);
+    }
+
+    #[tokio::test]
+    async fn test_count_tags_of_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let tagged = |tags: &[&str], deleted: bool| -> NotificationEntity {
+            let mut notification = Notification::new("recipient".to_string(), "message".to_string());
+            for tag in tags {
+                notification.add_tag(tag.to_string());
+            }
+            if deleted {
+                notification.set_as_deleted();
+            }
+            notification.into()
+        };
+
+        for entity in [&tagged(&["work", "billing"], false), &tagged(&["billing"], false), &tagged(&["urgent"], true)] {
+            repository.save(entity, &created_event(entity), None).await.unwrap();
+        }
+        let result = repository.count_tags_of("recipient").await.unwrap();
+
+        assert_eq!(result, vec![("billing".to_string(), 2), ("work".to_string(), 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_get_snoozed_until_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let now = ClockService::now();
+        let snoozed = |minutes: i64| -> NotificationEntity {
+            let mut notification = Notification::new("recipient".to_string(), "message".to_string());
+            notification.snooze(now + chrono::Duration::minutes(minutes));
+            notification.into()
+        };
+        let (later_due, earlier_due, not_due) = (snoozed(-1), snoozed(-5), snoozed(5));
+        let not_snoozed: NotificationEntity = Notification::new("recipient".to_string(), "message".to_string()).into();
+        let deleted = NotificationEntity { deleted: true, ..snoozed(-3) };
+
+        for entity in [&later_due, &earlier_due, &not_due, &not_snoozed, &deleted] {
+            repository.save(entity, &created_event(entity), None).await.unwrap();
+        }
+        let result: Vec<Uuid> = repository.get_snoozed_until(now, 10).await.unwrap()
+            .iter()
+            .map(|entity| entity.uuid)
+            .collect();
+
+        assert_eq!(result, vec![earlier_due.uuid, later_due.uuid]);
+    }
+
+    #[tokio::test]
+    async fn test_search_of_ranks_matches_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let save = |recipient: &str, title: Option<&str>, message: &str| -> NotificationEntity {
+            Notification {
+                title: title.map(str::to_string),
+                ..Notification::new(recipient.to_string(), message.to_string())
+            }.into()
+        };
+        let in_message = save("recipient", None, "Your invoice was paid");
+        let in_title = save("recipient", Some("Invoice paid"), "Thanks");
+        let partial = save("recipient", None, "Your invoice is due");
+        let other = save("someone else", None, "Your invoice was paid");
+
+        for entity in [&in_message, &in_title, &partial, &other] {
+            repository.save(entity, &created_event(entity), None).await.unwrap();
+        }
+        let result = repository.search_of("recipient", "paid invoice").await.unwrap();
+
+        let found: Vec<Uuid> = result.iter().map(|hit| hit.notification.uuid).collect();
+        assert_eq!(found, vec![in_title.uuid, in_message.uuid]);
+        assert_eq!(result[1].snippet, "Your <mark>invoice</mark> was <mark>paid</mark>");
+    }
+
+    #[tokio::test]
+    async fn test_save_appends_history_entry_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let notification = Notification::new("recipient".to_string(), "message".to_string());
+        let created: NotificationEntity = notification.into();
+        let created = repository.save(&created, &created_event(&created), None).await.unwrap();
+        let mut notification = Notification::from(created);
+        let from_state = NotificationState::from(&notification);
+        notification.set_as_seen();
+        let seen: NotificationEntity = notification.clone().into();
+        let entry: NotificationHistoryEntity = NotificationHistoryEntry::new(
+            notification.uuid,
+            NotificationEventType::Seen,
+            Some("recipient".to_string()),
+            ChangeSource::Rest,
+            from_state,
+            NotificationState::from(&notification),
+        ).into();
+
+        repository.save(&seen, &created_event(&seen), Some(entry.clone())).await.unwrap();
+        let result = repository.get_history_of(&notification.uuid).await.unwrap();
+
+        assert_eq!(result, vec![entry]);
+        assert!(repository.get_history_of(&Uuid::new_v4()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_save_outdated_version_conflict() {
+        let repository = InMemoryNotificationRepository::new();
+        let created: NotificationEntity = Notification::new("recipient".to_string(), "message".to_string()).into();
+        let saved = repository.save(&created, &created_event(&created), None).await.unwrap();
+        let seen = NotificationEntity { seen: true, ..saved.clone() };
+        let deleted = NotificationEntity { seen: true, deleted: true, ..saved };
+
+        repository.save(&seen, &created_event(&seen), None).await.unwrap();
+        let result = repository.save(&deleted, &created_event(&deleted), None).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+        let stored = repository.get(&created.uuid).await.unwrap();
+        assert!(stored.seen && !stored.deleted);
+        assert_eq!(stored.version, 2);
+        assert_eq!(repository.clone().get_pending(10).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_save_appends_outbox_event_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let notification_entity: NotificationEntity = Notification::new("recipient".to_string(), "message".to_string()).into();
+        let outbox_event = created_event(&notification_entity);
+
+        let saved = repository.save(&notification_entity, &outbox_event, None).await.unwrap();
+        let result = repository.clone().get_pending(10).await.unwrap();
+
+        assert_eq!(result, vec![OutboxEventEntity { notification: saved, ..outbox_event }]);
+    }
+
+    #[tokio::test]
+    async fn test_save_queues_event_with_stored_version() {
+        let repository = InMemoryNotificationRepository::new();
+        let notification_entity: NotificationEntity = Notification::new("recipient".to_string(), "message".to_string()).into();
+        let saved = repository.save(&notification_entity, &created_event(&notification_entity), None).await.unwrap();
+
+        let mut notification = Notification::from(saved);
+        notification.set_as_seen();
+        let seen: NotificationEntity = notification.into();
+        repository.save(&seen, &created_event(&seen), None).await.unwrap();
+        let stored = repository.get(&seen.uuid).await.unwrap();
+        let queued = repository.clone().get_pending(10).await.unwrap();
+
+        assert_eq!(queued.iter().map(|event| event.notification.version).collect::<Vec<u64>>(), vec![1, stored.version]);
+        assert_eq!(queued[1].notification, stored);
+    }
+
+    #[tokio::test]
+    async fn test_save_changes_queues_every_event() {
+        let repository = InMemoryNotificationRepository::new();
+        let notification_entity: NotificationEntity = Notification::new("recipient".to_string(), "message".to_string()).into();
+        let saved = repository.save(&notification_entity, &created_event(&notification_entity), None).await.unwrap();
+        repository.clone().mark_relayed(&repository.clone().get_pending(10).await.unwrap()[0].uuid).await.unwrap();
+
+        let mut notification = Notification::from(saved);
+        notification.set_as_seen();
+        notification.set_pinned(true);
+        let changed: NotificationEntity = notification.clone().into();
+        let events: Vec<OutboxEventEntity> = [NotificationEventType::Seen, NotificationEventType::Pinned]
+            .into_iter()
+            .map(|event_type| NotificationEvent::new(event_type, notification.clone()).into())
+            .collect();
+        let saved = repository.save_changes(&changed, &events, vec![]).await.unwrap();
+        let queued = repository.clone().get_pending(10).await.unwrap();
+
+        assert_eq!(queued.iter().map(|event| event.event_type).collect::<Vec<NotificationEventType>>(), vec![
+            NotificationEventType::Seen,
+            NotificationEventType::Pinned,
+        ]);
+        assert!(queued.iter().all(|event| event.notification == saved));
+    }
+
+    #[tokio::test]
+    async fn test_mark_relayed_success() {
+        let repository = InMemoryNotificationRepository::new();
+        let first: NotificationEntity = Notification::new("recipient".to_string(), "message 1".to_string()).into();
+        let second: NotificationEntity = Notification::new("recipient".to_string(), "message 2".to_string()).into();
+        let first_event = created_event(&first);
+        let second_event = created_event(&second);
+        repository.save(&first, &first_event, None).await.unwrap();
+        let second = repository.save(&second, &second_event, None).await.unwrap();
+
+        repository.mark_relayed(&first_event.uuid).await.unwrap();
+        let result = repository.get_pending(10).await.unwrap();
+
+        assert_eq!(result, vec![OutboxEventEntity { notification: second, ..second_event }]);
+    }
+
+    #[tokio::test]
+    async fn test_mark_relayed_removes_event() {
+        let repository = InMemoryNotificationRepository::new();
+        let notification_entity: NotificationEntity = Notification::new("recipient".to_string(), "message".to_string()).into();
+        let outbox_event = created_event(&notification_entity);
+        repository.save(&notification_entity, &outbox_event, None).await.unwrap();
+
+        repository.mark_relayed(&outbox_event.uuid).await.unwrap();
+        let result = repository.mark_relayed(&outbox_event.uuid).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+        assert!(repository.store.read().await.outbox.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_save_progress_keeps_event_pending() {
+        let repository = InMemoryNotificationRepository::new();
+        let notification_entity: NotificationEntity = Notification::new("recipient".to_string(), "message".to_string()).into();
+        let outbox_event = created_event(&notification_entity);
+        repository.save(&notification_entity, &outbox_event, None).await.unwrap();
+        let pending = repository.get_pending(10).await.unwrap().remove(0);
+
+        let progress = OutboxEventEntity {
+            published_at: Some(ClockService::now()),
+            routed_to: vec![Uuid::new_v4()],
+            ..pending
+        };
+        repository.save_progress(&progress).await.unwrap();
+
+        assert_eq!(repository.get_pending(10).await.unwrap(), vec![progress]);
+    }
+
+    #[tokio::test]
+    async fn test_mark_relayed_not_found() {
+        let repository = InMemoryNotificationRepository::new();
+
+        let result = repository.mark_relayed(&Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::adapter::outgoing::persistence::entity::notification_template_entity::NotificationTemplateEntity;
+use crate::application::error::ApplicationError;
+use crate::port::outgoing::persistence::notification_template_repository_port::NotificationTemplateRepositoryPort;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+#[derive(Default)]
+pub struct InMemoryNotificationTemplateRepository {
+    templates: RwLock<HashMap<String, NotificationTemplateEntity>>,
+}
+
+impl InMemoryNotificationTemplateRepository {
+    pub fn new() -> Self {
+        Self { templates: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl NotificationTemplateRepositoryPort for InMemoryNotificationTemplateRepository {
+    async fn save(&self, template: &NotificationTemplateEntity) -> Result<NotificationTemplateEntity, ApplicationError> {
+        let mut templates = self.templates.write().await;
+        templates.insert(template.id.clone(), template.clone());
+        Ok(template.clone())
+    }
+
+    async fn get(&self, id: &str) -> Result<NotificationTemplateEntity, ApplicationError> {
+        let templates = self.templates.read().await;
+        templates.get(id)
+            .cloned()
+            .ok_or_else(|| ApplicationError::NotFound(format!("Notification template {} not found", id)))
+    }
+
+    async fn get_all(&self) -> Result<Vec<NotificationTemplateEntity>, ApplicationError> {
+        let templates = self.templates.read().await;
+        let mut entities: Vec<NotificationTemplateEntity> = templates.values().cloned().collect();
+        entities.sort_by(|first, second| first.id.cmp(&second.id));
+        Ok(entities)
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+        let mut templates = self.templates.write().await;
+        templates.remove(id)
+            .map(|_| ())
+            .ok_or_else(|| ApplicationError::NotFound(format!("Notification template {} not found", id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification_template::NotificationTemplate;
+
+    fn template_entity(id: &str) -> NotificationTemplateEntity {
+        NotificationTemplate::new(id.to_string(), "en".to_string(), "title".to_string(), "body".to_string()).into()
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_success() {
+        let repository = InMemoryNotificationTemplateRepository::new();
+        let second = template_entity("second");
+        let first = template_entity("first");
+
+        repository.save(&second).await.unwrap();
+        repository.save(&first).await.unwrap();
+
+        assert_eq!(repository.get("first").await.unwrap(), first);
+        assert_eq!(repository.get_all().await.unwrap(), vec![first, second]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_success() {
+        let repository = InMemoryNotificationTemplateRepository::new();
+        repository.save(&template_entity("welcome")).await.unwrap();
+
+        repository.delete("welcome").await.unwrap();
+
+        assert!(matches!(repository.get("welcome").await, Err(ApplicationError::NotFound(_))));
+        assert!(matches!(repository.delete("welcome").await, Err(ApplicationError::NotFound(_))));
+    }
+}
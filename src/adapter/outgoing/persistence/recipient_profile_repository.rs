@@ -0,0 +1,50 @@
+use crate::adapter::outgoing::persistence::entity::recipient_profile_entity::RecipientProfileEntity;
+use crate::application::error::ApplicationError;
+use crate::port::outgoing::persistence::recipient_profile_repository_port::RecipientProfileRepositoryPort;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+#[derive(Default)]
+pub struct InMemoryRecipientProfileRepository {
+    profiles: RwLock<HashMap<String, RecipientProfileEntity>>,
+}
+
+impl InMemoryRecipientProfileRepository {
+    pub fn new() -> Self {
+        Self { profiles: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl RecipientProfileRepositoryPort for InMemoryRecipientProfileRepository {
+    async fn save(&self, profile: &RecipientProfileEntity) -> Result<RecipientProfileEntity, ApplicationError> {
+        let mut profiles = self.profiles.write().await;
+        profiles.insert(profile.recipient.clone(), profile.clone());
+        Ok(profile.clone())
+    }
+
+    async fn get(&self, recipient: &str) -> Result<RecipientProfileEntity, ApplicationError> {
+        let profiles = self.profiles.read().await;
+        profiles.get(recipient)
+            .cloned()
+            .ok_or_else(|| ApplicationError::NotFound(format!("Profile of {} not found", recipient)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::recipient_profile::RecipientProfile;
+
+    #[tokio::test]
+    async fn test_save_and_get_success() {
+        let repository = InMemoryRecipientProfileRepository::new();
+        let profile: RecipientProfileEntity = RecipientProfile::new("recipient".to_string()).into();
+
+        repository.save(&profile).await.unwrap();
+
+        assert_eq!(repository.get("recipient").await.unwrap(), profile);
+        assert!(matches!(repository.get("someone else").await, Err(ApplicationError::NotFound(_))));
+    }
+}
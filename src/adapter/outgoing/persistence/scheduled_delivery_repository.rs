@@ -0,0 +1,81 @@
+use crate::adapter::outgoing::persistence::entity::scheduled_delivery_entity::ScheduledDeliveryEntity;
+use crate::application::error::ApplicationError;
+use crate::port::outgoing::persistence::scheduled_delivery_repository_port::ScheduledDeliveryRepositoryPort;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct InMemoryScheduledDeliveryRepository {
+    scheduled_deliveries: RwLock<HashMap<Uuid, ScheduledDeliveryEntity>>,
+}
+
+impl InMemoryScheduledDeliveryRepository {
+    pub fn new() -> Self {
+        Self { scheduled_deliveries: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl ScheduledDeliveryRepositoryPort for InMemoryScheduledDeliveryRepository {
+    async fn save(&self, scheduled_delivery: &ScheduledDeliveryEntity) -> Result<ScheduledDeliveryEntity, ApplicationError> {
+        let mut scheduled_deliveries = self.scheduled_deliveries.write().await;
+        scheduled_deliveries.insert(scheduled_delivery.uuid, scheduled_delivery.clone());
+        Ok(scheduled_delivery.clone())
+    }
+
+    async fn get_due(&self, until: DateTime<Utc>, limit: usize) -> Result<Vec<ScheduledDeliveryEntity>, ApplicationError> {
+        let scheduled_deliveries = self.scheduled_deliveries.read().await;
+        let mut due: Vec<ScheduledDeliveryEntity> = scheduled_deliveries.values()
+            .filter(|scheduled_delivery| scheduled_delivery.due_at <= until)
+            .cloned()
+            .collect();
+        due.sort_by_key(|scheduled_delivery| scheduled_delivery.due_at);
+        due.truncate(limit);
+        Ok(due)
+    }
+
+    async fn delete(&self, uuid: &Uuid) -> Result<(), ApplicationError> {
+        let mut scheduled_deliveries = self.scheduled_deliveries.write().await;
+        scheduled_deliveries.remove(uuid)
+            .map(|_| ())
+            .ok_or_else(|| ApplicationError::NotFound(format!("Scheduled delivery {} not found", uuid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+    use crate::domain::model::scheduled_delivery::ScheduledDelivery;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_get_due_earliest_first() {
+        let repository = InMemoryScheduledDeliveryRepository::new();
+        let now = Utc::now();
+        let scheduled = |due_at| -> ScheduledDeliveryEntity {
+            let event = NotificationEvent::new(
+                NotificationEventType::Created,
+                Notification::new("recipient".to_string(), "message".to_string()),
+            );
+            ScheduledDelivery::new(Uuid::new_v4(), event, due_at).into()
+        };
+        let later = scheduled(now - Duration::minutes(1));
+        let earlier = scheduled(now - Duration::minutes(2));
+        let not_due = scheduled(now + Duration::minutes(1));
+
+        for scheduled_delivery in [&later, &earlier, &not_due] {
+            repository.save(scheduled_delivery).await.unwrap();
+        }
+
+        assert_eq!(repository.get_due(now, 10).await.unwrap(), vec![earlier.clone(), later]);
+        assert_eq!(repository.get_due(now, 1).await.unwrap(), vec![earlier.clone()]);
+
+        repository.delete(&earlier.uuid).await.unwrap();
+        assert!(matches!(repository.delete(&earlier.uuid).await, Err(ApplicationError::NotFound(_))));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::adapter::outgoing::persistence::entity::topic_publication_entity::TopicPublicationEntity;
+use crate::application::error::ApplicationError;
+use crate::domain::model::topic_publication::PublicationStatus;
+use crate::port::outgoing::persistence::topic_publication_repository_port::TopicPublicationRepositoryPort;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct InMemoryTopicPublicationRepository {
+    publications: RwLock<HashMap<Uuid, TopicPublicationEntity>>,
+}
+
+impl InMemoryTopicPublicationRepository {
+    pub fn new() -> Self {
+        Self { publications: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl TopicPublicationRepositoryPort for InMemoryTopicPublicationRepository {
+    async fn save(&self, publication: &TopicPublicationEntity) -> Result<TopicPublicationEntity, ApplicationError> {
+        let mut publications = self.publications.write().await;
+        publications.insert(publication.uuid, publication.clone());
+        Ok(publication.clone())
+    }
+
+    async fn get(&self, uuid: &Uuid) -> Result<TopicPublicationEntity, ApplicationError> {
+        let publications = self.publications.read().await;
+        publications.get(uuid)
+            .cloned()
+            .ok_or_else(|| ApplicationError::NotFound(format!("Publication {} not found", uuid)))
+    }
+
+    async fn get_unfinished(&self, limit: usize) -> Result<Vec<TopicPublicationEntity>, ApplicationError> {
+        let publications = self.publications.read().await;
+        let mut unfinished: Vec<TopicPublicationEntity> = publications.values()
+            .filter(|publication| publication.status != PublicationStatus::Completed)
+            .cloned()
+            .collect();
+        unfinished.sort_by_key(|publication| publication.date_created);
+        unfinished.truncate(limit);
+        Ok(unfinished)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::priority::Priority;
+    use crate::domain::model::topic_publication::TopicPublication;
+
+    #[tokio::test]
+    async fn test_get_unfinished_skips_completed() {
+        let repository = InMemoryTopicPublicationRepository::new();
+        let publication = || TopicPublication::new(
+            "project-x".to_string(), "message".to_string(), "general".to_string(), Priority::Normal, 1,
+        );
+        let pending: TopicPublicationEntity = publication().into();
+        let mut completed = publication();
+        completed.complete();
+        let completed: TopicPublicationEntity = completed.into();
+
+        repository.save(&pending).await.unwrap();
+        repository.save(&completed).await.unwrap();
+
+        assert_eq!(repository.get_unfinished(10).await.unwrap(), vec![pending.clone()]);
+        assert_eq!(repository.get(&completed.uuid).await.unwrap(), completed);
+    }
+}
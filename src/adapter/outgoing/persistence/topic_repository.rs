@@ -0,0 +1,150 @@
+use crate::adapter::outgoing::persistence::entity::topic_entity::{TopicEntity, TopicSubscriptionEntity};
+use crate::application::error::ApplicationError;
+use crate::port::outgoing::persistence::topic_repository_port::TopicRepositoryPort;
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+use tokio::sync::RwLock;
+
+#[derive(Default)]
+pub struct InMemoryTopicRepository {
+    topics: RwLock<HashMap<String, TopicEntity>>,
+    /// Keyed by topic then recipient, so the subscribers of a topic can be paged through in order.
+    subscriptions: RwLock<BTreeMap<(String, String), TopicSubscriptionEntity>>,
+}
+
+impl InMemoryTopicRepository {
+    pub fn new() -> Self {
+        Self { topics: RwLock::new(HashMap::new()), subscriptions: RwLock::new(BTreeMap::new()) }
+    }
+}
+
+#[async_trait]
+impl TopicRepositoryPort for InMemoryTopicRepository {
+    async fn save(&self, topic: &TopicEntity) -> Result<TopicEntity, ApplicationError> {
+        let mut topics = self.topics.write().await;
+        topics.insert(topic.id.clone(), topic.clone());
+        Ok(topic.clone())
+    }
+
+    async fn get(&self, id: &str) -> Result<TopicEntity, ApplicationError> {
+        let topics = self.topics.read().await;
+        topics.get(id)
+            .cloned()
+            .ok_or_else(|| ApplicationError::NotFound(format!("Topic {} not found", id)))
+    }
+
+    async fn get_all(&self) -> Result<Vec<TopicEntity>, ApplicationError> {
+        let topics = self.topics.read().await;
+        let mut entities: Vec<TopicEntity> = topics.values().cloned().collect();
+        entities.sort_by(|first, second| first.id.cmp(&second.id));
+        Ok(entities)
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError> {
+        let mut topics = self.topics.write().await;
+        topics.remove(id).ok_or_else(|| ApplicationError::NotFound(format!("Topic {} not found", id)))?;
+
+        let mut subscriptions = self.subscriptions.write().await;
+        subscriptions.retain(|(topic_id, _), _| topic_id != id);
+        Ok(())
+    }
+
+    async fn subscribe(&self, subscription: &TopicSubscriptionEntity) -> Result<TopicSubscriptionEntity, ApplicationError> {
+        let mut subscriptions = self.subscriptions.write().await;
+        let key = (subscription.topic_id.clone(), subscription.recipient.clone());
+        Ok(subscriptions.entry(key).or_insert_with(|| subscription.clone()).clone())
+    }
+
+    async fn unsubscribe(&self, topic_id: &str, recipient: &str) -> Result<(), ApplicationError> {
+        let mut subscriptions = self.subscriptions.write().await;
+        subscriptions.remove(&(topic_id.to_string(), recipient.to_string()))
+            .map(|_| ())
+            .ok_or_else(|| ApplicationError::NotFound(format!("{} is not subscribed to topic {}", recipient, topic_id)))
+    }
+
+    async fn get_subscriptions(
+        &self,
+        topic_id: &str,
+        after: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<TopicSubscriptionEntity>, ApplicationError> {
+        let subscriptions = self.subscriptions.read().await;
+        let start = match after {
+            Some(recipient) => Bound::Excluded((topic_id.to_string(), recipient)),
+            None => Bound::Included((topic_id.to_string(), String::new())),
+        };
+        Ok(subscriptions.range((start, Bound::Unbounded))
+            .take_while(|((subscribed_topic_id, _), _)| subscribed_topic_id == topic_id)
+            .take(limit)
+            .map(|(_, subscription)| subscription.clone())
+            .collect())
+    }
+
+    async fn count_subscriptions(&self, topic_id: &str) -> Result<usize, ApplicationError> {
+        let subscriptions = self.subscriptions.read().await;
+        Ok(subscriptions.keys().filter(|(subscribed_topic_id, _)| subscribed_topic_id == topic_id).count())
+    }
+
+    async fn get_subscriptions_of(&self, recipient: &str) -> Result<Vec<TopicSubscriptionEntity>, ApplicationError> {
+        let subscriptions = self.subscriptions.read().await;
+        Ok(subscriptions.values()
+            .filter(|subscription| subscription.recipient == recipient)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::topic::{Topic, TopicSubscription};
+
+    fn subscription(topic_id: &str, recipient: &str) -> TopicSubscriptionEntity {
+        TopicSubscription::new(topic_id.to_string(), recipient.to_string()).into()
+    }
+
+    #[tokio::test]
+    async fn test_get_subscriptions_pages_by_recipient() {
+        let repository = InMemoryTopicRepository::new();
+        for (topic_id, recipient) in [("project-x", "carol"), ("project-x", "alice"), ("project-y", "bob"), ("project-x", "bob")] {
+            repository.subscribe(&subscription(topic_id, recipient)).await.unwrap();
+        }
+
+        let recipients = |subscriptions: Vec<TopicSubscriptionEntity>| -> Vec<String> {
+            subscriptions.into_iter().map(|subscription| subscription.recipient).collect()
+        };
+        let first_page = repository.get_subscriptions("project-x", None, 2).await.unwrap();
+        let second_page = repository.get_subscriptions("project-x", Some("bob".to_string()), 2).await.unwrap();
+
+        assert_eq!(recipients(first_page), vec!["alice", "bob"]);
+        assert_eq!(recipients(second_page), vec!["carol"]);
+        assert_eq!(repository.count_subscriptions("project-x").await.unwrap(), 3);
+        assert_eq!(recipients(repository.get_subscriptions_of("bob").await.unwrap()), vec!["bob", "bob"]);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_keeps_first_subscription() {
+        let repository = InMemoryTopicRepository::new();
+        let first = subscription("project-x", "alice");
+
+        repository.subscribe(&first).await.unwrap();
+        let result = repository.subscribe(&subscription("project-x", "alice")).await.unwrap();
+
+        assert_eq!(result, first);
+        assert_eq!(repository.count_subscriptions("project-x").await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_subscriptions() {
+        let repository = InMemoryTopicRepository::new();
+        repository.save(&Topic::new("project-x".to_string(), None).into()).await.unwrap();
+        repository.subscribe(&subscription("project-x", "alice")).await.unwrap();
+
+        repository.delete("project-x").await.unwrap();
+
+        assert!(matches!(repository.get("project-x").await, Err(ApplicationError::NotFound(_))));
+        assert_eq!(repository.count_subscriptions("project-x").await.unwrap(), 0);
+        assert!(matches!(repository.unsubscribe("project-x", "alice").await, Err(ApplicationError::NotFound(_))));
+    }
+}
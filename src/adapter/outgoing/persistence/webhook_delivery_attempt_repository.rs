@@ -0,0 +1,58 @@
+use crate::adapter::outgoing::persistence::entity::webhook_delivery_attempt_entity::WebhookDeliveryAttemptEntity;
+use crate::application::error::ApplicationError;
+use crate::port::outgoing::persistence::webhook_delivery_attempt_repository_port::WebhookDeliveryAttemptRepositoryPort;
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct InMemoryWebhookDeliveryAttemptRepository {
+    attempts: RwLock<Vec<WebhookDeliveryAttemptEntity>>,
+}
+
+impl InMemoryWebhookDeliveryAttemptRepository {
+    pub fn new() -> Self {
+        Self { attempts: RwLock::new(Vec::new()) }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryAttemptRepositoryPort for InMemoryWebhookDeliveryAttemptRepository {
+    async fn save(&self, attempt: &WebhookDeliveryAttemptEntity) -> Result<WebhookDeliveryAttemptEntity, ApplicationError> {
+        let mut attempts = self.attempts.write().await;
+        attempts.push(attempt.clone());
+        Ok(attempt.clone())
+    }
+
+    async fn get_by_subscription(&self, subscription_uuid: &Uuid) -> Result<Vec<WebhookDeliveryAttemptEntity>, ApplicationError> {
+        let attempts = self.attempts.read().await;
+        Ok(attempts.iter()
+            .filter(|attempt| attempt.subscription_uuid == *subscription_uuid)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::notification_event::NotificationEventType;
+    use crate::domain::model::webhook_delivery_attempt::WebhookDeliveryAttempt;
+
+    #[tokio::test]
+    async fn test_get_by_subscription_success() {
+        let repository = InMemoryWebhookDeliveryAttemptRepository::new();
+        let subscription_uuid = Uuid::new_v4();
+        let attempt: WebhookDeliveryAttemptEntity = WebhookDeliveryAttempt::new(
+            subscription_uuid, Uuid::new_v4(), NotificationEventType::Created, 1, Some(200), None,
+        ).into();
+        let other_attempt: WebhookDeliveryAttemptEntity = WebhookDeliveryAttempt::new(
+            Uuid::new_v4(), Uuid::new_v4(), NotificationEventType::Created, 1, Some(200), None,
+        ).into();
+
+        repository.save(&attempt).await.unwrap();
+        repository.save(&other_attempt).await.unwrap();
+
+        assert_eq!(repository.get_by_subscription(&subscription_uuid).await.unwrap(), vec![attempt]);
+    }
+}
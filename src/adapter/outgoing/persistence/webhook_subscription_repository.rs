@@ -0,0 +1,81 @@
+use crate::adapter::outgoing::persistence::entity::webhook_subscription_entity::WebhookSubscriptionEntity;
+use crate::application::error::ApplicationError;
+use crate::port::outgoing::persistence::webhook_subscription_repository_port::WebhookSubscriptionRepositoryPort;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct InMemoryWebhookSubscriptionRepository {
+    subscriptions: RwLock<HashMap<Uuid, WebhookSubscriptionEntity>>,
+}
+
+impl InMemoryWebhookSubscriptionRepository {
+    pub fn new() -> Self {
+        Self { subscriptions: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionRepositoryPort for InMemoryWebhookSubscriptionRepository {
+    async fn save(&self, subscription: &WebhookSubscriptionEntity) -> Result<WebhookSubscriptionEntity, ApplicationError> {
+        let mut subscriptions = self.subscriptions.write().await;
+        subscriptions.insert(subscription.uuid, subscription.clone());
+        Ok(subscription.clone())
+    }
+
+    async fn get(&self, uuid: &Uuid) -> Result<WebhookSubscriptionEntity, ApplicationError> {
+        let subscriptions = self.subscriptions.read().await;
+        subscriptions.get(uuid)
+            .cloned()
+            .ok_or_else(|| ApplicationError::NotFound(format!("Webhook subscription {} not found", uuid)))
+    }
+
+    async fn get_all(&self) -> Result<Vec<WebhookSubscriptionEntity>, ApplicationError> {
+        let subscriptions = self.subscriptions.read().await;
+        let mut entities: Vec<WebhookSubscriptionEntity> = subscriptions.values().cloned().collect();
+        entities.sort_by_key(|entity| entity.date_created);
+        Ok(entities)
+    }
+
+    async fn delete(&self, uuid: &Uuid) -> Result<(), ApplicationError> {
+        let mut subscriptions = self.subscriptions.write().await;
+        subscriptions.remove(uuid)
+            .map(|_| ())
+            .ok_or_else(|| ApplicationError::NotFound(format!("Webhook subscription {} not found", uuid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::webhook_subscription::WebhookSubscription;
+
+    fn subscription_entity() -> WebhookSubscriptionEntity {
+        WebhookSubscription::new("http://localhost/hook".to_string(), "secret".to_string(), vec![]).into()
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_success() {
+        let repository = InMemoryWebhookSubscriptionRepository::new();
+        let subscription = subscription_entity();
+
+        repository.save(&subscription).await.unwrap();
+
+        assert_eq!(repository.get(&subscription.uuid).await.unwrap(), subscription);
+        assert_eq!(repository.get_all().await.unwrap(), vec![subscription]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_success() {
+        let repository = InMemoryWebhookSubscriptionRepository::new();
+        let subscription = subscription_entity();
+        repository.save(&subscription).await.unwrap();
+
+        repository.delete(&subscription.uuid).await.unwrap();
+
+        assert!(matches!(repository.get(&subscription.uuid).await, Err(ApplicationError::NotFound(_))));
+        assert!(matches!(repository.delete(&subscription.uuid).await, Err(ApplicationError::NotFound(_))));
+    }
+}
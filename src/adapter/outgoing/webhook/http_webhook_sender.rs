@@ -0,0 +1,83 @@
+use crate::application::error::ApplicationError;
+use crate::port::outgoing::webhook::webhook_sender_port::WebhookSenderPort;
+use async_trait::async_trait;
+use std::time::Duration;
+
+pub struct HttpWebhookSender {
+    http_client: reqwest::Client,
+}
+
+impl HttpWebhookSender {
+    pub fn new(timeout: Duration) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .expect("Failed to build webhook HTTP client");
+
+        Self { http_client }
+    }
+}
+
+#[async_trait]
+impl WebhookSenderPort for HttpWebhookSender {
+    async fn post(&self, url: &str, headers: Vec<(String, String)>, body: String) -> Result<u16, ApplicationError> {
+        let mut request = self.http_client.post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body);
+
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+
+        let response = request.send()
+            .await
+            .map_err(|error| ApplicationError::InternalError(format!("Webhook delivery to {} failed: {}", url, error)))?;
+
+        Ok(response.status().as_u16())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_post_reaches_receiver() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let handler = move |headers: HeaderMap, body: String| async move {
+            let signature = headers.get("X-Signature").unwrap().to_str().unwrap().to_string();
+            sender.send((signature, body)).unwrap();
+            StatusCode::ACCEPTED
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, Router::new().route("/hook", post(handler))).await.unwrap();
+        });
+
+        let webhook_sender = HttpWebhookSender::new(Duration::from_secs(5));
+        let result = webhook_sender.post(
+            &format!("http://{}/hook", address),
+            vec![("X-Signature".to_string(), "sha256=abc".to_string())],
+            "{}".to_string(),
+        ).await;
+
+        assert_eq!(result.unwrap(), 202);
+        assert_eq!(receiver.recv().await.unwrap(), ("sha256=abc".to_string(), "{}".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_post_unreachable_error() {
+        let webhook_sender = HttpWebhookSender::new(Duration::from_secs(5));
+
+        let result = webhook_sender.post("http://127.0.0.1:1/hook", vec![], "{}".to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::InternalError(_))));
+    }
+}
@@ -0,0 +1,9 @@
+use crate::domain::model::priority::Priority;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateBroadcastCommand {
+    pub title: Option<String>,
+    pub message: String,
+    pub category: Option<String>,
+    pub priority: Priority,
+}
@@ -0,0 +1,15 @@
+use crate::domain::model::notification_action::NotificationAction;
+use crate::domain::model::priority::Priority;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateNotificationCommand {
+    pub recipient: String,
+    pub message: String,
+    pub category: Option<String>,
+    pub priority: Priority,
+    pub group_key: Option<String>,
+    pub actor: Option<String>,
+    pub actions: Vec<NotificationAction>,
+    pub auto_resolve: bool,
+    pub tags: Vec<String>,
+}
@@ -0,0 +1,19 @@
+use crate::domain::model::notification_action::NotificationAction;
+use crate::domain::model::preference::Channel;
+use crate::domain::model::priority::Priority;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateNotificationFromTemplateCommand {
+    pub recipient: String,
+    pub template_id: String,
+    pub channel: Option<Channel>,
+    pub category: Option<String>,
+    pub priority: Priority,
+    pub group_key: Option<String>,
+    pub actor: Option<String>,
+    pub actions: Vec<NotificationAction>,
+    pub auto_resolve: bool,
+    pub tags: Vec<String>,
+    pub variables: HashMap<String, String>,
+}
@@ -0,0 +1,5 @@
+pub mod create_broadcast_command;
+pub mod create_notification_command;
+pub mod create_notification_from_template_command;
+pub mod publish_to_topic_command;
+pub mod update_notification_command;
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+
+/// Partial change to the state of a notification; what is left out stays as it is.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UpdateNotificationCommand {
+    pub seen: Option<bool>,
+    pub pinned: Option<bool>,
+    pub archived: Option<bool>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+}
+
+impl UpdateNotificationCommand {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
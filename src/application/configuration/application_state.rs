@@ -1,7 +1,53 @@
+use crate::adapter::incoming::rest::broadcast_api::BroadcastApi;
 use crate::adapter::incoming::rest::notification_api::NotificationApi;
+use crate::adapter::incoming::rest::notification_stream_api::NotificationStreamApi;
+use crate::adapter::incoming::rest::notification_template_api::NotificationTemplateApi;
+use crate::adapter::incoming::rest::recipient_profile_api::RecipientProfileApi;
+use crate::adapter::incoming::rest::topic_api::TopicApi;
+use crate::adapter::incoming::rest::webhook_api::WebhookApi;
+use crate::adapter::incoming::websocket::notification_socket_api::NotificationSocketApi;
+use crate::application::service::authentication_service::AuthenticationServicePort;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct AppState {
+    pub authentication_service: Arc<dyn AuthenticationServicePort>,
+    pub broadcast_api: Arc<BroadcastApi>,
     pub notification_api: Arc<NotificationApi>,
-}
\ No newline at end of file
+    pub notification_socket_api: Arc<NotificationSocketApi>,
+    pub notification_stream_api: Arc<NotificationStreamApi>,
+    pub notification_template_api: Arc<NotificationTemplateApi>,
+    pub recipient_profile_api: Arc<RecipientProfileApi>,
+    pub topic_api: Arc<TopicApi>,
+    pub webhook_api: Arc<WebhookApi>,
+}
+
+#[cfg(test)]
+impl AppState {
+    /// State backed by mocks without expectations, for tests to override the parts they exercise.
+    pub fn mocked() -> Self {
+        use crate::application::service::authentication_service::MockAuthenticationServicePort;
+        use crate::application::service::broadcast_service::MockBroadcastServicePort;
+        use crate::application::service::notification_service::MockNotificationServicePort;
+        use crate::application::service::notification_stream_service::MockNotificationStreamServicePort;
+        use crate::application::service::notification_template_service::MockNotificationTemplateServicePort;
+        use crate::application::service::recipient_profile_service::MockRecipientProfileServicePort;
+        use crate::application::service::topic_service::MockTopicServicePort;
+        use crate::application::service::webhook_service::MockWebhookServicePort;
+
+        Self {
+            authentication_service: Arc::new(MockAuthenticationServicePort::new()),
+            broadcast_api: Arc::new(BroadcastApi::new(Arc::new(MockBroadcastServicePort::new()))),
+            notification_api: Arc::new(NotificationApi::new(Arc::new(MockNotificationServicePort::new()), "unassigned".to_string())),
+            notification_socket_api: Arc::new(NotificationSocketApi::new(
+                Arc::new(MockNotificationServicePort::new()),
+                Arc::new(MockNotificationStreamServicePort::new()),
+            )),
+            notification_stream_api: Arc::new(NotificationStreamApi::new(Arc::new(MockNotificationStreamServicePort::new()))),
+            notification_template_api: Arc::new(NotificationTemplateApi::new(Arc::new(MockNotificationTemplateServicePort::new()))),
+            recipient_profile_api: Arc::new(RecipientProfileApi::new(Arc::new(MockRecipientProfileServicePort::new()))),
+            topic_api: Arc::new(TopicApi::new(Arc::new(MockTopicServicePort::new()))),
+            webhook_api: Arc::new(WebhookApi::new(Arc::new(MockWebhookServicePort::new()))),
+        }
+    }
+}
@@ -1,10 +1,80 @@
 use crate::application::configuration::structs::AppConfiguration;
-use config::{Config, ConfigError, Environment, File};
+use config::{Config, ConfigError, Environment, File, FileFormat};
+use std::env;
+use std::fs;
+
+const CONFIGURATION_FILE: &str = "configuration.yaml";
 
 pub fn load_config() -> Result<AppConfiguration, ConfigError> {
+    let content = fs::read_to_string(CONFIGURATION_FILE)
+        .map_err(|error| ConfigError::Foreign(Box::new(error)))?;
+
     let config_builder = Config::builder()
-        .add_source(File::with_name("configuration.yaml"))
+        .add_source(File::from_str(&expand_placeholders(&content), FileFormat::Yaml))
         .add_source(Environment::default().separator("_"));
 
-    Ok(config_builder.build()?.try_deserialize()?)
-}
\ No newline at end of file
+    config_builder.build()?.try_deserialize()
+}
+
+/// Replaces every `${VARIABLE:-default}` placeholder with the value of `VARIABLE`,
+/// falling back to `default` when the variable is not set.
+fn expand_placeholders(content: &str) -> String {
+    let mut expanded = String::with_capacity(content.len());
+    let mut remaining = content;
+
+    while let Some(start) = remaining.find("${") {
+        let Some(length) = remaining[start..].find('}') else {
+            break;
+        };
+
+        let placeholder = &remaining[start + 2..start + length];
+        let (variable, default) = placeholder.split_once(":-").unwrap_or((placeholder, ""));
+
+        expanded.push_str(&remaining[..start]);
+        expanded.push_str(&env::var(variable).unwrap_or_else(|_| default.to_string()));
+        remaining = &remaining[start + length + 1..];
+    }
+
+    expanded.push_str(remaining);
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::configuration::structs::AuthenticationSettings;
+
+    #[test]
+    fn test_expand_placeholders_with_default() {
+        let result = expand_placeholders("port: ${NOTIFICATION_CENTER_UNSET_VARIABLE:-8080}");
+
+        assert_eq!(result, "port: 8080");
+    }
+
+    #[test]
+    fn test_expand_placeholders_without_placeholders() {
+        let result = expand_placeholders("port: 8080");
+
+        assert_eq!(result, "port: 8080");
+    }
+
+    #[test]
+    fn test_load_config_success() {
+        env::set_var("AUTHENTICATION_TOKEN_SECRET", "secret");
+        let result = load_config().unwrap();
+
+        assert!(!result.app.incoming.sqs.queue_url.is_empty());
+        assert_eq!(result.app.localisation.fallback_locales, vec!["en".to_string()]);
+        assert!(result.app.preferences.categories.contains_key("general"));
+    }
+
+    #[test]
+    fn test_token_secret_is_required() {
+        let settings = Config::builder()
+            .add_source(File::from_str("token_secret: \"\"", FileFormat::Yaml))
+            .build()
+            .unwrap();
+
+        assert!(settings.try_deserialize::<AuthenticationSettings>().is_err());
+    }
+}
@@ -1,19 +1,102 @@
-use serde::Deserialize;
+use crate::domain::model::preference::Channel;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
 
 #[derive(Debug, Deserialize)]
 pub struct AppConfiguration {
     pub app: AppSettings,
+    #[allow(dead_code)]
     pub database: DatabaseSettings,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct AppSettings{
-    pub incoming: IncomingSettings
+    pub authentication: AuthenticationSettings,
+    pub incoming: IncomingSettings,
+    pub fan_out: FanOutSettings,
+    pub localisation: LocalisationSettings,
+    pub outgoing: OutgoingSettings,
+    pub preferences: PreferenceSettings,
+    pub snooze: SnoozeSettings,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuthenticationSettings {
+    /// Has no default: anyone knowing it can issue tokens for any recipient.
+    #[serde(deserialize_with = "required")]
+    pub token_secret: String,
+    /// Recipients allowed to manage webhook subscriptions; none by default.
+    #[serde(default, deserialize_with = "comma_separated")]
+    pub administrators: Vec<String>,
+}
+
+fn required<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    if value.trim().is_empty() {
+        return Err(serde::de::Error::custom("must be set"));
+    }
+    Ok(value)
+}
+
+/// Pace of the fan-out of topic publications to their subscribers.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FanOutSettings {
+    pub poll_interval_milliseconds: u64,
+    /// Subscribers notified per publication and poll.
+    pub batch_size: usize,
+    pub publications_per_poll: usize,
+}
+
+/// How often snoozed notifications are checked for, and how many are resurfaced at a time.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SnoozeSettings {
+    pub poll_interval_milliseconds: u64,
+    pub batch_size: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LocalisationSettings {
+    /// Locales tried, in order, when none of the recipient's is available.
+    #[serde(deserialize_with = "comma_separated")]
+    pub fallback_locales: Vec<String>,
+}
+
+/// Lists are configured as comma separated strings, so they can be overridden by a single environment variable.
+fn comma_separated<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(value.split(',').map(str::trim).filter(|item| !item.is_empty()).map(str::to_string).collect())
+}
+
+/// Categories notifications can belong to, with whether each channel is enabled until the recipient chooses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PreferenceSettings {
+    pub categories: HashMap<String, CategoryDefaults>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CategoryDefaults {
+    pub email: bool,
+    pub push: bool,
+    pub sms: bool,
+}
+
+impl PreferenceSettings {
+    /// Unknown categories are disabled everywhere, they are rejected when notifications are created.
+    pub fn default_enabled(&self, category: &str, channel: Channel) -> bool {
+        self.categories.get(category).is_some_and(|defaults| match channel {
+            Channel::Email => defaults.email,
+            Channel::Push => defaults.push,
+            Channel::Sms => defaults.sms,
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
 pub struct IncomingSettings {
-    pub rest: RestSettings
+    /// Recipient of the notifications created without one, as they were before notifications had recipients.
+    pub default_recipient: String,
+    pub rest: RestSettings,
+    pub sqs: SqsSettings,
 }
 
 #[derive(Debug, Deserialize)]
@@ -21,8 +104,56 @@ pub struct RestSettings {
     pub port: u16
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct SqsSettings {
+    pub enabled: bool,
+    pub endpoint: String,
+    pub queue_url: String,
+    pub dead_letter_queue_url: String,
+    pub wait_time_seconds: u8,
+    pub max_number_of_messages: u8,
+    pub max_receive_count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OutgoingSettings {
+    pub digest: DigestSettings,
+    pub outbox: OutboxSettings,
+    pub scheduler: SchedulerSettings,
+    pub webhook: WebhookSettings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutboxSettings {
+    pub poll_interval_milliseconds: u64,
+    pub batch_size: usize,
+}
+
+/// How often due digests are checked for, and how many are sent at a time.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DigestSettings {
+    pub poll_interval_milliseconds: u64,
+    pub batch_size: usize,
+}
+
+/// How often deliveries deferred by quiet hours are checked for, and how many are released at a time.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SchedulerSettings {
+    pub poll_interval_milliseconds: u64,
+    pub batch_size: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookSettings {
+    pub max_attempts: u32,
+    pub initial_backoff_milliseconds: u64,
+    pub max_backoff_milliseconds: u64,
+    pub timeout_seconds: u64,
+}
+
+#[allow(dead_code)] // not read until a database adapter replaces the in-memory repository
 #[derive(Debug, Deserialize)]
 pub struct DatabaseSettings {
     pub url: String,
     pub max_connections: u16,
-}
\ No newline at end of file
+}
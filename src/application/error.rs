@@ -1,29 +1,98 @@
+use crate::domain::model::notification_action::ActionError;
+use crate::domain::model::notification_template::TemplateError;
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
     Json,
 };
-use serde_json::json;
+use serde::Serialize;
+use thiserror::Error;
+use utoipa::ToSchema;
 
-
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum ApplicationError {
+    #[error("{0}")]
     NotFound(String),
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    Unauthorized(String),
+    /// The recipient is authenticated but may not do what was asked.
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Conflict(String),
+    /// The request was conditioned on a version of the resource that is no longer current.
+    #[error("{0}")]
+    PreconditionFailed(String),
+    #[error("{0}")]
     InternalError(String),
 }
 
+/// Templates are supplied by clients, so rendering them can only fail because of the request.
+impl From<TemplateError> for ApplicationError {
+    fn from(error: TemplateError) -> Self {
+        ApplicationError::BadRequest(error.to_string())
+    }
+}
+
+impl From<ActionError> for ApplicationError {
+    fn from(error: ActionError) -> Self {
+        match error {
+            ActionError::UnknownAction(_) => ApplicationError::NotFound(error.to_string()),
+            ActionError::AlreadyResolved(_) => ApplicationError::Conflict(error.to_string()),
+        }
+    }
+}
+
+/// Body of every error response.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
 impl IntoResponse for ApplicationError {
     fn into_response(self) -> Response {
         match self {
             ApplicationError::NotFound(message) => (
                 StatusCode::NOT_FOUND,
-                Json(json!({ "error": message })),
+                Json(ErrorResponse { error: message }),
+            )
+                .into_response(),
+
+            ApplicationError::BadRequest(message) => (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse { error: message }),
+            )
+                .into_response(),
+
+            ApplicationError::Unauthorized(message) => (
+                StatusCode::UNAUTHORIZED,
+                Json(ErrorResponse { error: message }),
+            )
+                .into_response(),
+
+            ApplicationError::Forbidden(message) => (
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse { error: message }),
+            )
+                .into_response(),
+
+            ApplicationError::Conflict(message) => (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse { error: message }),
+            )
+                .into_response(),
+
+            ApplicationError::PreconditionFailed(message) => (
+                StatusCode::PRECONDITION_FAILED,
+                Json(ErrorResponse { error: message }),
             )
                 .into_response(),
 
             ApplicationError::InternalError(message) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
-                Json(json!({ "error": message })),
+                Json(ErrorResponse { error: message }),
             )
                 .into_response(),
         }
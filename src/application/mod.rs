@@ -1,3 +1,5 @@
+pub mod command;
 pub mod service;
 pub mod error;
-pub mod configuration;
\ No newline at end of file
+pub mod configuration;
+pub mod worker;
\ No newline at end of file
@@ -0,0 +1,10 @@
+use crate::application::error::ApplicationError;
+use mockall::automock;
+
+#[automock]
+pub trait AuthenticationServicePort: Send + Sync {
+    /// Validates a bearer token and returns the recipient it was issued to.
+    fn authenticate(&self, token: &str) -> Result<String, ApplicationError>;
+    /// Whether the recipient may manage what is shared by every recipient, such as webhook subscriptions.
+    fn is_administrator(&self, recipient: &str) -> bool;
+}
@@ -0,0 +1,15 @@
+use crate::application::command::create_broadcast_command::CreateBroadcastCommand;
+use crate::application::error::ApplicationError;
+use crate::domain::model::broadcast::Broadcast;
+use async_trait::async_trait;
+use mockall::automock;
+
+/// Broadcasts reach every recipient through their inbox; seen and deleted state is kept by the notification service.
+#[automock]
+#[async_trait]
+pub trait BroadcastServicePort: Send + Sync {
+    async fn create_broadcast(&self, command: CreateBroadcastCommand) -> Result<Broadcast, ApplicationError>;
+    async fn get_broadcast(&self, uuid: String) -> Result<Broadcast, ApplicationError>;
+    async fn list_broadcasts(&self) -> Result<Vec<Broadcast>, ApplicationError>;
+    async fn delete_broadcast(&self, uuid: String) -> Result<(), ApplicationError>;
+}
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use mockall::automock;
 
 pub struct ClockService {}
 
@@ -7,3 +8,15 @@ impl ClockService {
         Utc::now()
     }
 }
+
+/// Current time for components whose behaviour depends on it, so tests can control it.
+#[automock]
+pub trait ClockPort: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+impl ClockPort for ClockService {
+    fn now(&self) -> DateTime<Utc> {
+        ClockService::now()
+    }
+}
@@ -0,0 +1,30 @@
+use crate::application::error::ApplicationError;
+use crate::domain::model::notification::Notification;
+use crate::domain::model::webhook_subscription::WebhookSubscription;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeliveryDecision {
+    Deliver,
+    /// Not delivered at all, for the given reason.
+    Suppress(String),
+    /// Delivered once the given time is reached.
+    Defer(DateTime<Utc>),
+    /// Delivered together with other notifications in the recipient's digest, due at the given time unless one is
+    /// already open.
+    Digest(DateTime<Utc>),
+}
+
+#[automock]
+#[async_trait]
+pub trait DeliveryPolicyServicePort: Send + Sync {
+    /// Applies the recipient's preferences, digest frequency and quiet hours to a delivery through the subscription's
+    /// channel.
+    async fn decide(
+        &self,
+        subscription: &WebhookSubscription,
+        notification: &Notification,
+    ) -> Result<DeliveryDecision, ApplicationError>;
+}
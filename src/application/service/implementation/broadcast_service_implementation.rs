@@ -0,0 +1,121 @@
+use crate::adapter::outgoing::persistence::entity::broadcast_entity::BroadcastEntity;
+use crate::adapter::outgoing::persistence::entity::outbox_event_entity::OutboxEventEntity;
+use crate::application::command::create_broadcast_command::CreateBroadcastCommand;
+use crate::application::configuration::structs::PreferenceSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::broadcast_service::BroadcastServicePort;
+use crate::application::service::implementation::notification_service_implementation::resolve_category;
+use crate::application::service::implementation::uuid_parser::parse_uuid;
+use crate::domain::model::broadcast::{Broadcast, EVERY_RECIPIENT};
+use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+use crate::port::outgoing::persistence::broadcast_repository_port::BroadcastRepositoryPort;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct BroadcastServiceImplementation {
+    broadcast_repository: Arc<dyn BroadcastRepositoryPort>,
+    preference_settings: PreferenceSettings,
+}
+
+impl BroadcastServiceImplementation {
+    pub fn new(broadcast_repository: Arc<dyn BroadcastRepositoryPort>, preference_settings: PreferenceSettings) -> Self {
+        Self { broadcast_repository, preference_settings }
+    }
+}
+
+#[async_trait]
+impl BroadcastServicePort for BroadcastServiceImplementation {
+    async fn create_broadcast(&self, command: CreateBroadcastCommand) -> Result<Broadcast, ApplicationError> {
+        let category = resolve_category(&self.preference_settings, command.category)?;
+        let broadcast = Broadcast {
+            category,
+            priority: command.priority,
+            ..Broadcast::new(command.title, command.message)
+        };
+        let broadcast_entity: BroadcastEntity = broadcast.clone().into();
+        let notification = broadcast.to_notification(EVERY_RECIPIENT.to_string(), None);
+        let outbox_event: OutboxEventEntity = NotificationEvent::new(NotificationEventType::Created, notification).into();
+        self.broadcast_repository.save(&broadcast_entity, &outbox_event).await?;
+        Ok(broadcast)
+    }
+
+    async fn get_broadcast(&self, uuid: String) -> Result<Broadcast, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        Ok(self.broadcast_repository.get(&parsed_uuid).await?.into())
+    }
+
+    async fn list_broadcasts(&self) -> Result<Vec<Broadcast>, ApplicationError> {
+        let broadcast_entities = self.broadcast_repository.get_all().await?;
+        Ok(broadcast_entities.into_iter().map(|entity| entity.into()).collect())
+    }
+
+    async fn delete_broadcast(&self, uuid: String) -> Result<(), ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        self.broadcast_repository.delete(&parsed_uuid).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::configuration::structs::CategoryDefaults;
+    use crate::domain::model::notification::NotificationKind;
+    use crate::domain::model::priority::Priority;
+    use crate::port::outgoing::persistence::broadcast_repository_port::MockBroadcastRepositoryPort;
+    use std::collections::HashMap;
+
+    fn service(broadcast_repository: MockBroadcastRepositoryPort) -> BroadcastServiceImplementation {
+        let preference_settings = PreferenceSettings {
+            categories: HashMap::from([
+                ("general".to_string(), CategoryDefaults { email: true, push: true, sms: false }),
+            ]),
+        };
+        BroadcastServiceImplementation::new(Arc::new(broadcast_repository), preference_settings)
+    }
+
+    #[tokio::test]
+    async fn test_create_broadcast_success() {
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_save()
+            .withf(|broadcast, outbox_event| {
+                broadcast.category == "general"
+                    && broadcast.priority == Priority::High
+                    && outbox_event.event_type == NotificationEventType::Created
+                    && outbox_event.notification.uuid == broadcast.uuid
+                    && outbox_event.notification.kind == NotificationKind::Broadcast
+                    && outbox_event.notification.recipient == EVERY_RECIPIENT
+            })
+            .times(1)
+            .returning(|broadcast, _| Ok(broadcast.clone()));
+
+        let result = service(broadcast_repository)
+            .create_broadcast(CreateBroadcastCommand {
+                title: Some("Maintenance".to_string()),
+                message: "Down at 22:00".to_string(),
+                category: None,
+                priority: Priority::High,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.title, Some("Maintenance".to_string()));
+        assert_eq!(result.message, "Down at 22:00");
+    }
+
+    #[tokio::test]
+    async fn test_create_broadcast_unknown_category_error() {
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_save().never();
+
+        let result = service(broadcast_repository)
+            .create_broadcast(CreateBroadcastCommand {
+                title: None,
+                message: "message".to_string(),
+                category: Some("unknown".to_string()),
+                priority: Priority::Normal,
+            })
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+}
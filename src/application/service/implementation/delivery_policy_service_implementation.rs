@@ -0,0 +1,172 @@
+use crate::application::configuration::structs::PreferenceSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::clock_service::ClockPort;
+use crate::application::service::delivery_policy_service::{DeliveryDecision, DeliveryPolicyServicePort};
+use crate::application::service::implementation::recipient_profile_service_implementation::{is_enabled, load_profile};
+use crate::domain::model::notification::Notification;
+use crate::domain::model::priority::Priority;
+use crate::domain::model::webhook_subscription::WebhookSubscription;
+use crate::port::outgoing::persistence::recipient_profile_repository_port::RecipientProfileRepositoryPort;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct DeliveryPolicyServiceImplementation {
+    profile_repository: Arc<dyn RecipientProfileRepositoryPort>,
+    preference_settings: PreferenceSettings,
+    clock: Arc<dyn ClockPort>,
+}
+
+impl DeliveryPolicyServiceImplementation {
+    pub fn new(
+        profile_repository: Arc<dyn RecipientProfileRepositoryPort>,
+        preference_settings: PreferenceSettings,
+        clock: Arc<dyn ClockPort>,
+    ) -> Self {
+        Self { profile_repository, preference_settings, clock }
+    }
+}
+
+#[async_trait]
+impl DeliveryPolicyServicePort for DeliveryPolicyServiceImplementation {
+    /// Subscriptions without a channel are integrations rather than recipient channels, so they always receive events.
+    async fn decide(
+        &self,
+        subscription: &WebhookSubscription,
+        notification: &Notification,
+    ) -> Result<DeliveryDecision, ApplicationError> {
+        let Some(channel) = subscription.channel else { return Ok(DeliveryDecision::Deliver) };
+
+        let profile = load_profile(self.profile_repository.as_ref(), &notification.recipient).await?;
+        if !is_enabled(&self.preference_settings, &profile, &notification.category, channel) {
+            return Ok(DeliveryDecision::Suppress(format!(
+                "Recipient has {} disabled for {} notifications", channel.as_str(), notification.category,
+            )));
+        }
+
+        let now = self.clock.now();
+        if let (Priority::Low, Some(frequency)) = (notification.priority, profile.digest_frequency) {
+            let due_at = frequency.next_boundary(now);
+            let due_at = profile.quiet_hours.deferred_until(due_at, Priority::Low).unwrap_or(due_at);
+            return Ok(DeliveryDecision::Digest(due_at));
+        }
+
+        Ok(match profile.quiet_hours.deferred_until(now, notification.priority) {
+            Some(until) => DeliveryDecision::Defer(until),
+            None => DeliveryDecision::Deliver,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::configuration::structs::CategoryDefaults;
+    use crate::application::service::clock_service::MockClockPort;
+    use crate::domain::model::digest::DigestFrequency;
+    use crate::domain::model::preference::{Channel, ChannelPreference};
+    use crate::domain::model::quiet_hours::{QuietHours, QuietWindow};
+    use crate::domain::model::recipient_profile::RecipientProfile;
+    use crate::port::outgoing::persistence::recipient_profile_repository_port::MockRecipientProfileRepositoryPort;
+    use chrono::{DateTime, Utc};
+    use std::collections::HashMap;
+
+    fn instant(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    fn service(now: &str) -> DeliveryPolicyServiceImplementation {
+        let mut profile_repository = MockRecipientProfileRepositoryPort::new();
+        profile_repository.expect_get()
+            .returning(|recipient| {
+                let mut profile = RecipientProfile::new(recipient.to_string());
+                profile.set_preference(ChannelPreference { category: "general".to_string(), channel: Channel::Push, enabled: false });
+                profile.set_quiet_hours(QuietHours {
+                    window: Some(QuietWindow {
+                        start: "22:00".parse().unwrap(),
+                        end: "07:00".parse().unwrap(),
+                        time_zone: chrono_tz::UTC,
+                    }),
+                    ..QuietHours::default()
+                });
+                profile.set_digest_frequency(Some(DigestFrequency::Hourly));
+                Ok(profile.into())
+            });
+
+        let now = instant(now);
+        let mut clock = MockClockPort::new();
+        clock.expect_now().returning(move || now);
+
+        let preference_settings = PreferenceSettings {
+            categories: HashMap::from([
+                ("general".to_string(), CategoryDefaults { email: true, push: true, sms: false }),
+            ]),
+        };
+
+        DeliveryPolicyServiceImplementation::new(Arc::new(profile_repository), preference_settings, Arc::new(clock))
+    }
+
+    fn subscription(channel: Option<Channel>) -> WebhookSubscription {
+        WebhookSubscription { channel, ..WebhookSubscription::new("http://localhost/hook".to_string(), "secret".to_string(), vec![]) }
+    }
+
+    fn notification(priority: Priority) -> Notification {
+        Notification { priority, ..Notification::new("recipient".to_string(), "message".to_string()) }
+    }
+
+    #[tokio::test]
+    async fn test_decide_delivers_outside_quiet_hours() {
+        let service = service("2026-01-10T12:00:00Z");
+
+        let result = service.decide(&subscription(Some(Channel::Email)), &notification(Priority::Normal)).await;
+
+        assert_eq!(result.unwrap(), DeliveryDecision::Deliver);
+    }
+
+    #[tokio::test]
+    async fn test_decide_suppresses_disabled_channel() {
+        let service = service("2026-01-10T12:00:00Z");
+
+        let result = service.decide(&subscription(Some(Channel::Push)), &notification(Priority::Normal)).await;
+
+        assert!(matches!(result.unwrap(), DeliveryDecision::Suppress(_)));
+    }
+
+    #[tokio::test]
+    async fn test_decide_defers_during_quiet_hours() {
+        let service = service("2026-01-10T23:00:00Z");
+
+        let result = service.decide(&subscription(Some(Channel::Email)), &notification(Priority::High)).await;
+
+        assert_eq!(result.unwrap(), DeliveryDecision::Defer(instant("2026-01-11T07:00:00Z")));
+    }
+
+    #[tokio::test]
+    async fn test_decide_collects_low_priority_into_digest() {
+        let daytime = service("2026-01-10T12:10:00Z");
+        let evening = service("2026-01-10T21:30:00Z");
+
+        let at_noon = daytime.decide(&subscription(Some(Channel::Email)), &notification(Priority::Low)).await;
+        let before_quiet_hours = evening.decide(&subscription(Some(Channel::Email)), &notification(Priority::Low)).await;
+
+        assert_eq!(at_noon.unwrap(), DeliveryDecision::Digest(instant("2026-01-10T13:00:00Z")));
+        assert_eq!(before_quiet_hours.unwrap(), DeliveryDecision::Digest(instant("2026-01-11T07:00:00Z")));
+    }
+
+    #[tokio::test]
+    async fn test_decide_urgent_bypasses_quiet_hours() {
+        let service = service("2026-01-10T23:00:00Z");
+
+        let result = service.decide(&subscription(Some(Channel::Email)), &notification(Priority::Urgent)).await;
+
+        assert_eq!(result.unwrap(), DeliveryDecision::Deliver);
+    }
+
+    #[tokio::test]
+    async fn test_decide_always_delivers_without_channel() {
+        let service = service("2026-01-10T23:00:00Z");
+
+        let result = service.decide(&subscription(None), &notification(Priority::Normal)).await;
+
+        assert_eq!(result.unwrap(), DeliveryDecision::Deliver);
+    }
+}
@@ -1 +1,11 @@
-pub mod notification_service_implementation;
\ No newline at end of file
+pub mod broadcast_service_implementation;
+pub mod delivery_policy_service_implementation;
+pub mod notification_service_implementation;
+pub mod notification_stream_service_implementation;
+pub mod notification_template_service_implementation;
+pub mod recipient_profile_service_implementation;
+pub mod slug_validator;
+pub mod token_authentication_service_implementation;
+pub mod topic_service_implementation;
+pub mod uuid_parser;
+pub mod webhook_service_implementation;
\ No newline at end of file
@@ -1,67 +1,773 @@
+use crate::adapter::outgoing::persistence::entity::broadcast_entity::BroadcastReceiptEntity;
 use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+use crate::adapter::outgoing::persistence::entity::outbox_event_entity::OutboxEventEntity;
+use crate::application::command::create_notification_command::CreateNotificationCommand;
+use crate::application::command::create_notification_from_template_command::CreateNotificationFromTemplateCommand;
+use crate::application::command::update_notification_command::UpdateNotificationCommand;
+use crate::application::configuration::structs::PreferenceSettings;
 use crate::application::error::ApplicationError;
+use crate::application::service::clock_service::ClockService;
+use crate::application::service::implementation::recipient_profile_service_implementation::load_profile;
+use crate::application::service::implementation::slug_validator::validate_slug;
+use crate::application::service::implementation::uuid_parser::parse_uuid;
 use crate::application::service::notification_service::NotificationServicePort;
+use crate::domain::model::broadcast::{Broadcast, BroadcastReceipt};
+use crate::domain::model::localisation::{locale_candidates, LocalizedContent};
 use crate::domain::model::notification::Notification;
+use crate::domain::model::notification_action::{ActionError, NotificationAction};
+use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+use crate::domain::model::notification_filter::NotificationFilter;
+use crate::domain::model::notification_group::NotificationGroup;
+use crate::domain::model::notification_history::{ChangeSource, NotificationHistoryEntry, NotificationState};
+use crate::domain::model::notification_search::{SearchHit, SearchQuery};
+use crate::domain::model::notification_template::{NotificationTemplate, TemplateFormat};
+use crate::domain::model::preference::DEFAULT_CATEGORY;
+use crate::domain::model::state_change::StateChange;
+use crate::domain::model::tag_count::TagCount;
+use crate::port::outgoing::persistence::broadcast_repository_port::BroadcastRepositoryPort;
 use crate::port::outgoing::persistence::notification_repository_port::NotificationRepositoryPort;
+use crate::port::outgoing::persistence::notification_template_repository_port::NotificationTemplateRepositoryPort;
+use crate::port::outgoing::persistence::recipient_profile_repository_port::RecipientProfileRepositoryPort;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
+const MAX_TAGS: usize = 20;
+
+/// One of several changes made to a notification before they are saved together.
+struct Change {
+    event_type: NotificationEventType,
+    from_state: NotificationState,
+    to_state: NotificationState,
+}
+
+impl Change {
+    fn new(event_type: NotificationEventType, notification: &mut Notification, change: impl FnOnce(&mut Notification)) -> Self {
+        let from_state = NotificationState::from(&*notification);
+        change(notification);
+        Self { event_type, from_state, to_state: NotificationState::from(&*notification) }
+    }
+}
+
+/// Entry of a recipient's inbox: their own notification, or their state of a broadcast.
+enum InboxItem {
+    Personal(Box<Notification>),
+    Broadcast(BroadcastReceipt),
+}
+
 pub struct NotificationServiceImplementation<R: NotificationRepositoryPort + Sync + Send> {
     notification_repository: R,
+    template_repository: Arc<dyn NotificationTemplateRepositoryPort>,
+    profile_repository: Arc<dyn RecipientProfileRepositoryPort>,
+    broadcast_repository: Arc<dyn BroadcastRepositoryPort>,
+    fallback_locales: Vec<String>,
+    preference_settings: PreferenceSettings,
 }
 
 impl<R: NotificationRepositoryPort + Sync + Send> NotificationServiceImplementation<R> {
-    pub fn new(repository: R) -> Self {
-        Self { notification_repository: repository }
+    pub fn new(
+        repository: R,
+        template_repository: Arc<dyn NotificationTemplateRepositoryPort>,
+        profile_repository: Arc<dyn RecipientProfileRepositoryPort>,
+        broadcast_repository: Arc<dyn BroadcastRepositoryPort>,
+        fallback_locales: Vec<String>,
+        preference_settings: PreferenceSettings,
+    ) -> Self {
+        Self {
+            notification_repository: repository,
+            template_repository,
+            profile_repository,
+            broadcast_repository,
+            fallback_locales,
+            preference_settings,
+        }
+    }
+
+    /// Every broadcast as it appears in the recipient's inbox.
+    async fn broadcasts_of(&self, recipient: &str) -> Result<Vec<Notification>, ApplicationError> {
+        let broadcast_entities = self.broadcast_repository.get_all().await?;
+        let receipts: HashMap<Uuid, BroadcastReceipt> = self.broadcast_repository.get_receipts_of(recipient).await?
+            .into_iter()
+            .map(|entity| (entity.broadcast_uuid, entity.into()))
+            .collect();
+
+        Ok(broadcast_entities.into_iter()
+            .map(|entity| {
+                let broadcast: Broadcast = entity.into();
+                broadcast.to_notification(recipient.to_string(), receipts.get(&broadcast.uuid))
+            })
+            .collect())
+    }
+
+    /// Notifications of other recipients are reported as missing rather than forbidden, to not reveal them.
+    async fn find_in_inbox(&self, uuid: &Uuid, recipient: String) -> Result<InboxItem, ApplicationError> {
+        let not_found = || ApplicationError::NotFound(format!("Notification {} not found", uuid));
+        match self.notification_repository.get(uuid).await {
+            Ok(entity) if entity.recipient != recipient => Err(not_found()),
+            Ok(entity) => Ok(InboxItem::Personal(Box::new(entity.into()))),
+            Err(ApplicationError::NotFound(_)) => {
+                match self.broadcast_repository.get(uuid).await {
+                    Ok(_) => {}
+                    Err(ApplicationError::NotFound(_)) => return Err(not_found()),
+                    Err(error) => return Err(error),
+                }
+                match self.broadcast_repository.get_receipt(uuid, &recipient).await {
+                    Ok(entity) => Ok(InboxItem::Broadcast(entity.into())),
+                    Err(ApplicationError::NotFound(_)) => Ok(InboxItem::Broadcast(BroadcastReceipt::new(*uuid, recipient))),
+                    Err(error) => Err(error),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Finds the item like `find_in_inbox`, refusing it unless it is at the `expected_version`, when given.
+    async fn find_expected_in_inbox(
+        &self,
+        uuid: &Uuid,
+        recipient: String,
+        expected_version: Option<u64>,
+    ) -> Result<InboxItem, ApplicationError> {
+        let item = self.find_in_inbox(uuid, recipient).await?;
+        match (&item, expected_version) {
+            (_, None) => Ok(item),
+            (InboxItem::Personal(notification), Some(version)) if notification.version == version => Ok(item),
+            (InboxItem::Personal(_), Some(version)) => Err(ApplicationError::PreconditionFailed(
+                format!("Notification {} is no longer at version {}", uuid, version),
+            )),
+            (InboxItem::Broadcast(_), Some(_)) => Err(ApplicationError::PreconditionFailed(
+                format!("Notification {} is a broadcast, which has no version", uuid),
+            )),
+        }
+    }
+
+    /// Notifications of the recipient's group that are not deleted; a group without any is reported as missing.
+    async fn group_of(&self, recipient: &str, group_key: &str) -> Result<Vec<Notification>, ApplicationError> {
+        let notifications: Vec<Notification> = self.notification_repository.get_all_of(recipient).await?
+            .into_iter()
+            .filter(|entity| !entity.deleted && entity.group_key.as_deref() == Some(group_key))
+            .map(|entity| entity.into())
+            .collect();
+        if notifications.is_empty() {
+            return Err(ApplicationError::NotFound(format!("Group {} not found", group_key)));
+        }
+        Ok(notifications)
+    }
+
+    /// Persists the notification together with its event; the outbox relay publishes the event afterwards.
+    async fn save(&self, event_type: NotificationEventType, notification: Notification) -> Result<NotificationEntity, ApplicationError> {
+        let notification_entity: NotificationEntity = notification.clone().into();
+        let outbox_event: OutboxEventEntity = NotificationEvent::new(event_type, notification).into();
+        self.notification_repository.save(&notification_entity, &outbox_event, None).await
+    }
+
+    /// Persists a change to the notification like `save`, recording it in the notification's history.
+    async fn save_change(
+        &self,
+        event_type: NotificationEventType,
+        from_state: NotificationState,
+        notification: Notification,
+        changed_by: Option<String>,
+        source: ChangeSource,
+    ) -> Result<NotificationEntity, ApplicationError> {
+        let to_state = NotificationState::from(&notification);
+        let history_entry = NotificationHistoryEntry::new(notification.uuid, event_type, changed_by, source, from_state, to_state);
+        let notification_entity: NotificationEntity = notification.clone().into();
+        let outbox_event: OutboxEventEntity = NotificationEvent::new(event_type, notification).into();
+        self.notification_repository.save(&notification_entity, &outbox_event, Some(history_entry.into())).await
+    }
+
+    /// Persists several changes to the notification at once, queuing an outbox event of each change's type and
+    /// recording each in its history, in the order they were made.
+    async fn save_changes(
+        &self,
+        changes: Vec<Change>,
+        notification: Notification,
+        changed_by: Option<String>,
+        source: ChangeSource,
+    ) -> Result<NotificationEntity, ApplicationError> {
+        let notification_entity: NotificationEntity = notification.clone().into();
+        let outbox_events: Vec<OutboxEventEntity> = changes.iter()
+            .map(|change| NotificationEvent::new(change.event_type, notification.clone()).into())
+            .collect();
+        let history_entries = changes.into_iter()
+            .map(|change| NotificationHistoryEntry::new(
+                notification.uuid, change.event_type, changed_by.clone(), source, change.from_state, change.to_state,
+            ).into())
+            .collect();
+        self.notification_repository.save_changes(&notification_entity, &outbox_events, history_entries).await
+    }
+
+    /// Makes the change to the notification and saves it with `save_change`, returning it as saved.
+    async fn apply_change(
+        &self,
+        event_type: NotificationEventType,
+        mut notification: Notification,
+        change: impl FnOnce(&mut Notification) + Send,
+        changed_by: Option<String>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError> {
+        let from_state = NotificationState::from(&notification);
+        change(&mut notification);
+        Ok(self.save_change(event_type, from_state, notification, changed_by, source).await?.into())
     }
+
+    /// The broadcast as the recipient of the receipt sees it.
+    async fn broadcast_state(&self, uuid: &Uuid, receipt: BroadcastReceipt, changed: bool) -> Result<StateChange, ApplicationError> {
+        let broadcast: Broadcast = self.broadcast_repository.get(uuid).await?.into();
+        let notification = broadcast.to_notification(receipt.recipient.clone(), Some(&receipt));
+        Ok(StateChange { notification, changed })
+    }
+
+    /// Persists the recipient's state of a broadcast, recording the change in its history for that recipient.
+    async fn save_receipt_change(
+        &self,
+        event_type: NotificationEventType,
+        from_state: NotificationState,
+        receipt: BroadcastReceipt,
+        source: ChangeSource,
+    ) -> Result<BroadcastReceiptEntity, ApplicationError> {
+        let history_entry = NotificationHistoryEntry::new(
+            receipt.broadcast_uuid,
+            event_type,
+            Some(receipt.recipient.clone()),
+            source,
+            from_state,
+            NotificationState::from(&receipt),
+        );
+        self.broadcast_repository.save_receipt(&receipt.into(), &history_entry.into()).await
+    }
+}
+
+/// Action ids must be unique within the notification, as they address the action taken.
+fn validate_actions(actions: &[NotificationAction]) -> Result<(), ApplicationError> {
+    for (index, action) in actions.iter().enumerate() {
+        validate_slug("action", &action.id)?;
+        if actions[..index].iter().any(|previous| previous.id == action.id) {
+            return Err(ApplicationError::BadRequest(format!("Action {} is defined more than once", action.id)));
+        }
+        if action.label.is_empty() {
+            return Err(ApplicationError::BadRequest(format!("Action {} has no label", action.id)));
+        }
+    }
+    Ok(())
+}
+
+/// Tags are used as filters in urls, like ids.
+fn validate_tags(tags: &[String]) -> Result<(), ApplicationError> {
+    if tags.len() > MAX_TAGS {
+        return Err(ApplicationError::BadRequest(format!("A notification can have at most {} tags", MAX_TAGS)));
+    }
+    for (index, tag) in tags.iter().enumerate() {
+        validate_slug("tag", tag)?;
+        if tags[..index].contains(tag) {
+            return Err(ApplicationError::BadRequest(format!("Tag {} is given more than once", tag)));
+        }
+    }
+    Ok(())
+}
+
+/// Deleted notifications have left the inbox, so there is nothing to hide or bring back.
+fn deleted_snooze_error() -> ApplicationError {
+    ApplicationError::BadRequest("A deleted notification cannot be snoozed".to_string())
+}
+
+/// Preferences are set per category, so notifications may only use the configured ones.
+pub fn resolve_category(preference_settings: &PreferenceSettings, category: Option<String>) -> Result<String, ApplicationError> {
+    let category = category.unwrap_or_else(|| DEFAULT_CATEGORY.to_string());
+    if !preference_settings.categories.contains_key(&category) {
+        return Err(ApplicationError::BadRequest(format!("{} is not a known category", category)));
+    }
+    Ok(category)
 }
 
 #[async_trait]
 impl<R: NotificationRepositoryPort + Sync + Send> NotificationServicePort for NotificationServiceImplementation<R> {
-    async fn create_notification(&self, message: String) -> Result<Notification, ApplicationError> {
-        let notification = Notification::new(message);
-        let notification_entity: NotificationEntity = notification.clone().into();
-        self.notification_repository.save(&notification_entity).await?;
-        Ok(notification)
+    async fn create_notification(&self, command: CreateNotificationCommand) -> Result<Notification, ApplicationError> {
+        let category = resolve_category(&self.preference_settings, command.category)?;
+        validate_actions(&command.actions)?;
+        validate_tags(&command.tags)?;
+        let notification = Notification {
+            category,
+            priority: command.priority,
+            group_key: command.group_key,
+            actor: command.actor,
+            actions: command.actions,
+            auto_resolve: command.auto_resolve,
+            tags: command.tags,
+            ..Notification::new(command.recipient, command.message)
+        };
+        Ok(self.save(NotificationEventType::Created, notification).await?.into())
+    }
+
+    /// Notifications are rendered as HTML for the email channel and as text otherwise.
+    async fn create_notification_from_template(
+        &self,
+        command: CreateNotificationFromTemplateCommand,
+    ) -> Result<Notification, ApplicationError> {
+        let category = resolve_category(&self.preference_settings, command.category)?;
+        validate_actions(&command.actions)?;
+        validate_tags(&command.tags)?;
+        let template: NotificationTemplate = self.template_repository.get(&command.template_id).await?.into();
+        let format = TemplateFormat::for_channel(command.channel);
+        let rendered = template.render(&command.variables, format)?;
+        let localizations = template.render_variants(&command.variables, format)?
+            .into_iter()
+            .map(|(locale, rendered)| LocalizedContent { locale, title: Some(rendered.title), message: rendered.body })
+            .collect();
+
+        let notification = Notification {
+            category,
+            priority: command.priority,
+            group_key: command.group_key,
+            actor: command.actor,
+            actions: command.actions,
+            auto_resolve: command.auto_resolve,
+            tags: command.tags,
+            title: Some(rendered.title),
+            locale: Some(template.locale),
+            localizations,
+            ..Notification::new(command.recipient, rendered.body)
+        };
+        Ok(self.save(NotificationEventType::Created, notification).await?.into())
     }
 
-    async fn get_notification(&self, uuid: String) -> Result<Notification, ApplicationError> {
-        let parsed_uuid = Uuid::parse_str(uuid.as_str()).unwrap();
-        let notification_entity = self.notification_repository.get(&parsed_uuid).await?;
-        Ok(notification_entity.into())
+    async fn get_notification_of(&self, uuid: String, recipient: String) -> Result<Notification, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        match self.find_in_inbox(&parsed_uuid, recipient).await? {
+            InboxItem::Personal(notification) => Ok(*notification),
+            InboxItem::Broadcast(receipt) => Ok(self.broadcast_state(&parsed_uuid, receipt, false).await?.notification),
+        }
     }
 
-    async fn list_notifications(&self) -> Result<Vec<Notification>, ApplicationError> {
-        let notification_entities = self.notification_repository.get_all().await?;
-        let notifications = notification_entities.into_iter().map(|entity| entity.into()).collect();
+    async fn list_notifications(
+        &self,
+        recipient: String,
+        filter: NotificationFilter,
+        accepted_locales: Vec<String>,
+    ) -> Result<Vec<Notification>, ApplicationError> {
+        let now = ClockService::now();
+        let mut notifications: Vec<Notification> = match &filter.tag {
+            Some(tag) => self.notification_repository.get_tagged_of(&recipient, tag).await?,
+            None => self.notification_repository.get_all_of(&recipient).await?,
+        }.into_iter()
+            .map(Notification::from)
+            .filter(|notification| {
+                (filter.updated_since.is_some() || !notification.is_snoozed(now)) && filter.matches(notification)
+            })
+            .collect();
+
+        if notifications.iter().any(|notification| !notification.localizations.is_empty()) {
+            let profile = load_profile(self.profile_repository.as_ref(), &recipient).await?;
+            let preferred: Vec<String> = profile.locale.into_iter().chain(accepted_locales).collect();
+            let candidates = locale_candidates(&preferred, &self.fallback_locales);
+            for notification in notifications.iter_mut().filter(|notification| !notification.localizations.is_empty()) {
+                notification.localize(&candidates);
+            }
+        }
+
+        let broadcasts = self.broadcasts_of(&recipient).await?;
+        notifications.extend(broadcasts.into_iter().filter(|broadcast| filter.matches(broadcast)));
+        notifications.sort_by_key(|notification| notification.date_created);
+        notifications.sort_by_key(|notification| !notification.pinned);
         Ok(notifications)
     }
 
-    async fn mark_as_seen(&self, uuid: String) -> Result<bool, ApplicationError> {
-        let parsed_uuid = Uuid::parse_str(uuid.as_str()).unwrap();
-        let notification_entity = self.notification_repository.get(&parsed_uuid).await?;
-        let mut notification: Notification = notification_entity.into();
-        notification.set_as_seen();
-        let updated_entity: NotificationEntity = notification.into();
-        Ok(self.notification_repository.save(&updated_entity).await?.seen)
+    async fn list_notification_groups(
+        &self,
+        recipient: String,
+        accepted_locales: Vec<String>,
+    ) -> Result<Vec<NotificationGroup>, ApplicationError> {
+        let notifications = self.list_notifications(recipient, NotificationFilter::default(), accepted_locales).await?
+            .into_iter()
+            .filter(|notification| !notification.deleted)
+            .collect();
+        Ok(NotificationGroup::group(notifications))
     }
 
-    async fn mark_as_deleted(&self, uuid: String) -> Result<bool, ApplicationError> {
-        let parsed_uuid = Uuid::parse_str(uuid.as_str()).unwrap();
-        let notification_entity = self.notification_repository.get(&parsed_uuid).await?;
-        let mut notification: Notification = notification_entity.into();
-        notification.set_as_deleted();
-        let updated_entity: NotificationEntity = notification.into();
-        Ok(self.notification_repository.save(&updated_entity).await?.deleted)
+    async fn search_notifications(
+        &self,
+        recipient: String,
+        query: String,
+        filter: NotificationFilter,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>, ApplicationError> {
+        if SearchQuery::new(&query).is_empty() {
+            return Err(ApplicationError::BadRequest("The search query must hold at least one word".to_string()));
+        }
+        let now = ClockService::now();
+        Ok(self.notification_repository.search_of(&recipient, &query).await?
+            .into_iter()
+            .map(SearchHit::from)
+            .filter(|hit| {
+                let notification = &hit.notification;
+                !notification.deleted && !notification.is_snoozed(now) && filter.matches(notification)
+            })
+            .take(limit)
+            .collect())
+    }
+
+    async fn count_tags(&self, recipient: String) -> Result<Vec<TagCount>, ApplicationError> {
+        Ok(self.notification_repository.count_tags_of(&recipient).await?
+            .into_iter()
+            .map(|(tag, count)| TagCount { tag, count })
+            .collect())
+    }
+
+    async fn count_unread(&self, recipient: String) -> Result<usize, ApplicationError> {
+        let now = ClockService::now();
+        let notifications: Vec<Notification> = self.notification_repository.get_all_of(&recipient).await?
+            .into_iter()
+            .map(Notification::from)
+            .filter(|notification| !notification.is_snoozed(now) && !notification.archived && !notification.deleted)
+            .collect();
+        let broadcasts = self.broadcasts_of(&recipient).await?;
+        Ok(notifications.iter().filter(|notification| !notification.seen).count()
+            + broadcasts.iter().filter(|broadcast| !broadcast.seen).count())
+    }
+
+    async fn mark_as_seen(
+        &self,
+        uuid: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<StateChange, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        let changed_by = Some(recipient.clone());
+        match self.find_expected_in_inbox(&parsed_uuid, recipient, expected_version).await? {
+            InboxItem::Personal(notification) if notification.seen => {
+                Ok(StateChange { notification: *notification, changed: false })
+            }
+            InboxItem::Personal(notification) => {
+                let notification = self
+                    .apply_change(NotificationEventType::Seen, *notification, Notification::set_as_seen, changed_by, source)
+                    .await?;
+                Ok(StateChange { notification, changed: true })
+            }
+            InboxItem::Broadcast(receipt) if receipt.seen => self.broadcast_state(&parsed_uuid, receipt, false).await,
+            InboxItem::Broadcast(mut receipt) => {
+                let from_state = NotificationState::from(&receipt);
+                receipt.set_as_seen();
+                let saved_entity = self.save_receipt_change(NotificationEventType::Seen, from_state, receipt, source).await?;
+                self.broadcast_state(&parsed_uuid, saved_entity.into(), true).await
+            }
+        }
+    }
+
+    async fn mark_as_deleted(
+        &self,
+        uuid: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<StateChange, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        let changed_by = Some(recipient.clone());
+        match self.find_expected_in_inbox(&parsed_uuid, recipient, expected_version).await? {
+            InboxItem::Personal(notification) if notification.deleted => {
+                Ok(StateChange { notification: *notification, changed: false })
+            }
+            InboxItem::Personal(notification) => {
+                let notification = self
+                    .apply_change(NotificationEventType::Deleted, *notification, Notification::set_as_deleted, changed_by, source)
+                    .await?;
+                Ok(StateChange { notification, changed: true })
+            }
+            InboxItem::Broadcast(receipt) if receipt.deleted => self.broadcast_state(&parsed_uuid, receipt, false).await,
+            InboxItem::Broadcast(mut receipt) => {
+                let from_state = NotificationState::from(&receipt);
+                receipt.set_as_deleted();
+                let saved_entity = self.save_receipt_change(NotificationEventType::Deleted, from_state, receipt, source).await?;
+                self.broadcast_state(&parsed_uuid, saved_entity.into(), true).await
+            }
+        }
+    }
+
+    async fn take_action(
+        &self,
+        uuid: String,
+        action_id: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        let changed_by = Some(recipient.clone());
+        match self.find_expected_in_inbox(&parsed_uuid, recipient, expected_version).await? {
+            InboxItem::Personal(mut notification) => {
+                let from_state = NotificationState::from(notification.as_ref());
+                notification.take_action(&action_id)?;
+                Ok(self.save_change(NotificationEventType::ActionTaken, from_state, *notification, changed_by, source).await?.into())
+            }
+            InboxItem::Broadcast(_) => Err(ActionError::UnknownAction(action_id).into()),
+        }
+    }
+
+    async fn snooze(
+        &self,
+        uuid: String,
+        until: DateTime<Utc>,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        if until <= ClockService::now() {
+            return Err(ApplicationError::BadRequest("A notification can only be snoozed until a later time".to_string()));
+        }
+        let changed_by = Some(recipient.clone());
+        match self.find_expected_in_inbox(&parsed_uuid, recipient, expected_version).await? {
+            InboxItem::Personal(notification) if notification.deleted => Err(deleted_snooze_error()),
+            InboxItem::Personal(mut notification) => {
+                let from_state = NotificationState::from(notification.as_ref());
+                notification.snooze(until);
+                Ok(self.save_change(NotificationEventType::Snoozed, from_state, *notification, changed_by, source).await?.into())
+            }
+            InboxItem::Broadcast(_) => Err(ApplicationError::BadRequest("Broadcasts cannot be snoozed".to_string())),
+        }
+    }
+
+    async fn set_pinned(
+        &self,
+        uuid: String,
+        pinned: bool,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        let changed_by = Some(recipient.clone());
+        match self.find_expected_in_inbox(&parsed_uuid, recipient, expected_version).await? {
+            InboxItem::Personal(mut notification) => {
+                let from_state = NotificationState::from(notification.as_ref());
+                notification.set_pinned(pinned);
+                let event_type = if pinned { NotificationEventType::Pinned } else { NotificationEventType::Unpinned };
+                Ok(self.save_change(event_type, from_state, *notification, changed_by, source).await?.into())
+            }
+            InboxItem::Broadcast(_) => Err(ApplicationError::BadRequest("Broadcasts cannot be pinned".to_string())),
+        }
+    }
+
+    async fn set_archived(
+        &self,
+        uuid: String,
+        archived: bool,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        let changed_by = Some(recipient.clone());
+        match self.find_expected_in_inbox(&parsed_uuid, recipient, expected_version).await? {
+            InboxItem::Personal(mut notification) => {
+                let from_state = NotificationState::from(notification.as_ref());
+                notification.set_archived(archived);
+                let event_type = if archived { NotificationEventType::Archived } else { NotificationEventType::Unarchived };
+                Ok(self.save_change(event_type, from_state, *notification, changed_by, source).await?.into())
+            }
+            InboxItem::Broadcast(_) => Err(ApplicationError::BadRequest("Broadcasts cannot be archived".to_string())),
+        }
+    }
+
+    async fn add_tag(
+        &self,
+        uuid: String,
+        tag: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        validate_slug("tag", &tag)?;
+        let changed_by = Some(recipient.clone());
+        match self.find_expected_in_inbox(&parsed_uuid, recipient, expected_version).await? {
+            InboxItem::Personal(mut notification) => {
+                let mut tags = notification.tags.clone();
+                if !tags.contains(&tag) {
+                    tags.push(tag.clone());
+                    validate_tags(&tags)?;
+                }
+                let from_state = NotificationState::from(notification.as_ref());
+                if !notification.add_tag(tag) {
+                    return Ok(*notification);
+                }
+                Ok(self.save_change(NotificationEventType::Tagged, from_state, *notification, changed_by, source).await?.into())
+            }
+            InboxItem::Broadcast(_) => Err(ApplicationError::BadRequest("Broadcasts cannot be tagged".to_string())),
+        }
+    }
+
+    async fn remove_tag(
+        &self,
+        uuid: String,
+        tag: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        let changed_by = Some(recipient.clone());
+        match self.find_expected_in_inbox(&parsed_uuid, recipient, expected_version).await? {
+            InboxItem::Personal(mut notification) => {
+                let from_state = NotificationState::from(notification.as_ref());
+                if !notification.remove_tag(&tag) {
+                    return Ok(*notification);
+                }
+                Ok(self.save_change(NotificationEventType::Untagged, from_state, *notification, changed_by, source).await?.into())
+            }
+            InboxItem::Broadcast(_) => Err(ApplicationError::BadRequest("Broadcasts cannot be tagged".to_string())),
+        }
+    }
+
+    /// Every change is made before the notification is saved once, so the changes are applied together or not at all.
+    async fn update_notification(
+        &self,
+        uuid: String,
+        command: UpdateNotificationCommand,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        if command.is_empty() {
+            return Err(ApplicationError::BadRequest("The update must change at least one field".to_string()));
+        }
+        if command.seen == Some(false) {
+            return Err(ApplicationError::BadRequest("A notification cannot be marked as unseen".to_string()));
+        }
+        if command.snoozed_until.is_some_and(|until| until <= ClockService::now()) {
+            return Err(ApplicationError::BadRequest(
+                "A notification can only be snoozed until a later time".to_string(),
+            ));
+        }
+        let changed_by = Some(recipient.clone());
+        match self.find_expected_in_inbox(&parsed_uuid, recipient, expected_version).await? {
+            InboxItem::Personal(notification) => {
+                let mut notification = *notification;
+                let mut changes = Vec::new();
+                if command.seen == Some(true) && !notification.seen {
+                    changes.push(Change::new(NotificationEventType::Seen, &mut notification, Notification::set_as_seen));
+                }
+                if let Some(pinned) = command.pinned.filter(|pinned| *pinned != notification.pinned) {
+                    let event_type = if pinned { NotificationEventType::Pinned } else { NotificationEventType::Unpinned };
+                    changes.push(Change::new(event_type, &mut notification, |notification| notification.set_pinned(pinned)));
+                }
+                if let Some(archived) = command.archived.filter(|archived| *archived != notification.archived) {
+                    let event_type = if archived { NotificationEventType::Archived } else { NotificationEventType::Unarchived };
+                    changes.push(Change::new(event_type, &mut notification, |notification| notification.set_archived(archived)));
+                }
+                if let Some(until) = command.snoozed_until {
+                    if notification.deleted {
+                        return Err(deleted_snooze_error());
+                    }
+                    changes.push(Change::new(NotificationEventType::Snoozed, &mut notification, |notification| notification.snooze(until)));
+                }
+                if changes.is_empty() {
+                    return Ok(notification);
+                }
+                Ok(self.save_changes(changes, notification, changed_by, source).await?.into())
+            }
+            InboxItem::Broadcast(mut receipt) => {
+                if command.pinned.is_some() || command.archived.is_some() || command.snoozed_until.is_some() {
+                    return Err(ApplicationError::BadRequest("Broadcasts can only be marked as seen".to_string()));
+                }
+                if !receipt.seen {
+                    let from_state = NotificationState::from(&receipt);
+                    receipt.set_as_seen();
+                    let saved = self.save_receipt_change(NotificationEventType::Seen, from_state, receipt, source).await?;
+                    receipt = saved.into();
+                }
+                Ok(self.broadcast_state(&parsed_uuid, receipt, false).await?.notification)
+            }
+        }
+    }
+
+    async fn resurface_snoozed(&self, until: DateTime<Utc>, limit: usize) -> Result<usize, ApplicationError> {
+        let snoozed = self.notification_repository.get_snoozed_until(until, limit).await?;
+        let resurfaced = snoozed.len();
+        for entity in snoozed {
+            let mut notification: Notification = entity.into();
+            let from_state = NotificationState::from(&notification);
+            notification.resurface();
+            self.save_change(NotificationEventType::Resurfaced, from_state, notification, None, ChangeSource::System).await?;
+        }
+        Ok(resurfaced)
+    }
+
+    async fn mark_group_as_seen(
+        &self,
+        recipient: String,
+        group_key: String,
+        source: ChangeSource,
+    ) -> Result<usize, ApplicationError> {
+        let unseen: Vec<Notification> = self.group_of(&recipient, &group_key).await?
+            .into_iter()
+            .filter(|notification| !notification.seen)
+            .collect();
+        let marked = unseen.len();
+        for mut notification in unseen {
+            let from_state = NotificationState::from(&notification);
+            notification.set_as_seen();
+            self.save_change(NotificationEventType::Seen, from_state, notification, Some(recipient.clone()), source).await?;
+        }
+        Ok(marked)
+    }
+
+    async fn mark_group_as_deleted(
+        &self,
+        recipient: String,
+        group_key: String,
+        source: ChangeSource,
+    ) -> Result<usize, ApplicationError> {
+        let notifications = self.group_of(&recipient, &group_key).await?;
+        let marked = notifications.len();
+        for mut notification in notifications {
+            let from_state = NotificationState::from(&notification);
+            notification.set_as_deleted();
+            self.save_change(NotificationEventType::Deleted, from_state, notification, Some(recipient.clone()), source).await?;
+        }
+        Ok(marked)
+    }
+
+    async fn get_history(
+        &self,
+        uuid: String,
+        recipient: String,
+    ) -> Result<Vec<NotificationHistoryEntry>, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        let history = match self.find_in_inbox(&parsed_uuid, recipient).await? {
+            InboxItem::Personal(_) => self.notification_repository.get_history_of(&parsed_uuid).await?,
+            InboxItem::Broadcast(receipt) => {
+                self.broadcast_repository.get_receipt_history(&parsed_uuid, &receipt.recipient).await?
+            }
+        };
+        Ok(history.into_iter().map(NotificationHistoryEntry::from).collect())
     }
 }
 
 //TODO pending to add error cases
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::clone_on_copy)]
 mod tests {
     use super::*;
+    use crate::application::configuration::structs::CategoryDefaults;
+    use crate::domain::model::preference::Channel;
+    use crate::domain::model::priority::Priority;
+    use crate::domain::model::recipient_profile::RecipientProfile;
+    use crate::adapter::outgoing::persistence::entity::broadcast_entity::BroadcastEntity;
+    use crate::adapter::outgoing::persistence::entity::notification_search_hit_entity::NotificationSearchHitEntity;
+    use crate::domain::model::notification::NotificationKind;
+    use crate::domain::model::notification_action::ActionStyle;
+    use crate::port::outgoing::persistence::broadcast_repository_port::MockBroadcastRepositoryPort;
     use crate::port::outgoing::persistence::notification_repository_port::MockNotificationRepositoryPort;
+    use crate::port::outgoing::persistence::notification_template_repository_port::MockNotificationTemplateRepositoryPort;
+    use crate::port::outgoing::persistence::recipient_profile_repository_port::MockRecipientProfileRepositoryPort;
+    use mockall::predicate::eq;
     use tokio;
+    use uuid::Uuid;
 
     #[tokio::test]
     async fn test_create_notification_success() {
@@ -71,14 +777,16 @@ mod tests {
         let mut mocked_repo = MockNotificationRepositoryPort::new();
         mocked_repo.expect_save()
             .times(1)
-            .returning(|notification_entity| Ok(notification_entity.clone()));
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
 
-        let notification_service = NotificationServiceImplementation::new(mocked_repo);
+        let notification_service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
 
-        let result = notification_service.create_notification(message.clone()).await;
+        let command = CreateNotificationCommand { recipient: "recipient".to_string(), message: message.clone(), category: None, priority: Priority::Normal, group_key: None, actor: None, actions: vec![], auto_resolve: false, tags: vec![] };
+        let result = notification_service.create_notification(command).await;
 
         let result = result.unwrap();
 
+        assert_eq!(result.recipient, "recipient");
         assert_eq!(result.message, message);
         assert_eq!(result.seen, false);
         assert_eq!(result.deleted, false);
@@ -98,9 +806,9 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(notification.clone().into()));
 
-        let notification_service = NotificationServiceImplementation::new(mocked_repo);
+        let notification_service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
 
-        let result = notification_service.get_notification(uuid_to_look_for_as_string.clone()).await;
+        let result = notification_service.get_notification_of(uuid_to_look_for_as_string.clone(), "recipient".to_string()).await;
 
         let result = result.unwrap();
 
@@ -110,12 +818,30 @@ mod tests {
         assert_eq!(result.uuid, uuid_to_look_for);
     }
 
+    #[tokio::test]
+    async fn test_get_notification_of_other_recipient_not_found() {
+        let notification_entity: NotificationEntity = generate_random_notification().into();
+        let uuid = notification_entity.uuid.to_string();
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .returning(move |_| Ok(notification_entity.clone()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let own = service.get_notification_of(uuid.clone(), "recipient".to_string()).await;
+        let other = service.get_notification_of(uuid, "someone else".to_string()).await;
+
+        assert_eq!(own.unwrap().recipient, "recipient");
+        assert!(matches!(other, Err(ApplicationError::NotFound(_))));
+    }
+
     #[tokio::test]
     async fn test_list_notifications_success() {
         let mut mocked_repo = MockNotificationRepositoryPort::new();
-        mocked_repo.expect_get_all()
+        mocked_repo.expect_get_all_of()
             .times(1)
-            .returning(|| {
+            .returning(|_| {
                 let mut notifications: Vec<NotificationEntity> = Vec::new();
                 for _ in 0..10 {
                     let notification_entity: NotificationEntity = generate_random_notification().into();
@@ -124,23 +850,70 @@ mod tests {
                 Ok(notifications)
             });
 
-        let notification_service = NotificationServiceImplementation::new(mocked_repo);
+        let notification_service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), no_broadcasts(), vec![], preference_settings());
 
-        let result = notification_service.list_notifications().await.unwrap();
+        let result = notification_service.list_notifications("recipient".to_string(), NotificationFilter::default(), vec![]).await.unwrap();
 
         assert_eq!(result.len(), 10);
     }
 
+    #[tokio::test]
+    async fn test_list_notifications_localizes_for_recipient() {
+        let localized = |recipient: &str| -> NotificationEntity {
+            Notification {
+                locale: Some("en".to_string()),
+                localizations: vec![
+                    LocalizedContent { locale: "pt".to_string(), title: None, message: "mensagem".to_string() },
+                    LocalizedContent { locale: "es".to_string(), title: None, message: "mensaje".to_string() },
+                ],
+                ..Notification::new(recipient.to_string(), "message".to_string())
+            }.into()
+        };
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .returning(move |recipient| Ok(vec![localized(recipient)]));
+
+        let mut profile_repository = MockRecipientProfileRepositoryPort::new();
+        profile_repository.expect_get()
+            .times(3)
+            .returning(|recipient| {
+                let mut profile = RecipientProfile::new(recipient.to_string());
+                match recipient {
+                    "ana" => profile.set_locale(Some("pt-BR".to_string())),
+                    "eve" => profile.set_locale(Some("fr".to_string())),
+                    _ => return Err(ApplicationError::NotFound(recipient.to_string())),
+                }
+                Ok(profile.into())
+            });
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), Arc::new(profile_repository), no_broadcasts(), vec!["es".to_string()], preference_settings(),
+        );
+
+        let mut messages: Vec<(String, Option<String>)> = Vec::new();
+        for recipient in ["ana", "bob", "eve"] {
+            let result = service.list_notifications(recipient.to_string(), NotificationFilter::default(), vec!["de".to_string()]).await.unwrap();
+            messages.extend(result.into_iter().map(|notification| (notification.message, notification.locale)));
+        }
+
+        assert_eq!(messages, vec![
+            ("mensagem".to_string(), Some("pt".to_string())),
+            ("mensaje".to_string(), Some("es".to_string())),
+            ("mensaje".to_string(), Some("es".to_string())),
+        ]);
+    }
+
     #[tokio::test]
     async fn test_list_notifications_with_empty_result_success() {
         let mut mocked_repo = MockNotificationRepositoryPort::new();
-        mocked_repo.expect_get_all()
+        mocked_repo.expect_get_all_of()
             .times(1)
-            .returning(|| Ok(vec![]));
+            .returning(|_| Ok(vec![]));
 
-        let notification_service = NotificationServiceImplementation::new(mocked_repo);
+        let notification_service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), no_broadcasts(), vec![], preference_settings());
 
-        let result = notification_service.list_notifications().await.unwrap();
+        let result = notification_service.list_notifications("recipient".to_string(), NotificationFilter::default(), vec![]).await.unwrap();
 
         assert_eq!(result.len(), 0);
     }
@@ -165,15 +938,15 @@ mod tests {
         mock_repo
             .expect_save()
             .times(1)
-            .returning(move |_| Ok(updated_entity.clone()));
+            .returning(move |_, _, _| Ok(updated_entity.clone()));
 
-        let service = NotificationServiceImplementation::new(mock_repo);
+        let service = NotificationServiceImplementation::new(mock_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
 
         let uuid_to_search_for = notification.uuid.to_string();
-        let result = service.mark_as_seen(uuid_to_search_for).await;
+        let result = service.mark_as_seen(uuid_to_search_for, "recipient".to_string(), None, ChangeSource::Rest).await.unwrap();
 
-        assert!(result.is_ok());
-        assert!(result.unwrap());
+        assert!(result.changed);
+        assert!(result.notification.seen);
     }
 
     #[tokio::test]
@@ -197,20 +970,1081 @@ mod tests {
         mock_repo
             .expect_save()
             .times(1)
-            .returning(move |_| Ok(updated_entity.clone()));
+            .returning(move |_, _, _| Ok(updated_entity.clone()));
 
-        let service = NotificationServiceImplementation::new(mock_repo);
+        let service = NotificationServiceImplementation::new(mock_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
 
         let uuid_to_search_for = notification.uuid.to_string();
-        let result = service.mark_as_deleted(uuid_to_search_for).await;
+        let result = service.mark_as_deleted(uuid_to_search_for, "recipient".to_string(), None, ChangeSource::Rest).await.unwrap();
+
+        assert!(result.changed);
+        assert!(result.notification.deleted);
+    }
+
+    #[tokio::test]
+    async fn test_mark_as_deleted_again_unchanged() {
+        let mut notification = generate_random_notification();
+        notification.set_as_deleted();
+        let notification_entity: NotificationEntity = notification.into();
+        let uuid_to_search_for = notification_entity.uuid.to_string();
+
+        let mut mock_repo = MockNotificationRepositoryPort::new();
+        mock_repo.expect_get()
+            .returning(move |_| Ok(notification_entity.clone()));
+        mock_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(mock_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.mark_as_deleted(uuid_to_search_for, "recipient".to_string(), None, ChangeSource::Rest).await.unwrap();
+
+        assert!(!result.changed);
+        assert!(result.notification.deleted);
+    }
+
+    #[tokio::test]
+    async fn test_mark_as_seen_writes_outbox_event() {
+        let notification_entity: NotificationEntity = generate_random_notification().into();
+        let uuid_to_search_for = notification_entity.uuid.to_string();
+
+        let mut mock_repo = MockNotificationRepositoryPort::new();
+        mock_repo.expect_get()
+            .returning(move |_| Ok(notification_entity.clone()));
+        mock_repo.expect_save()
+            .withf(|notification_entity, outbox_event, _| {
+                outbox_event.event_type == NotificationEventType::Seen
+                    && outbox_event.notification == *notification_entity
+                    && outbox_event.published_at.is_none()
+                    && notification_entity.seen
+            })
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let service = NotificationServiceImplementation::new(mock_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        assert!(service.mark_as_seen(uuid_to_search_for, "recipient".to_string(), None, ChangeSource::Rest).await.unwrap().changed);
+    }
+
+    #[tokio::test]
+    async fn test_mark_as_seen_records_history_entry() {
+        let notification_entity: NotificationEntity = generate_random_notification().into();
+        let uuid = notification_entity.uuid;
+
+        let mut mock_repo = MockNotificationRepositoryPort::new();
+        mock_repo.expect_get()
+            .returning(move |_| Ok(notification_entity.clone()));
+        mock_repo.expect_save()
+            .withf(move |_, _, history_entry| {
+                history_entry.as_ref().is_some_and(|entry| {
+                    entry.notification_uuid == uuid
+                        && entry.event_type == NotificationEventType::Seen
+                        && entry.changed_by == Some("recipient".to_string())
+                        && entry.source == ChangeSource::WebSocket
+                        && !entry.from_state.seen
+                        && entry.to_state.seen
+                })
+            })
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let service = NotificationServiceImplementation::new(mock_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.mark_as_seen(uuid.to_string(), "recipient".to_string(), None, ChangeSource::WebSocket).await;
+
+        assert!(result.unwrap().changed);
+    }
+
+    #[tokio::test]
+    async fn test_get_history_of_broadcast_reads_receipt_history() {
+        let broadcast: BroadcastEntity = Broadcast::new(None, "message".to_string()).into();
+        let uuid = broadcast.uuid;
+        let receipt = BroadcastReceipt::new(uuid, "recipient".to_string());
+        let mut deleted = receipt.clone();
+        deleted.set_as_deleted();
+        let entry = NotificationHistoryEntry::new(
+            uuid, NotificationEventType::Deleted, Some("recipient".to_string()), ChangeSource::Rest,
+            NotificationState::from(&receipt), NotificationState::from(&deleted),
+        );
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .returning(|uuid| Err(ApplicationError::NotFound(uuid.to_string())));
+        mocked_repo.expect_get_history_of().never();
+
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get()
+            .returning(move |_| Ok(broadcast.clone()));
+        broadcast_repository.expect_get_receipt()
+            .returning(move |_, _| Ok(deleted.clone().into()));
+        broadcast_repository.expect_get_receipt_history()
+            .withf(move |broadcast_uuid, recipient| *broadcast_uuid == uuid && recipient == "recipient")
+            .times(1)
+            .returning(move |_, _| Ok(vec![entry.clone().into()]));
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), Arc::new(broadcast_repository), vec![], preference_settings(),
+        );
+
+        let history = service.get_history(uuid.to_string(), "recipient".to_string()).await.unwrap();
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].event_type, NotificationEventType::Deleted);
+        assert!(history[0].to_state.deleted);
+    }
+
+    #[tokio::test]
+    async fn test_mark_as_seen_outdated_version_precondition_failed() {
+        let notification_entity = NotificationEntity { version: 2, ..generate_random_notification().into() };
+        let uuid = notification_entity.uuid.to_string();
+
+        let mut mock_repo = MockNotificationRepositoryPort::new();
+        mock_repo.expect_get()
+            .returning(move |_| Ok(notification_entity.clone()));
+        mock_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(mock_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.mark_as_seen(uuid, "recipient".to_string(), Some(1), ChangeSource::Rest).await;
+
+        assert!(matches!(result, Err(ApplicationError::PreconditionFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_set_pinned_returns_saved_version() {
+        let notification_entity = NotificationEntity { version: 2, ..generate_random_notification().into() };
+        let uuid = notification_entity.uuid.to_string();
+
+        let mut mock_repo = MockNotificationRepositoryPort::new();
+        mock_repo.expect_get()
+            .returning(move |_| Ok(notification_entity.clone()));
+        mock_repo.expect_save()
+            .withf(|notification_entity, _, _| notification_entity.version == 2)
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(NotificationEntity { version: 3, ..notification_entity.clone() }));
+
+        let service = NotificationServiceImplementation::new(mock_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.set_pinned(uuid, true, "recipient".to_string(), Some(2), ChangeSource::Rest).await.unwrap();
+
+        assert!(result.pinned);
+        assert_eq!(result.version, 3);
+    }
+
+    #[tokio::test]
+    async fn test_mark_broadcast_as_seen_with_version_precondition_failed() {
+        let broadcast: BroadcastEntity = Broadcast::new(None, "message".to_string()).into();
+        let uuid = broadcast.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .returning(|uuid| Err(ApplicationError::NotFound(uuid.to_string())));
+
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get()
+            .returning(move |_| Ok(broadcast.clone()));
+        broadcast_repository.expect_get_receipt()
+            .returning(|uuid, recipient| Err(ApplicationError::NotFound(format!("{} {}", uuid, recipient))));
+        broadcast_repository.expect_save_receipt().never();
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), Arc::new(broadcast_repository), vec![], preference_settings(),
+        );
+
+        let result = service.mark_as_seen(uuid.to_string(), "recipient".to_string(), Some(0), ChangeSource::Rest).await;
+
+        assert!(matches!(result, Err(ApplicationError::PreconditionFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_notification_saves_every_change_at_once() {
+        let notification_entity: NotificationEntity = generate_random_notification().into();
+        let uuid = notification_entity.uuid.to_string();
+
+        let mut mock_repo = MockNotificationRepositoryPort::new();
+        mock_repo.expect_get()
+            .returning(move |_| Ok(notification_entity.clone()));
+        mock_repo.expect_save().never();
+        mock_repo.expect_save_changes()
+            .withf(|notification_entity, outbox_events, history_entries| {
+                outbox_events.iter().map(|event| event.event_type).collect::<Vec<NotificationEventType>>()
+                    == vec![NotificationEventType::Seen, NotificationEventType::Pinned]
+                    && history_entries.iter().map(|entry| entry.event_type).collect::<Vec<NotificationEventType>>()
+                    == vec![NotificationEventType::Seen, NotificationEventType::Pinned]
+                    && history_entries[0].to_state == history_entries[1].from_state
+                    && notification_entity.version == 0
+                    && notification_entity.seen
+                    && notification_entity.pinned
+            })
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(NotificationEntity { version: 1, ..notification_entity.clone() }));
+
+        let service = NotificationServiceImplementation::new(mock_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let command = UpdateNotificationCommand { seen: Some(true), pinned: Some(true), archived: Some(false), snoozed_until: None };
+        let result = service.update_notification(uuid, command, "recipient".to_string(), Some(0), ChangeSource::Rest).await.unwrap();
+
+        assert!(result.seen && result.pinned && !result.archived);
+        assert_eq!(result.version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_notification_without_changes_error() {
+        let mut mock_repo = MockNotificationRepositoryPort::new();
+        mock_repo.expect_get().never();
+
+        let service = NotificationServiceImplementation::new(mock_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let empty = service.update_notification(Uuid::new_v4().to_string(), UpdateNotificationCommand::default(), "recipient".to_string(), None, ChangeSource::Rest).await;
+        let unseen = UpdateNotificationCommand { seen: Some(false), ..UpdateNotificationCommand::default() };
+        let unseen = service.update_notification(Uuid::new_v4().to_string(), unseen, "recipient".to_string(), None, ChangeSource::Rest).await;
+
+        assert!(matches!(empty, Err(ApplicationError::BadRequest(_))));
+        assert!(matches!(unseen, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_broadcast_only_marks_as_seen() {
+        let broadcast: BroadcastEntity = Broadcast::new(None, "message".to_string()).into();
+        let uuid = broadcast.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .returning(|uuid| Err(ApplicationError::NotFound(uuid.to_string())));
+
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get()
+            .returning(move |_| Ok(broadcast.clone()));
+        broadcast_repository.expect_get_receipt()
+            .returning(|uuid, recipient| Err(ApplicationError::NotFound(format!("{} {}", uuid, recipient))));
+        broadcast_repository.expect_save_receipt()
+            .times(1)
+            .returning(|receipt, _| Ok(receipt.clone()));
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), Arc::new(broadcast_repository), vec![], preference_settings(),
+        );
+
+        let recipient = "recipient".to_string();
+        let pin = UpdateNotificationCommand { pinned: Some(true), ..UpdateNotificationCommand::default() };
+        let pinned = service.update_notification(uuid.to_string(), pin, recipient.clone(), None, ChangeSource::Rest).await;
+        let see = UpdateNotificationCommand { seen: Some(true), ..UpdateNotificationCommand::default() };
+        let seen = service.update_notification(uuid.to_string(), see, recipient, None, ChangeSource::Rest).await.unwrap();
+
+        assert!(matches!(pinned, Err(ApplicationError::BadRequest(_))));
+        assert_eq!(seen.kind, NotificationKind::Broadcast);
+        assert!(seen.seen);
+    }
+
+    #[tokio::test]
+    async fn test_list_notifications_of_recipient_mixes_broadcasts() {
+        let mut older: NotificationEntity = generate_random_notification().into();
+        older.date_created -= chrono::Duration::minutes(2);
+        let newer: NotificationEntity = generate_random_notification().into();
+        let mut seen_broadcast: BroadcastEntity = Broadcast::new(None, "seen".to_string()).into();
+        seen_broadcast.date_created -= chrono::Duration::minutes(1);
+        let unseen_broadcast: BroadcastEntity = Broadcast::new(None, "unseen".to_string()).into();
+        let mut receipt = BroadcastReceipt::new(seen_broadcast.uuid, "recipient".to_string());
+        receipt.set_as_seen();
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .with(eq("recipient"))
+            .returning(move |_| Ok(vec![older.clone(), newer.clone()]));
+
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get_all()
+            .returning(move || Ok(vec![seen_broadcast.clone(), unseen_broadcast.clone()]));
+        broadcast_repository.expect_get_receipts_of()
+            .with(eq("recipient"))
+            .returning(move |_| Ok(vec![receipt.clone().into()]));
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), Arc::new(broadcast_repository), vec![], preference_settings(),
+        );
+
+        let result = service.list_notifications("recipient".to_string(), NotificationFilter::default(), vec![]).await.unwrap();
+
+        let kinds: Vec<(NotificationKind, bool)> = result.iter().map(|notification| (notification.kind, notification.seen)).collect();
+        assert_eq!(kinds, vec![
+            (NotificationKind::Personal, false),
+            (NotificationKind::Broadcast, true),
+            (NotificationKind::Personal, false),
+            (NotificationKind::Broadcast, false),
+        ]);
+        assert!(result.iter().all(|notification| notification.recipient == "recipient"));
+    }
+
+    #[tokio::test]
+    async fn test_list_notifications_filters_archived_and_lists_pinned_first() {
+        let mut pinned = generate_random_notification();
+        pinned.date_created -= chrono::Duration::minutes(1);
+        pinned.set_pinned(true);
+        let pinned: NotificationEntity = pinned.into();
+        let pinned_uuid = pinned.uuid;
+        let mut older = generate_random_notification();
+        older.date_created -= chrono::Duration::minutes(2);
+        let older: NotificationEntity = older.into();
+        let older_uuid = older.uuid;
+        let mut archived = generate_random_notification();
+        archived.set_archived(true);
+        let archived: NotificationEntity = archived.into();
+        let archived_uuid = archived.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .returning(move |_| Ok(vec![older.clone(), pinned.clone(), archived.clone()]));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), no_broadcasts(), vec![], preference_settings());
+
+        let inbox = service.list_notifications("recipient".to_string(), NotificationFilter::default(), vec![]).await.unwrap();
+        let archive = NotificationFilter { archived: Some(true), ..NotificationFilter::default() };
+        let archived = service.list_notifications("recipient".to_string(), archive, vec![]).await.unwrap();
+
+        let inbox_uuids: Vec<Uuid> = inbox.iter().map(|notification| notification.uuid).collect();
+        assert_eq!(inbox_uuids, vec![pinned_uuid, older_uuid]);
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].uuid, archived_uuid);
+    }
+
+    #[tokio::test]
+    async fn test_list_notifications_updated_since_includes_snoozed() {
+        let since = Utc::now() - chrono::Duration::minutes(1);
+        let mut unchanged = generate_random_notification();
+        unchanged.last_updated = since - chrono::Duration::minutes(1);
+        let unchanged: NotificationEntity = unchanged.into();
+        let mut snoozed = generate_random_notification();
+        snoozed.snooze(Utc::now() + chrono::Duration::hours(1));
+        let snoozed: NotificationEntity = snoozed.into();
+        let snoozed_uuid = snoozed.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .returning(move |_| Ok(vec![unchanged.clone(), snoozed.clone()]));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), no_broadcasts(), vec![], preference_settings());
+
+        let inbox = service.list_notifications("recipient".to_string(), NotificationFilter::default(), vec![]).await.unwrap();
+        let changes = NotificationFilter { archived: None, updated_since: Some(since), ..NotificationFilter::default() };
+        let changed = service.list_notifications("recipient".to_string(), changes, vec![]).await.unwrap();
+
+        assert_eq!(inbox.len(), 1);
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].uuid, snoozed_uuid);
+    }
+
+    #[tokio::test]
+    async fn test_set_archived_unpins_and_publishes_event() {
+        let mut notification = generate_random_notification();
+        notification.set_pinned(true);
+        let notification_entity: NotificationEntity = notification.into();
+        let uuid = notification_entity.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .with(eq(uuid))
+            .returning(move |_| Ok(notification_entity.clone()));
+        mocked_repo.expect_save()
+            .withf(|notification_entity, outbox_event, _| {
+                notification_entity.archived
+                    && !notification_entity.pinned
+                    && outbox_event.event_type == NotificationEventType::Archived
+            })
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.set_archived(uuid.to_string(), true, "recipient".to_string(), None, ChangeSource::Rest).await.unwrap();
+
+        assert!(result.archived);
+        assert!(!result.pinned);
+    }
+
+    #[tokio::test]
+    async fn test_search_notifications_filters_hits() {
+        let hit = |notification: Notification| NotificationSearchHitEntity {
+            notification: notification.into(),
+            rank: 1.0,
+            snippet: "<mark>invoice</mark>".to_string(),
+        };
+        let mut archived = generate_random_notification();
+        archived.set_archived(true);
+        let mut deleted = generate_random_notification();
+        deleted.set_as_deleted();
+        let archived = hit(archived);
+        let archived_uuid = archived.notification.uuid;
+        let hits = vec![hit(generate_random_notification()), archived, hit(deleted)];
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_search_of()
+            .with(eq("recipient"), eq("invoice"))
+            .returning(move |_, _| Ok(hits.clone()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let any = NotificationFilter { archived: None, ..NotificationFilter::default() };
+        let all = service.search_notifications("recipient".to_string(), "invoice".to_string(), any, 10).await.unwrap();
+        let archive = NotificationFilter { archived: Some(true), ..NotificationFilter::default() };
+        let archived = service.search_notifications("recipient".to_string(), "invoice".to_string(), archive, 10).await.unwrap();
+        let limited = service.search_notifications("recipient".to_string(), "invoice".to_string(), NotificationFilter::default(), 0).await.unwrap();
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].notification.uuid, archived_uuid);
+        assert!(limited.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_notifications_without_words_error() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_search_of().never();
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.search_notifications("recipient".to_string(), " - ".to_string(), NotificationFilter::default(), 10).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_notifications_with_tag_uses_tag_index() {
+        let mut tagged = generate_random_notification();
+        tagged.add_tag("billing".to_string());
+        let tagged: NotificationEntity = tagged.into();
+        let tagged_uuid = tagged.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of().never();
+        mocked_repo.expect_get_tagged_of()
+            .with(eq("recipient"), eq("billing"))
+            .returning(move |_, _| Ok(vec![tagged.clone()]));
+
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get_all()
+            .returning(|| Ok(vec![Broadcast::new(None, "announcement".to_string()).into()]));
+        broadcast_repository.expect_get_receipts_of()
+            .returning(|_| Ok(vec![]));
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), Arc::new(broadcast_repository), vec![], preference_settings(),
+        );
+
+        let filter = NotificationFilter { tag: Some("billing".to_string()), ..NotificationFilter::default() };
+        let result = service.list_notifications("recipient".to_string(), filter, vec![]).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].uuid, tagged_uuid);
+    }
+
+    #[tokio::test]
+    async fn test_add_tag_publishes_event_once() {
+        let mut notification = generate_random_notification();
+        notification.add_tag("work".to_string());
+        let notification_entity: NotificationEntity = notification.into();
+        let uuid = notification_entity.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .with(eq(uuid))
+            .returning(move |_| Ok(notification_entity.clone()));
+        mocked_repo.expect_save()
+            .withf(|notification_entity, outbox_event, _| {
+                notification_entity.tags == vec!["work".to_string(), "billing".to_string()]
+                    && outbox_event.event_type == NotificationEventType::Tagged
+            })
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let added = service.add_tag(uuid.to_string(), "billing".to_string(), "recipient".to_string(), None, ChangeSource::Rest).await.unwrap();
+        let unchanged = service.add_tag(uuid.to_string(), "work".to_string(), "recipient".to_string(), None, ChangeSource::Rest).await.unwrap();
+
+        assert_eq!(added.tags.len(), 2);
+        assert_eq!(unchanged.tags, vec!["work".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_create_notification_with_invalid_tags_error() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        for tags in [vec!["not a slug".to_string()], vec!["work".to_string(), "work".to_string()]] {
+            let command = CreateNotificationCommand {
+                recipient: "recipient".to_string(),
+                message: "message".to_string(),
+                category: None,
+                priority: Priority::Normal,
+                group_key: None,
+                actor: None,
+                actions: vec![],
+                auto_resolve: false,
+                tags,
+            };
+            assert!(matches!(service.create_notification(command).await, Err(ApplicationError::BadRequest(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_count_unread_includes_broadcasts() {
+        let mut seen: NotificationEntity = generate_random_notification().into();
+        seen.seen = true;
+        let unseen: NotificationEntity = generate_random_notification().into();
+        let broadcast: BroadcastEntity = Broadcast::new(None, "message".to_string()).into();
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .returning(move |_| Ok(vec![seen.clone(), unseen.clone()]));
+
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get_all()
+            .returning(move || Ok(vec![broadcast.clone()]));
+        broadcast_repository.expect_get_receipts_of()
+            .returning(|_| Ok(vec![]));
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), Arc::new(broadcast_repository), vec![], preference_settings(),
+        );
+
+        assert_eq!(service.count_unread("recipient".to_string()).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_count_unread_leaves_out_deleted() {
+        let unseen: NotificationEntity = generate_random_notification().into();
+        let deleted = NotificationEntity { deleted: true, ..generate_random_notification().into() };
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .returning(move |_| Ok(vec![unseen.clone(), deleted.clone()]));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), no_broadcasts(), vec![], preference_settings());
+
+        assert_eq!(service.count_unread("recipient".to_string()).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mark_broadcast_as_deleted_stores_receipt() {
+        let broadcast: BroadcastEntity = Broadcast::new(None, "message".to_string()).into();
+        let uuid = broadcast.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .returning(|uuid| Err(ApplicationError::NotFound(uuid.to_string())));
+        mocked_repo.expect_save().never();
+
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get()
+            .with(eq(uuid))
+            .returning(move |_| Ok(broadcast.clone()));
+        broadcast_repository.expect_get_receipt()
+            .returning(|uuid, recipient| Err(ApplicationError::NotFound(format!("{} {}", uuid, recipient))));
+        broadcast_repository.expect_save_receipt()
+            .withf(move |receipt, _| receipt.broadcast_uuid == uuid && receipt.recipient == "recipient" && receipt.seen && receipt.deleted)
+            .times(1)
+            .returning(|receipt, _| Ok(receipt.clone()));
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), Arc::new(broadcast_repository), vec![], preference_settings(),
+        );
+
+        let result = service.mark_as_deleted(uuid.to_string(), "recipient".to_string(), None, ChangeSource::Rest).await.unwrap();
+
+        assert!(result.changed);
+        assert_eq!(result.notification.kind, NotificationKind::Broadcast);
+        assert!(result.notification.deleted);
+    }
+
+    #[tokio::test]
+    async fn test_mark_broadcast_as_seen_again_unchanged() {
+        let broadcast: BroadcastEntity = Broadcast::new(None, "message".to_string()).into();
+        let uuid = broadcast.uuid;
+        let mut receipt = BroadcastReceipt::new(uuid, "recipient".to_string());
+        receipt.set_as_seen();
+        let receipt: BroadcastReceiptEntity = receipt.into();
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .returning(|uuid| Err(ApplicationError::NotFound(uuid.to_string())));
+
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get()
+            .returning(move |_| Ok(broadcast.clone()));
+        broadcast_repository.expect_get_receipt()
+            .returning(move |_, _| Ok(receipt.clone()));
+        broadcast_repository.expect_save_receipt().never();
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), Arc::new(broadcast_repository), vec![], preference_settings(),
+        );
+
+        let result = service.mark_as_seen(uuid.to_string(), "recipient".to_string(), None, ChangeSource::Rest).await.unwrap();
+
+        assert!(!result.changed);
+        assert!(result.notification.seen);
+    }
+
+    #[tokio::test]
+    async fn test_mark_as_seen_other_recipient_not_found() {
+        let notification_entity: NotificationEntity = generate_random_notification().into();
+        let uuid = notification_entity.uuid.to_string();
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .returning(move |_| Ok(notification_entity.clone()));
+        mocked_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.mark_as_seen(uuid, "someone else".to_string(), None, ChangeSource::Rest).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
+    fn grouped_notification(group_key: &str, actor: &str) -> NotificationEntity {
+        Notification {
+            group_key: Some(group_key.to_string()),
+            actor: Some(actor.to_string()),
+            ..generate_random_notification()
+        }.into()
+    }
+
+    #[tokio::test]
+    async fn test_list_notification_groups_leaves_out_deleted() {
+        let first = grouped_notification("post-42", "ana");
+        let mut deleted = grouped_notification("post-42", "eve");
+        deleted.deleted = true;
+        let second = grouped_notification("post-42", "bob");
+        let ungrouped: NotificationEntity = generate_random_notification().into();
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .with(eq("recipient"))
+            .returning(move |_| Ok(vec![first.clone(), deleted.clone(), second.clone(), ungrouped.clone()]));
+
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get_all().returning(|| Ok(vec![]));
+        broadcast_repository.expect_get_receipts_of().returning(|_| Ok(vec![]));
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), Arc::new(broadcast_repository), vec![], preference_settings(),
+        );
+
+        let result = service.list_notification_groups("recipient".to_string(), vec![]).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].group_key, Some("post-42".to_string()));
+        assert_eq!(result[0].count, 2);
+        assert_eq!(result[0].actors, vec!["bob".to_string(), "ana".to_string()]);
+        assert_eq!(result[1].group_key, None);
+    }
+
+    #[tokio::test]
+    async fn test_mark_group_as_seen_marks_unseen_of_group() {
+        let unseen = grouped_notification("post-42", "ana");
+        let mut seen = grouped_notification("post-42", "bob");
+        seen.seen = true;
+        let other_group = grouped_notification("post-7", "ana");
+        let unseen_uuid = unseen.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .returning(move |_| Ok(vec![unseen.clone(), seen.clone(), other_group.clone()]));
+        mocked_repo.expect_save()
+            .withf(move |notification_entity, outbox_event, _| {
+                notification_entity.uuid == unseen_uuid
+                    && notification_entity.seen
+                    && outbox_event.event_type == NotificationEventType::Seen
+            })
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.mark_group_as_seen("recipient".to_string(), "post-42".to_string(), ChangeSource::Rest).await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mark_group_as_deleted_unknown_group_not_found() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .returning(|_| Ok(vec![grouped_notification("post-7", "ana")]));
+        mocked_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.mark_group_as_deleted("recipient".to_string(), "post-42".to_string(), ChangeSource::Rest).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
+    fn approval(auto_resolve: bool) -> Notification {
+        let action = |id: &str| NotificationAction { id: id.to_string(), label: id.to_string(), style: ActionStyle::Primary, url: None };
+        Notification {
+            actions: vec![action("approve"), action("reject")],
+            auto_resolve,
+            ..generate_random_notification()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_take_action_publishes_event() {
+        let notification_entity: NotificationEntity = approval(true).into();
+        let uuid = notification_entity.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .with(eq(uuid))
+            .returning(move |_| Ok(notification_entity.clone()));
+        mocked_repo.expect_save()
+            .withf(|notification_entity, outbox_event, _| {
+                notification_entity.seen
+                    && outbox_event.event_type == NotificationEventType::ActionTaken
+                    && outbox_event.notification.action_taken.as_ref().is_some_and(|taken| taken.action_id == "reject")
+            })
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.take_action(uuid.to_string(), "reject".to_string(), "recipient".to_string(), None, ChangeSource::Rest).await.unwrap();
+
+        assert!(result.is_resolved());
+    }
+
+    #[tokio::test]
+    async fn test_take_action_resolved_conflict() {
+        let mut notification = approval(true);
+        notification.take_action("approve").unwrap();
+        let notification_entity: NotificationEntity = notification.into();
+        let uuid = notification_entity.uuid.to_string();
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .returning(move |_| Ok(notification_entity.clone()));
+        mocked_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.take_action(uuid, "reject".to_string(), "recipient".to_string(), None, ChangeSource::Rest).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_notification_duplicate_action_error() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let mut actions = approval(false).actions;
+        actions[1].id = "approve".to_string();
+        let command = CreateNotificationCommand {
+            recipient: "recipient".to_string(),
+            message: "message".to_string(),
+            category: None,
+            priority: Priority::Normal,
+            group_key: None,
+            actor: None,
+            actions,
+            auto_resolve: false,
+            tags: vec![],
+        };
+
+        assert!(matches!(service.create_notification(command).await, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_snooze_hides_notification_from_listing() {
+        let mut snoozed = generate_random_notification();
+        snoozed.snooze(Utc::now() + chrono::Duration::hours(1));
+        let snoozed: NotificationEntity = snoozed.into();
+        let visible: NotificationEntity = generate_random_notification().into();
+        let visible_uuid = visible.uuid;
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_all_of()
+            .returning(move |_| Ok(vec![snoozed.clone(), visible.clone()]));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), no_broadcasts(), vec![], preference_settings());
+
+        let result = service.list_notifications("recipient".to_string(), NotificationFilter::default(), vec![]).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].uuid, visible_uuid);
+    }
+
+    #[tokio::test]
+    async fn test_snooze_past_time_error() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = service.snooze(Uuid::new_v4().to_string(), Utc::now() - chrono::Duration::minutes(1), "recipient".to_string(), None, ChangeSource::Rest).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_snooze_deleted_error() {
+        let mut deleted = generate_random_notification();
+        deleted.set_as_deleted();
+        let deleted: NotificationEntity = deleted.into();
+        let uuid = deleted.uuid.to_string();
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get()
+            .returning(move |_| Ok(deleted.clone()));
+        mocked_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let until = Utc::now() + chrono::Duration::hours(1);
+        let snoozed = service.snooze(uuid.clone(), until, "recipient".to_string(), None, ChangeSource::Rest).await;
+        let command = UpdateNotificationCommand { snoozed_until: Some(until), ..UpdateNotificationCommand::default() };
+        let updated = service.update_notification(uuid, command, "recipient".to_string(), None, ChangeSource::Rest).await;
+
+        assert!(matches!(snoozed, Err(ApplicationError::BadRequest(_))));
+        assert!(matches!(updated, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_resurface_snoozed_publishes_event() {
+        let mut snoozed = generate_random_notification();
+        snoozed.set_as_seen();
+        snoozed.snooze(Utc::now());
+        let snoozed: NotificationEntity = snoozed.into();
+
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get_snoozed_until()
+            .with(mockall::predicate::always(), eq(10))
+            .returning(move |_, _| Ok(vec![snoozed.clone()]));
+        mocked_repo.expect_save()
+            .withf(|notification_entity, outbox_event, _| {
+                notification_entity.snoozed_until.is_none()
+                    && !notification_entity.seen
+                    && outbox_event.event_type == NotificationEventType::Resurfaced
+            })
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        assert_eq!(service.resurface_snoozed(Utc::now(), 10).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_notification_repository_error() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_save()
+            .withf(|_, outbox_event, _| outbox_event.event_type == NotificationEventType::Created)
+            .returning(|_, _, _| Err(ApplicationError::InternalError("storage unavailable".to_string())));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let command = CreateNotificationCommand { recipient: "recipient".to_string(), message: "message".to_string(), category: None, priority: Priority::Normal, group_key: None, actor: None, actions: vec![], auto_resolve: false, tags: vec![] };
+        assert!(matches!(service.create_notification(command).await, Err(ApplicationError::InternalError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_notification_from_template_success() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_save()
+            .withf(|notification_entity, outbox_event, _| {
+                notification_entity.title.as_deref() == Some("Order 42")
+                    && outbox_event.event_type == NotificationEventType::Created
+            })
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|id| Ok(NotificationTemplate::new(
+                id.to_string(), "en".to_string(), "Order {{order}}".to_string(), "<{{order}}> shipped".to_string(),
+            ).into()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, Arc::new(template_repository), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let command = CreateNotificationFromTemplateCommand {
+            recipient: "recipient".to_string(),
+            template_id: "order_shipped".to_string(),
+            channel: None,
+            category: None,
+            priority: Priority::Normal,
+            group_key: None,
+            actor: None,
+            actions: vec![],
+            auto_resolve: false,
+            tags: vec![],
+            variables: HashMap::from([("order".to_string(), "42".to_string())]),
+        };
+        let result = service.create_notification_from_template(command).await.unwrap();
+
+        assert_eq!(result.title, Some("Order 42".to_string()));
+        assert_eq!(result.message, "<42> shipped");
+    }
+
+    #[tokio::test]
+    async fn test_create_notification_from_template_for_email_escapes_variables() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_save()
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|id| Ok(NotificationTemplate::new(
+                id.to_string(), "en".to_string(), "Order {{order}}".to_string(), "<b>{{order}}</b> shipped".to_string(),
+            ).into()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, Arc::new(template_repository), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let command = CreateNotificationFromTemplateCommand {
+            recipient: "recipient".to_string(),
+            template_id: "order_shipped".to_string(),
+            channel: Some(Channel::Email),
+            category: None,
+            priority: Priority::Normal,
+            group_key: None,
+            actor: None,
+            actions: vec![],
+            auto_resolve: false,
+            tags: vec![],
+            variables: HashMap::from([("order".to_string(), "<42>".to_string())]),
+        };
+        let result = service.create_notification_from_template(command).await.unwrap();
+
+        assert_eq!(result.message, "<b>&lt;42&gt;</b> shipped");
+    }
+
+    #[tokio::test]
+    async fn test_create_notification_from_template_missing_variable_error() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_save().never();
+
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|id| Ok(NotificationTemplate::new(
+                id.to_string(), "en".to_string(), "Order {{order}}".to_string(), "shipped".to_string(),
+            ).into()));
+
+        let service = NotificationServiceImplementation::new(mocked_repo, Arc::new(template_repository), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let command = CreateNotificationFromTemplateCommand {
+            recipient: "recipient".to_string(),
+            template_id: "order_shipped".to_string(),
+            channel: None,
+            category: None,
+            priority: Priority::Normal,
+            group_key: None,
+            actor: None,
+            actions: vec![],
+            auto_resolve: false,
+            tags: vec![],
+            variables: HashMap::new(),
+        };
+        let result = service.create_notification_from_template(command).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_notification_with_category_success() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_save()
+            .withf(|notification_entity, _, _| notification_entity.category == "marketing")
+            .times(1)
+            .returning(|notification_entity, _, _| Ok(notification_entity.clone()));
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings(),
+        );
+
+        let command = CreateNotificationCommand {
+            recipient: "recipient".to_string(),
+            message: "message".to_string(),
+            category: Some("marketing".to_string()),
+            priority: Priority::Normal,
+            group_key: None,
+            actor: None,
+            actions: vec![],
+            auto_resolve: false,
+            tags: vec![],
+        };
+        assert_eq!(service.create_notification(command).await.unwrap().category, "marketing");
+    }
+
+    #[tokio::test]
+    async fn test_create_notification_unknown_category_error() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_save().never();
+
+        let service = NotificationServiceImplementation::new(
+            mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings(),
+        );
 
-        assert!(result.is_ok());
-        assert!(result.unwrap());
+        let command = CreateNotificationCommand {
+            recipient: "recipient".to_string(),
+            message: "message".to_string(),
+            category: Some("unknown".to_string()),
+            priority: Priority::Normal,
+            group_key: None,
+            actor: None,
+            actions: vec![],
+            auto_resolve: false,
+            tags: vec![],
+        };
+        assert!(matches!(service.create_notification(command).await, Err(ApplicationError::BadRequest(_))));
     }
 
+    #[tokio::test]
+    async fn test_get_notification_invalid_uuid_error() {
+        let mut mocked_repo = MockNotificationRepositoryPort::new();
+        mocked_repo.expect_get().never();
+
+        let notification_service = NotificationServiceImplementation::new(mocked_repo, template_repository(), profile_repository(), broadcast_repository(), vec![], preference_settings());
+
+        let result = notification_service.get_notification_of("not-a-uuid".to_string(), "recipient".to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    fn template_repository() -> Arc<dyn NotificationTemplateRepositoryPort> {
+        Arc::new(MockNotificationTemplateRepositoryPort::new())
+    }
+
+    fn preference_settings() -> PreferenceSettings {
+        let defaults = CategoryDefaults { email: true, push: true, sms: false };
+        PreferenceSettings {
+            categories: HashMap::from([
+                (DEFAULT_CATEGORY.to_string(), defaults.clone()),
+                ("marketing".to_string(), defaults),
+            ]),
+        }
+    }
+
+    fn broadcast_repository() -> Arc<dyn BroadcastRepositoryPort> {
+        Arc::new(MockBroadcastRepositoryPort::new())
+    }
+
+    fn no_broadcasts() -> Arc<dyn BroadcastRepositoryPort> {
+        let mut broadcast_repository = MockBroadcastRepositoryPort::new();
+        broadcast_repository.expect_get_all().returning(|| Ok(vec![]));
+        broadcast_repository.expect_get_receipts_of().returning(|_| Ok(vec![]));
+        Arc::new(broadcast_repository)
+    }
+
+    fn profile_repository() -> Arc<dyn RecipientProfileRepositoryPort> {
+        Arc::new(MockRecipientProfileRepositoryPort::new())
+    }
 
     fn generate_random_notification() -> Notification {
         let random_message = Uuid::new_v4().to_string();
-        Notification::new(random_message)
+        Notification::new("recipient".to_string(), random_message)
     }
 }
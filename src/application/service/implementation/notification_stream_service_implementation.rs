@@ -0,0 +1,176 @@
+use crate::application::service::notification_stream_service::{NotificationEventStream, NotificationStreamServicePort};
+use crate::domain::model::notification::NotificationKind;
+use crate::domain::model::notification_event::NotificationEvent;
+use crate::port::outgoing::event::notification_event_stream_port::NotificationEventStreamPort;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt;
+use uuid::Uuid;
+
+pub struct NotificationStreamServiceImplementation {
+    event_stream: Arc<dyn NotificationEventStreamPort>,
+}
+
+impl NotificationStreamServiceImplementation {
+    pub fn new(event_stream: Arc<dyn NotificationEventStreamPort>) -> Self {
+        Self { event_stream }
+    }
+
+    /// Events after `last_event_id`, or every retained event when it is too old to be found, so a resuming
+    /// subscriber may see duplicates but never misses an event still in the replay buffer.
+    fn replay(&self, recipient: &str, last_event_id: &str) -> Vec<NotificationEvent> {
+        let recent_events = self.event_stream.recent_events();
+        let start = recent_events.iter()
+            .position(|event| event.uuid.to_string() == last_event_id)
+            .map_or(0, |position| position + 1);
+
+        recent_events.into_iter()
+            .skip(start)
+            .filter_map(|event| addressed_to(event, recipient))
+            .collect()
+    }
+}
+
+/// The event if it concerns the recipient, with broadcasts, published once for everyone, addressed to them.
+fn addressed_to(mut event: NotificationEvent, recipient: &str) -> Option<NotificationEvent> {
+    match event.notification.kind {
+        NotificationKind::Broadcast => {
+            event.notification.recipient = recipient.to_string();
+            Some(event)
+        }
+        NotificationKind::Personal if event.notification.recipient == recipient => Some(event),
+        NotificationKind::Personal => None,
+    }
+}
+
+impl NotificationStreamServicePort for NotificationStreamServiceImplementation {
+    fn subscribe(&self, recipient: String, last_event_id: Option<String>) -> NotificationEventStream {
+        // Subscribing before reading the replay buffer ensures no event falls between the two.
+        let receiver = self.event_stream.subscribe();
+        let replayed = last_event_id
+            .map(|last_event_id| self.replay(&recipient, &last_event_id))
+            .unwrap_or_default();
+        let replayed_uuids: HashSet<Uuid> = replayed.iter().map(|event| event.uuid).collect();
+
+        // A lagging receiver has lost events, so the stream ends there for the subscriber to resume from the last
+        // event it received instead of going on past the gap.
+        let live = BroadcastStream::new(receiver)
+            .map_while(Result::ok)
+            .filter(move |event| !replayed_uuids.contains(&event.uuid))
+            .filter_map(move |event| addressed_to(event, &recipient));
+
+        Box::pin(tokio_stream::iter(replayed).chain(live))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::broadcast::{Broadcast, EVERY_RECIPIENT};
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::NotificationEventType;
+    use crate::port::outgoing::event::notification_event_stream_port::MockNotificationEventStreamPort;
+    use tokio::sync::broadcast;
+
+    fn event(recipient: &str) -> NotificationEvent {
+        NotificationEvent::new(
+            NotificationEventType::Created,
+            Notification::new(recipient.to_string(), "message".to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_streams_only_recipient_events() {
+        let (sender, _) = broadcast::channel(16);
+        let subscribe_sender = sender.clone();
+        let mut event_stream = MockNotificationEventStreamPort::new();
+        event_stream.expect_subscribe().returning(move || subscribe_sender.subscribe());
+        event_stream.expect_recent_events().never();
+
+        let service = NotificationStreamServiceImplementation::new(Arc::new(event_stream));
+        let mut stream = service.subscribe("recipient".to_string(), None);
+
+        let expected = event("recipient");
+        sender.send(event("someone else")).unwrap();
+        sender.send(expected.clone()).unwrap();
+
+        assert_eq!(stream.next().await.unwrap().uuid, expected.uuid);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_streams_broadcasts_to_every_recipient() {
+        let (sender, _) = broadcast::channel(16);
+        let subscribe_sender = sender.clone();
+        let mut event_stream = MockNotificationEventStreamPort::new();
+        event_stream.expect_subscribe().returning(move || subscribe_sender.subscribe());
+
+        let service = NotificationStreamServiceImplementation::new(Arc::new(event_stream));
+        let mut stream = service.subscribe("recipient".to_string(), None);
+
+        let announcement = Broadcast::new(None, "message".to_string()).to_notification(EVERY_RECIPIENT.to_string(), None);
+        sender.send(NotificationEvent::new(NotificationEventType::Created, announcement.clone())).unwrap();
+
+        let result = stream.next().await.unwrap();
+        assert_eq!(result.notification.uuid, announcement.uuid);
+        assert_eq!(result.notification.recipient, "recipient");
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_ends_when_lagging() {
+        let (sender, _) = broadcast::channel(1);
+        let subscribe_sender = sender.clone();
+        let mut event_stream = MockNotificationEventStreamPort::new();
+        event_stream.expect_subscribe().returning(move || subscribe_sender.subscribe());
+        event_stream.expect_recent_events().never();
+
+        let service = NotificationStreamServiceImplementation::new(Arc::new(event_stream));
+        let mut stream = service.subscribe("recipient".to_string(), None);
+
+        sender.send(event("recipient")).unwrap();
+        sender.send(event("recipient")).unwrap();
+
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_replays_after_last_event_id() {
+        let (sender, _) = broadcast::channel(16);
+        let subscribe_sender = sender.clone();
+        let seen = event("recipient");
+        let missed = event("recipient");
+        let recent_events = vec![seen.clone(), event("someone else"), missed.clone()];
+
+        let mut event_stream = MockNotificationEventStreamPort::new();
+        event_stream.expect_subscribe().returning(move || subscribe_sender.subscribe());
+        event_stream.expect_recent_events().returning(move || recent_events.clone());
+
+        let service = NotificationStreamServiceImplementation::new(Arc::new(event_stream));
+        let mut stream = service.subscribe("recipient".to_string(), Some(seen.uuid.to_string()));
+
+        let live = event("recipient");
+        sender.send(missed.clone()).unwrap();
+        sender.send(live.clone()).unwrap();
+
+        assert_eq!(stream.next().await.unwrap().uuid, missed.uuid);
+        assert_eq!(stream.next().await.unwrap().uuid, live.uuid);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_replays_everything_for_unknown_last_event_id() {
+        let (sender, _) = broadcast::channel::<NotificationEvent>(16);
+        let first = event("recipient");
+        let second = event("recipient");
+        let recent_events = vec![first.clone(), second.clone()];
+
+        let mut event_stream = MockNotificationEventStreamPort::new();
+        event_stream.expect_subscribe().returning(move || sender.subscribe());
+        event_stream.expect_recent_events().returning(move || recent_events.clone());
+
+        let service = NotificationStreamServiceImplementation::new(Arc::new(event_stream));
+        let mut stream = service.subscribe("recipient".to_string(), Some(Uuid::new_v4().to_string()));
+
+        assert_eq!(stream.next().await.unwrap().uuid, first.uuid);
+        assert_eq!(stream.next().await.unwrap().uuid, second.uuid);
+    }
+}
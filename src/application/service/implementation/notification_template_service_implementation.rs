@@ -0,0 +1,262 @@
+use crate::adapter::outgoing::persistence::entity::notification_template_entity::NotificationTemplateEntity;
+use crate::application::error::ApplicationError;
+use crate::application::service::implementation::slug_validator::validate_slug;
+use crate::application::service::notification_template_service::NotificationTemplateServicePort;
+use crate::domain::model::localisation::locale_candidates;
+use crate::domain::model::notification_template::{NotificationTemplate, RenderedTemplate, TemplateFormat};
+use crate::port::outgoing::persistence::notification_template_repository_port::NotificationTemplateRepositoryPort;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct NotificationTemplateServiceImplementation {
+    template_repository: Arc<dyn NotificationTemplateRepositoryPort>,
+}
+
+impl NotificationTemplateServiceImplementation {
+    pub fn new(template_repository: Arc<dyn NotificationTemplateRepositoryPort>) -> Self {
+        Self { template_repository }
+    }
+
+    async fn save(&self, template: NotificationTemplate) -> Result<NotificationTemplate, ApplicationError> {
+        template.validate()?;
+        let template_entity: NotificationTemplateEntity = template.clone().into();
+        self.template_repository.save(&template_entity).await?;
+        Ok(template)
+    }
+}
+
+fn validate_locale(locale: &str) -> Result<(), ApplicationError> {
+    if locale.trim().is_empty() {
+        return Err(ApplicationError::BadRequest("Locale cannot be empty".to_string()));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl NotificationTemplateServicePort for NotificationTemplateServiceImplementation {
+    async fn create_template(
+        &self,
+        id: String,
+        locale: String,
+        title: String,
+        body: String,
+    ) -> Result<NotificationTemplate, ApplicationError> {
+        validate_slug("template", &id)?;
+        validate_locale(&locale)?;
+        match self.template_repository.get(&id).await {
+            Ok(_) => return Err(ApplicationError::Conflict(format!("Notification template {} already exists", id))),
+            Err(ApplicationError::NotFound(_)) => {}
+            Err(error) => return Err(error),
+        }
+
+        self.save(NotificationTemplate::new(id, locale, title, body)).await
+    }
+
+    async fn get_template(&self, id: String) -> Result<NotificationTemplate, ApplicationError> {
+        Ok(self.template_repository.get(&id).await?.into())
+    }
+
+    async fn list_templates(&self) -> Result<Vec<NotificationTemplate>, ApplicationError> {
+        let template_entities = self.template_repository.get_all().await?;
+        Ok(template_entities.into_iter().map(|entity| entity.into()).collect())
+    }
+
+    async fn update_template(
+        &self,
+        id: String,
+        locale: String,
+        title: String,
+        body: String,
+    ) -> Result<NotificationTemplate, ApplicationError> {
+        validate_locale(&locale)?;
+        let mut template: NotificationTemplate = self.template_repository.get(&id).await?.into();
+        template.update(locale, title, body);
+        self.save(template).await
+    }
+
+    async fn delete_template(&self, id: String) -> Result<(), ApplicationError> {
+        self.template_repository.delete(&id).await
+    }
+
+    async fn set_variant(
+        &self,
+        id: String,
+        locale: String,
+        title: String,
+        body: String,
+    ) -> Result<NotificationTemplate, ApplicationError> {
+        validate_locale(&locale)?;
+        let mut template: NotificationTemplate = self.template_repository.get(&id).await?.into();
+        template.set_variant(locale, title, body);
+        self.save(template).await
+    }
+
+    async fn delete_variant(&self, id: String, locale: String) -> Result<NotificationTemplate, ApplicationError> {
+        let mut template: NotificationTemplate = self.template_repository.get(&id).await?.into();
+        if !template.remove_variant(&locale) {
+            return Err(ApplicationError::NotFound(format!("Notification template {} has no {} variant", id, locale)));
+        }
+        self.save(template).await
+    }
+
+    async fn render_template(
+        &self,
+        id: String,
+        variables: HashMap<String, String>,
+        format: TemplateFormat,
+        locale: Option<String>,
+    ) -> Result<RenderedTemplate, ApplicationError> {
+        let mut template: NotificationTemplate = self.template_repository.get(&id).await?.into();
+        if let Some(locale) = locale {
+            template.localize(&locale_candidates(&[locale], &[]));
+        }
+        Ok(template.render(&variables, format)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::outgoing::persistence::notification_template_repository_port::MockNotificationTemplateRepositoryPort;
+    use mockall::predicate::eq;
+
+    fn service(template_repository: MockNotificationTemplateRepositoryPort) -> NotificationTemplateServiceImplementation {
+        NotificationTemplateServiceImplementation::new(Arc::new(template_repository))
+    }
+
+    fn template_entity() -> NotificationTemplateEntity {
+        NotificationTemplate::new(
+            "welcome".to_string(),
+            "en".to_string(),
+            "Welcome {{name}}".to_string(),
+            "Hello <b>{{name}}</b>".to_string(),
+        ).into()
+    }
+
+    #[tokio::test]
+    async fn test_create_template_success() {
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|id| Err(ApplicationError::NotFound(id.to_string())));
+        template_repository.expect_save()
+            .times(1)
+            .returning(|template| Ok(template.clone()));
+
+        let result = service(template_repository)
+            .create_template("welcome".to_string(), "en".to_string(), "Hi".to_string(), "Hello {{name}}".to_string()).await
+            .unwrap();
+
+        assert_eq!(result.id, "welcome");
+        assert_eq!(result.version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_template_existing_id_error() {
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|_| Ok(template_entity()));
+        template_repository.expect_save().never();
+
+        let result = service(template_repository)
+            .create_template("welcome".to_string(), "en".to_string(), "Hi".to_string(), "Hello".to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_template_malformed_placeholder_error() {
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|id| Err(ApplicationError::NotFound(id.to_string())));
+        template_repository.expect_save().never();
+
+        let result = service(template_repository)
+            .create_template("welcome".to_string(), "en".to_string(), "Hi".to_string(), "Hello {{name".to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_template_invalid_id_error() {
+        let result = service(MockNotificationTemplateRepositoryPort::new())
+            .create_template("wel come/".to_string(), "en".to_string(), "Hi".to_string(), "Hello".to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_template_increments_version() {
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .with(eq("welcome"))
+            .returning(|_| Ok(template_entity()));
+        template_repository.expect_save()
+            .withf(|template| template.version == 2 && template.body == "Hey {{name}}")
+            .times(1)
+            .returning(|template| Ok(template.clone()));
+
+        let result = service(template_repository)
+            .update_template("welcome".to_string(), "en".to_string(), "Hi".to_string(), "Hey {{name}}".to_string()).await
+            .unwrap();
+
+        assert_eq!(result.version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_render_template_html_success() {
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|_| Ok(template_entity()));
+
+        let variables = HashMap::from([("name".to_string(), "<Ana>".to_string())]);
+        let result = service(template_repository)
+            .render_template("welcome".to_string(), variables, TemplateFormat::Html, None).await
+            .unwrap();
+
+        assert_eq!(result.body, "Hello <b>&lt;Ana&gt;</b>");
+    }
+
+    #[tokio::test]
+    async fn test_render_template_localized_success() {
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|_| {
+                let mut template: NotificationTemplate = template_entity().into();
+                template.set_variant("pt".to_string(), "Bem-vindo".to_string(), "Olá {{name}}".to_string());
+                Ok(template.into())
+            });
+
+        let variables = HashMap::from([("name".to_string(), "Ana".to_string())]);
+        let result = service(template_repository)
+            .render_template("welcome".to_string(), variables, TemplateFormat::Text, Some("pt-BR".to_string())).await
+            .unwrap();
+
+        assert_eq!(result.body, "Olá Ana");
+    }
+
+    #[tokio::test]
+    async fn test_delete_variant_unknown_locale_error() {
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|_| Ok(template_entity()));
+        template_repository.expect_save().never();
+
+        let result = service(template_repository)
+            .delete_variant("welcome".to_string(), "pt".to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_render_template_missing_variable_error() {
+        let mut template_repository = MockNotificationTemplateRepositoryPort::new();
+        template_repository.expect_get()
+            .returning(|_| Ok(template_entity()));
+
+        let result = service(template_repository)
+            .render_template("welcome".to_string(), HashMap::new(), TemplateFormat::Text, None).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(message)) if message.contains("name")));
+    }
+}
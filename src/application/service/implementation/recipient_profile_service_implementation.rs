@@ -0,0 +1,276 @@
+use crate::adapter::outgoing::persistence::entity::recipient_profile_entity::RecipientProfileEntity;
+use crate::application::configuration::structs::PreferenceSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::recipient_profile_service::RecipientProfileServicePort;
+use crate::domain::model::digest::DigestFrequency;
+use crate::domain::model::preference::{Channel, ChannelPreference, EffectivePreference};
+use crate::domain::model::quiet_hours::QuietHours;
+use crate::domain::model::recipient_profile::RecipientProfile;
+use crate::port::outgoing::persistence::recipient_profile_repository_port::RecipientProfileRepositoryPort;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct RecipientProfileServiceImplementation {
+    profile_repository: Arc<dyn RecipientProfileRepositoryPort>,
+    preference_settings: PreferenceSettings,
+}
+
+impl RecipientProfileServiceImplementation {
+    pub fn new(profile_repository: Arc<dyn RecipientProfileRepositoryPort>, preference_settings: PreferenceSettings) -> Self {
+        Self { profile_repository, preference_settings }
+    }
+
+    fn effective_preferences(&self, profile: &RecipientProfile) -> Vec<EffectivePreference> {
+        let mut categories: Vec<&String> = self.preference_settings.categories.keys().collect();
+        categories.sort();
+
+        categories.into_iter()
+            .flat_map(|category| Channel::ALL.into_iter().map(move |channel| (category, channel)))
+            .map(|(category, channel)| {
+                let chosen = profile.preference(category, channel);
+                EffectivePreference {
+                    category: category.clone(),
+                    channel,
+                    enabled: is_enabled(&self.preference_settings, profile, category, channel),
+                    default: chosen.is_none(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Profiles are created lazily, so recipients that never changed a setting have none stored.
+pub async fn load_profile(
+    profile_repository: &dyn RecipientProfileRepositoryPort,
+    recipient: &str,
+) -> Result<RecipientProfile, ApplicationError> {
+    match profile_repository.get(recipient).await {
+        Ok(profile_entity) => Ok(profile_entity.into()),
+        Err(ApplicationError::NotFound(_)) => Ok(RecipientProfile::new(recipient.to_string())),
+        Err(error) => Err(error),
+    }
+}
+
+/// The recipient's choice wins over the category default.
+pub fn is_enabled(preference_settings: &PreferenceSettings, profile: &RecipientProfile, category: &str, channel: Channel) -> bool {
+    profile.preference(category, channel)
+        .unwrap_or_else(|| preference_settings.default_enabled(category, channel))
+}
+
+#[async_trait]
+impl RecipientProfileServicePort for RecipientProfileServiceImplementation {
+    async fn get_profile(&self, recipient: String) -> Result<RecipientProfile, ApplicationError> {
+        load_profile(self.profile_repository.as_ref(), &recipient).await
+    }
+
+    async fn update_profile(&self, recipient: String, locale: Option<String>) -> Result<RecipientProfile, ApplicationError> {
+        if locale.as_deref().is_some_and(|locale| locale.trim().is_empty()) {
+            return Err(ApplicationError::BadRequest("Locale cannot be empty".to_string()));
+        }
+
+        let mut profile = load_profile(self.profile_repository.as_ref(), &recipient).await?;
+        profile.set_locale(locale);
+        let profile_entity: RecipientProfileEntity = profile.clone().into();
+        self.profile_repository.save(&profile_entity).await?;
+        Ok(profile)
+    }
+
+    async fn get_preferences(&self, recipient: String) -> Result<Vec<EffectivePreference>, ApplicationError> {
+        let profile = load_profile(self.profile_repository.as_ref(), &recipient).await?;
+        Ok(self.effective_preferences(&profile))
+    }
+
+    async fn update_preferences(
+        &self,
+        recipient: String,
+        preferences: Vec<ChannelPreference>,
+    ) -> Result<Vec<EffectivePreference>, ApplicationError> {
+        if let Some(preference) = preferences.iter().find(|preference| !self.preference_settings.categories.contains_key(&preference.category)) {
+            return Err(ApplicationError::BadRequest(format!("{} is not a known category", preference.category)));
+        }
+
+        let mut profile = load_profile(self.profile_repository.as_ref(), &recipient).await?;
+        for preference in preferences {
+            profile.set_preference(preference);
+        }
+        let profile_entity: RecipientProfileEntity = profile.clone().into();
+        self.profile_repository.save(&profile_entity).await?;
+        Ok(self.effective_preferences(&profile))
+    }
+
+    async fn get_quiet_hours(&self, recipient: String) -> Result<QuietHours, ApplicationError> {
+        let profile = load_profile(self.profile_repository.as_ref(), &recipient).await?;
+        Ok(profile.quiet_hours)
+    }
+
+    async fn update_quiet_hours(&self, recipient: String, quiet_hours: QuietHours) -> Result<QuietHours, ApplicationError> {
+        if quiet_hours.window.as_ref().is_some_and(|window| window.start == window.end) {
+            return Err(ApplicationError::BadRequest("Quiet hours cannot start and end at the same time".to_string()));
+        }
+
+        let mut profile = load_profile(self.profile_repository.as_ref(), &recipient).await?;
+        profile.set_quiet_hours(quiet_hours);
+        let profile_entity: RecipientProfileEntity = profile.clone().into();
+        self.profile_repository.save(&profile_entity).await?;
+        Ok(profile.quiet_hours)
+    }
+
+    async fn get_digest_frequency(&self, recipient: String) -> Result<Option<DigestFrequency>, ApplicationError> {
+        let profile = load_profile(self.profile_repository.as_ref(), &recipient).await?;
+        Ok(profile.digest_frequency)
+    }
+
+    async fn update_digest_frequency(
+        &self,
+        recipient: String,
+        digest_frequency: Option<DigestFrequency>,
+    ) -> Result<Option<DigestFrequency>, ApplicationError> {
+        let mut profile = load_profile(self.profile_repository.as_ref(), &recipient).await?;
+        profile.set_digest_frequency(digest_frequency);
+        let profile_entity: RecipientProfileEntity = profile.clone().into();
+        self.profile_repository.save(&profile_entity).await?;
+        Ok(profile.digest_frequency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::configuration::structs::CategoryDefaults;
+    use crate::domain::model::quiet_hours::QuietWindow;
+    use crate::port::outgoing::persistence::recipient_profile_repository_port::MockRecipientProfileRepositoryPort;
+    use std::collections::HashMap;
+
+    fn preference_settings() -> PreferenceSettings {
+        PreferenceSettings {
+            categories: HashMap::from([
+                ("general".to_string(), CategoryDefaults { email: true, push: true, sms: false }),
+                ("marketing".to_string(), CategoryDefaults { email: true, push: false, sms: false }),
+            ]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_profile_defaults_when_missing() {
+        let mut profile_repository = MockRecipientProfileRepositoryPort::new();
+        profile_repository.expect_get()
+            .returning(|recipient| Err(ApplicationError::NotFound(recipient.to_string())));
+
+        let result = RecipientProfileServiceImplementation::new(Arc::new(profile_repository), preference_settings())
+            .get_profile("recipient".to_string()).await
+            .unwrap();
+
+        assert_eq!(result.recipient, "recipient");
+        assert_eq!(result.locale, None);
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_success() {
+        let mut profile_repository = MockRecipientProfileRepositoryPort::new();
+        profile_repository.expect_get()
+            .returning(|recipient| Err(ApplicationError::NotFound(recipient.to_string())));
+        profile_repository.expect_save()
+            .withf(|profile| profile.locale.as_deref() == Some("pt-BR"))
+            .times(1)
+            .returning(|profile| Ok(profile.clone()));
+
+        let result = RecipientProfileServiceImplementation::new(Arc::new(profile_repository), preference_settings())
+            .update_profile("recipient".to_string(), Some("pt-BR".to_string())).await
+            .unwrap();
+
+        assert_eq!(result.locale, Some("pt-BR".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_empty_locale_error() {
+        let result = RecipientProfileServiceImplementation::new(Arc::new(MockRecipientProfileRepositoryPort::new()), preference_settings())
+            .update_profile("recipient".to_string(), Some(" ".to_string())).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_preferences_overrides_defaults() {
+        let mut profile_repository = MockRecipientProfileRepositoryPort::new();
+        profile_repository.expect_get()
+            .returning(|recipient| Err(ApplicationError::NotFound(recipient.to_string())));
+        profile_repository.expect_save()
+            .times(1)
+            .returning(|profile| Ok(profile.clone()));
+
+        let preferences = vec![ChannelPreference { category: "marketing".to_string(), channel: Channel::Email, enabled: false }];
+        let result = RecipientProfileServiceImplementation::new(Arc::new(profile_repository), preference_settings())
+            .update_preferences("recipient".to_string(), preferences).await
+            .unwrap();
+
+        assert_eq!(result.len(), 6);
+        assert_eq!(result[0], EffectivePreference { category: "general".to_string(), channel: Channel::Email, enabled: true, default: true });
+        assert_eq!(result[3], EffectivePreference { category: "marketing".to_string(), channel: Channel::Email, enabled: false, default: false });
+        assert_eq!(result[4], EffectivePreference { category: "marketing".to_string(), channel: Channel::Push, enabled: false, default: true });
+    }
+
+    #[tokio::test]
+    async fn test_update_preferences_unknown_category_error() {
+        let mut profile_repository = MockRecipientProfileRepositoryPort::new();
+        profile_repository.expect_save().never();
+
+        let preferences = vec![ChannelPreference { category: "unknown".to_string(), channel: Channel::Sms, enabled: true }];
+        let result = RecipientProfileServiceImplementation::new(Arc::new(profile_repository), preference_settings())
+            .update_preferences("recipient".to_string(), preferences).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_quiet_hours_success() {
+        let mut profile_repository = MockRecipientProfileRepositoryPort::new();
+        profile_repository.expect_get()
+            .returning(|recipient| Err(ApplicationError::NotFound(recipient.to_string())));
+        profile_repository.expect_save()
+            .withf(|profile| profile.quiet_hours.window.is_some())
+            .times(1)
+            .returning(|profile| Ok(profile.clone()));
+
+        let quiet_hours = QuietHours {
+            window: Some(QuietWindow { start: "22:00".parse().unwrap(), end: "07:00".parse().unwrap(), time_zone: chrono_tz::Europe::Lisbon }),
+            ..QuietHours::default()
+        };
+        let result = RecipientProfileServiceImplementation::new(Arc::new(profile_repository), preference_settings())
+            .update_quiet_hours("recipient".to_string(), quiet_hours.clone()).await
+            .unwrap();
+
+        assert_eq!(result, quiet_hours);
+    }
+
+    #[tokio::test]
+    async fn test_update_quiet_hours_empty_window_error() {
+        let mut profile_repository = MockRecipientProfileRepositoryPort::new();
+        profile_repository.expect_save().never();
+
+        let quiet_hours = QuietHours {
+            window: Some(QuietWindow { start: "22:00".parse().unwrap(), end: "22:00".parse().unwrap(), time_zone: chrono_tz::UTC }),
+            ..QuietHours::default()
+        };
+        let result = RecipientProfileServiceImplementation::new(Arc::new(profile_repository), preference_settings())
+            .update_quiet_hours("recipient".to_string(), quiet_hours).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_digest_frequency_success() {
+        let mut profile_repository = MockRecipientProfileRepositoryPort::new();
+        profile_repository.expect_get()
+            .returning(|recipient| Err(ApplicationError::NotFound(recipient.to_string())));
+        profile_repository.expect_save()
+            .withf(|profile| profile.digest_frequency == Some(DigestFrequency::Daily))
+            .times(1)
+            .returning(|profile| Ok(profile.clone()));
+
+        let result = RecipientProfileServiceImplementation::new(Arc::new(profile_repository), preference_settings())
+            .update_digest_frequency("recipient".to_string(), Some(DigestFrequency::Daily)).await
+            .unwrap();
+
+        assert_eq!(result, Some(DigestFrequency::Daily));
+    }
+}
@@ -0,0 +1,11 @@
+use crate::application::error::ApplicationError;
+
+/// Ids end up in urls and producer code, so they are restricted to a url safe slug.
+pub fn validate_slug(kind: &str, id: &str) -> Result<(), ApplicationError> {
+    let valid = !id.is_empty()
+        && id.chars().all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_' | '.'));
+    if !valid {
+        return Err(ApplicationError::BadRequest(format!("{} is not a valid {} id", id, kind)));
+    }
+    Ok(())
+}
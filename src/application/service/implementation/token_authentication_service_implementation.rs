@@ -0,0 +1,88 @@
+use crate::application::error::ApplicationError;
+use crate::application::service::authentication_service::AuthenticationServicePort;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Accepts tokens of the form `{recipient}.{signature}`, where the signature is the hex HMAC-SHA256 of the
+/// recipient keyed with the configured token secret. The configured administrators are recipients like any other.
+pub struct TokenAuthenticationServiceImplementation {
+    token_secret: String,
+    administrators: Vec<String>,
+}
+
+impl TokenAuthenticationServiceImplementation {
+    pub fn new(token_secret: String, administrators: Vec<String>) -> Self {
+        Self { token_secret, administrators }
+    }
+
+    fn mac(&self, recipient: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.token_secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(recipient.as_bytes());
+        mac
+    }
+}
+
+impl AuthenticationServicePort for TokenAuthenticationServiceImplementation {
+    fn authenticate(&self, token: &str) -> Result<String, ApplicationError> {
+        let invalid_token = || ApplicationError::Unauthorized("Invalid token".to_string());
+
+        let (recipient, signature) = token.rsplit_once('.').ok_or_else(invalid_token)?;
+        let signature = hex::decode(signature).map_err(|_| invalid_token())?;
+
+        if recipient.is_empty() || self.mac(recipient).verify_slice(&signature).is_err() {
+            return Err(invalid_token());
+        }
+
+        Ok(recipient.to_string())
+    }
+
+    fn is_administrator(&self, recipient: &str) -> bool {
+        self.administrators.iter().any(|administrator| administrator == recipient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_token(secret: &str, recipient: &str) -> String {
+        let signature = TokenAuthenticationServiceImplementation::new(secret.to_string(), vec![]).mac(recipient).finalize();
+        format!("{}.{}", recipient, hex::encode(signature.into_bytes()))
+    }
+
+    #[test]
+    fn test_authenticate_success() {
+        let service = TokenAuthenticationServiceImplementation::new("secret".to_string(), vec![]);
+
+        let result = service.authenticate(&issue_token("secret", "user.1@example.com"));
+
+        assert_eq!(result.unwrap(), "user.1@example.com");
+    }
+
+    #[test]
+    fn test_authenticate_wrong_secret_error() {
+        let service = TokenAuthenticationServiceImplementation::new("secret".to_string(), vec![]);
+
+        let result = service.authenticate(&issue_token("other secret", "recipient"));
+
+        assert!(matches!(result, Err(ApplicationError::Unauthorized(_))));
+    }
+
+    #[test]
+    fn test_authenticate_malformed_token_error() {
+        let service = TokenAuthenticationServiceImplementation::new("secret".to_string(), vec![]);
+
+        assert!(service.authenticate("recipient").is_err());
+        assert!(service.authenticate("recipient.not-hex").is_err());
+        assert!(service.authenticate(&issue_token("secret", "")).is_err());
+    }
+
+    #[test]
+    fn test_is_administrator() {
+        let service = TokenAuthenticationServiceImplementation::new("secret".to_string(), vec!["admin".to_string()]);
+
+        assert!(service.is_administrator("admin"));
+        assert!(!service.is_administrator("recipient"));
+    }
+}
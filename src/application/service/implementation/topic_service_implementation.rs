@@ -0,0 +1,233 @@
+use crate::adapter::outgoing::persistence::entity::topic_entity::{TopicEntity, TopicSubscriptionEntity};
+use crate::adapter::outgoing::persistence::entity::topic_publication_entity::TopicPublicationEntity;
+use crate::application::command::publish_to_topic_command::PublishToTopicCommand;
+use crate::application::configuration::structs::PreferenceSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::implementation::notification_service_implementation::resolve_category;
+use crate::application::service::implementation::slug_validator::validate_slug;
+use crate::application::service::implementation::uuid_parser::parse_uuid;
+use crate::application::service::topic_service::TopicServicePort;
+use crate::domain::model::topic::{Topic, TopicSubscription};
+use crate::domain::model::topic_publication::TopicPublication;
+use crate::port::outgoing::persistence::topic_publication_repository_port::TopicPublicationRepositoryPort;
+use crate::port::outgoing::persistence::topic_repository_port::TopicRepositoryPort;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct TopicServiceImplementation {
+    topic_repository: Arc<dyn TopicRepositoryPort>,
+    publication_repository: Arc<dyn TopicPublicationRepositoryPort>,
+    preference_settings: PreferenceSettings,
+}
+
+impl TopicServiceImplementation {
+    pub fn new(
+        topic_repository: Arc<dyn TopicRepositoryPort>,
+        publication_repository: Arc<dyn TopicPublicationRepositoryPort>,
+        preference_settings: PreferenceSettings,
+    ) -> Self {
+        Self { topic_repository, publication_repository, preference_settings }
+    }
+}
+
+#[async_trait]
+impl TopicServicePort for TopicServiceImplementation {
+    async fn create_topic(&self, id: String, description: Option<String>) -> Result<Topic, ApplicationError> {
+        validate_slug("topic", &id)?;
+        match self.topic_repository.get(&id).await {
+            Ok(_) => return Err(ApplicationError::Conflict(format!("Topic {} already exists", id))),
+            Err(ApplicationError::NotFound(_)) => {}
+            Err(error) => return Err(error),
+        }
+
+        let topic = Topic::new(id, description);
+        let topic_entity: TopicEntity = topic.clone().into();
+        self.topic_repository.save(&topic_entity).await?;
+        Ok(topic)
+    }
+
+    async fn get_topic(&self, id: String) -> Result<Topic, ApplicationError> {
+        Ok(self.topic_repository.get(&id).await?.into())
+    }
+
+    async fn list_topics(&self) -> Result<Vec<Topic>, ApplicationError> {
+        let topic_entities = self.topic_repository.get_all().await?;
+        Ok(topic_entities.into_iter().map(|entity| entity.into()).collect())
+    }
+
+    async fn delete_topic(&self, id: String) -> Result<(), ApplicationError> {
+        self.topic_repository.delete(&id).await
+    }
+
+    async fn subscribe(&self, topic_id: String, recipient: String) -> Result<TopicSubscription, ApplicationError> {
+        self.topic_repository.get(&topic_id).await?;
+        let subscription_entity: TopicSubscriptionEntity = TopicSubscription::new(topic_id, recipient).into();
+        Ok(self.topic_repository.subscribe(&subscription_entity).await?.into())
+    }
+
+    async fn unsubscribe(&self, topic_id: String, recipient: String) -> Result<(), ApplicationError> {
+        self.topic_repository.unsubscribe(&topic_id, &recipient).await
+    }
+
+    async fn list_subscribers(
+        &self,
+        topic_id: String,
+        after: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<TopicSubscription>, ApplicationError> {
+        self.topic_repository.get(&topic_id).await?;
+        let subscription_entities = self.topic_repository.get_subscriptions(&topic_id, after, limit).await?;
+        Ok(subscription_entities.into_iter().map(|entity| entity.into()).collect())
+    }
+
+    async fn list_subscriptions(&self, recipient: String) -> Result<Vec<TopicSubscription>, ApplicationError> {
+        let subscription_entities = self.topic_repository.get_subscriptions_of(&recipient).await?;
+        Ok(subscription_entities.into_iter().map(|entity| entity.into()).collect())
+    }
+
+    async fn publish(&self, command: PublishToTopicCommand) -> Result<TopicPublication, ApplicationError> {
+        let category = resolve_category(&self.preference_settings, command.category)?;
+        self.topic_repository.get(&command.topic_id).await?;
+        let total_recipients = self.topic_repository.count_subscriptions(&command.topic_id).await?;
+
+        let publication = TopicPublication::new(command.topic_id, command.message, category, command.priority, total_recipients);
+        let publication_entity: TopicPublicationEntity = publication.clone().into();
+        self.publication_repository.save(&publication_entity).await?;
+        Ok(publication)
+    }
+
+    async fn get_publication(&self, topic_id: String, uuid: String) -> Result<TopicPublication, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        let publication: TopicPublication = self.publication_repository.get(&parsed_uuid).await?.into();
+        if publication.topic_id != topic_id {
+            return Err(ApplicationError::NotFound(format!("Publication {} not found", uuid)));
+        }
+        Ok(publication)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::configuration::structs::CategoryDefaults;
+    use crate::domain::model::priority::Priority;
+    use crate::domain::model::topic_publication::PublicationStatus;
+    use crate::port::outgoing::persistence::topic_publication_repository_port::MockTopicPublicationRepositoryPort;
+    use crate::port::outgoing::persistence::topic_repository_port::MockTopicRepositoryPort;
+    use std::collections::HashMap;
+
+    fn service(
+        topic_repository: MockTopicRepositoryPort,
+        publication_repository: MockTopicPublicationRepositoryPort,
+    ) -> TopicServiceImplementation {
+        let preference_settings = PreferenceSettings {
+            categories: HashMap::from([
+                ("general".to_string(), CategoryDefaults { email: true, push: true, sms: false }),
+            ]),
+        };
+        TopicServiceImplementation::new(Arc::new(topic_repository), Arc::new(publication_repository), preference_settings)
+    }
+
+    fn existing_topic(topic_repository: &mut MockTopicRepositoryPort) {
+        topic_repository.expect_get()
+            .returning(|id| Ok(Topic::new(id.to_string(), None).into()));
+    }
+
+    #[tokio::test]
+    async fn test_create_topic_success() {
+        let mut topic_repository = MockTopicRepositoryPort::new();
+        topic_repository.expect_get()
+            .returning(|id| Err(ApplicationError::NotFound(id.to_string())));
+        topic_repository.expect_save()
+            .times(1)
+            .returning(|topic| Ok(topic.clone()));
+
+        let result = service(topic_repository, MockTopicPublicationRepositoryPort::new())
+            .create_topic("project-x".to_string(), Some("Project X".to_string())).await
+            .unwrap();
+
+        assert_eq!(result.id, "project-x");
+    }
+
+    #[tokio::test]
+    async fn test_create_topic_conflict_error() {
+        let mut topic_repository = MockTopicRepositoryPort::new();
+        existing_topic(&mut topic_repository);
+        topic_repository.expect_save().never();
+
+        let result = service(topic_repository, MockTopicPublicationRepositoryPort::new())
+            .create_topic("project-x".to_string(), None).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_unknown_topic_error() {
+        let mut topic_repository = MockTopicRepositoryPort::new();
+        topic_repository.expect_get()
+            .returning(|id| Err(ApplicationError::NotFound(id.to_string())));
+        topic_repository.expect_subscribe().never();
+
+        let result = service(topic_repository, MockTopicPublicationRepositoryPort::new())
+            .subscribe("project-x".to_string(), "alice".to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_publish_success() {
+        let mut topic_repository = MockTopicRepositoryPort::new();
+        existing_topic(&mut topic_repository);
+        topic_repository.expect_count_subscriptions()
+            .returning(|_| Ok(1_000_000));
+
+        let mut publication_repository = MockTopicPublicationRepositoryPort::new();
+        publication_repository.expect_save()
+            .withf(|publication| publication.status == PublicationStatus::Pending && publication.total_recipients == 1_000_000)
+            .times(1)
+            .returning(|publication| Ok(publication.clone()));
+
+        let command = PublishToTopicCommand {
+            topic_id: "project-x".to_string(),
+            message: "Release 1.0 is out".to_string(),
+            category: None,
+            priority: Priority::Normal,
+        };
+        let result = service(topic_repository, publication_repository).publish(command).await.unwrap();
+
+        assert_eq!(result.category, "general");
+        assert_eq!(result.processed_recipients, 0);
+    }
+
+    #[tokio::test]
+    async fn test_publish_unknown_category_error() {
+        let mut publication_repository = MockTopicPublicationRepositoryPort::new();
+        publication_repository.expect_save().never();
+
+        let command = PublishToTopicCommand {
+            topic_id: "project-x".to_string(),
+            message: "message".to_string(),
+            category: Some("unknown".to_string()),
+            priority: Priority::Normal,
+        };
+        let result = service(MockTopicRepositoryPort::new(), publication_repository).publish(command).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_publication_of_other_topic_error() {
+        let publication = TopicPublication::new(
+            "project-y".to_string(), "message".to_string(), "general".to_string(), Priority::Normal, 0,
+        );
+        let uuid = publication.uuid.to_string();
+        let mut publication_repository = MockTopicPublicationRepositoryPort::new();
+        publication_repository.expect_get()
+            .returning(move |_| Ok(publication.clone().into()));
+
+        let result = service(MockTopicRepositoryPort::new(), publication_repository)
+            .get_publication("project-x".to_string(), uuid).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+}
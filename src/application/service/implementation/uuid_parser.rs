@@ -0,0 +1,6 @@
+use crate::application::error::ApplicationError;
+use uuid::Uuid;
+
+pub fn parse_uuid(uuid: &str) -> Result<Uuid, ApplicationError> {
+    Uuid::parse_str(uuid).map_err(|_| ApplicationError::BadRequest(format!("{} is not a valid uuid", uuid)))
+}
@@ -0,0 +1,303 @@
+use crate::adapter::outgoing::persistence::entity::webhook_subscription_entity::WebhookSubscriptionEntity;
+use crate::application::error::ApplicationError;
+use crate::application::service::implementation::uuid_parser::parse_uuid;
+use crate::application::service::webhook_service::WebhookServicePort;
+use crate::domain::model::digest::Digest;
+use crate::domain::model::notification_event::NotificationEventType;
+use crate::domain::model::preference::Channel;
+use crate::domain::model::webhook_delivery_attempt::WebhookDeliveryAttempt;
+use crate::domain::model::webhook_subscription::WebhookSubscription;
+use crate::port::outgoing::persistence::digest_repository_port::DigestRepositoryPort;
+use crate::port::outgoing::persistence::webhook_delivery_attempt_repository_port::WebhookDeliveryAttemptRepositoryPort;
+use crate::port::outgoing::persistence::webhook_subscription_repository_port::WebhookSubscriptionRepositoryPort;
+use async_trait::async_trait;
+use reqwest::Url;
+use std::net::IpAddr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct WebhookServiceImplementation {
+    subscription_repository: Arc<dyn WebhookSubscriptionRepositoryPort>,
+    delivery_attempt_repository: Arc<dyn WebhookDeliveryAttemptRepositoryPort>,
+    digest_repository: Arc<dyn DigestRepositoryPort>,
+}
+
+impl WebhookServiceImplementation {
+    pub fn new(
+        subscription_repository: Arc<dyn WebhookSubscriptionRepositoryPort>,
+        delivery_attempt_repository: Arc<dyn WebhookDeliveryAttemptRepositoryPort>,
+        digest_repository: Arc<dyn DigestRepositoryPort>,
+    ) -> Self {
+        Self { subscription_repository, delivery_attempt_repository, digest_repository }
+    }
+}
+
+fn validate_url(url: &str) -> Result<(), ApplicationError> {
+    let parsed_url = match Url::parse(url) {
+        Ok(parsed_url) if parsed_url.scheme() == "http" || parsed_url.scheme() == "https" => parsed_url,
+        _ => return Err(ApplicationError::BadRequest(format!("{} is not a valid http(s) url", url))),
+    };
+    if parsed_url.host().is_none_or(|host| is_internal(&host.to_string())) {
+        return Err(ApplicationError::BadRequest(format!("{} does not point to a public address", url)));
+    }
+    Ok(())
+}
+
+/// Whether the host names this machine or its private network, which deliveries must not be made to. Only literal
+/// addresses and `localhost` are recognised; names are not resolved.
+fn is_internal(host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']').to_ascii_lowercase();
+    if host == "localhost" || host.ends_with(".localhost") {
+        return true;
+    }
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(address)) => {
+            address.is_loopback() || address.is_private() || address.is_link_local() || address.is_unspecified()
+                || address.is_broadcast()
+        }
+        Ok(IpAddr::V6(address)) => match address.to_ipv4_mapped() {
+            Some(address) => is_internal(&address.to_string()),
+            None => address.is_loopback() || address.is_unspecified() || address.is_unique_local()
+                || address.is_unicast_link_local(),
+        },
+        Err(_) => false,
+    }
+}
+
+#[async_trait]
+impl WebhookServicePort for WebhookServiceImplementation {
+    async fn create_subscription(
+        &self,
+        url: String,
+        secret: Option<String>,
+        events: Vec<NotificationEventType>,
+        channel: Option<Channel>,
+    ) -> Result<WebhookSubscription, ApplicationError> {
+        validate_url(&url)?;
+        if events.contains(&NotificationEventType::Digest) {
+            return Err(ApplicationError::BadRequest(
+                "digest cannot be subscribed to, digests are sent to subscriptions receiving created events".to_string(),
+            ));
+        }
+
+        let secret = match secret {
+            Some(secret) if secret.is_empty() => return Err(ApplicationError::BadRequest("Secret cannot be empty".to_string())),
+            Some(secret) => secret,
+            None => Uuid::new_v4().simple().to_string(),
+        };
+
+        let subscription = WebhookSubscription { channel, ..WebhookSubscription::new(url, secret, events) };
+        let subscription_entity: WebhookSubscriptionEntity = subscription.clone().into();
+        self.subscription_repository.save(&subscription_entity).await?;
+        Ok(subscription)
+    }
+
+    async fn get_subscription(&self, uuid: String) -> Result<WebhookSubscription, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        Ok(self.subscription_repository.get(&parsed_uuid).await?.into())
+    }
+
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, ApplicationError> {
+        let subscription_entities = self.subscription_repository.get_all().await?;
+        Ok(subscription_entities.into_iter().map(|entity| entity.into()).collect())
+    }
+
+    async fn delete_subscription(&self, uuid: String) -> Result<(), ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        self.subscription_repository.delete(&parsed_uuid).await
+    }
+
+    async fn list_delivery_attempts(&self, uuid: String) -> Result<Vec<WebhookDeliveryAttempt>, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        self.subscription_repository.get(&parsed_uuid).await?;
+        let attempt_entities = self.delivery_attempt_repository.get_by_subscription(&parsed_uuid).await?;
+        Ok(attempt_entities.into_iter().map(|entity| entity.into()).collect())
+    }
+
+    async fn list_digests(&self, uuid: String) -> Result<Vec<Digest>, ApplicationError> {
+        let parsed_uuid = parse_uuid(&uuid)?;
+        self.subscription_repository.get(&parsed_uuid).await?;
+        let digest_entities = self.digest_repository.get_by_subscription(&parsed_uuid).await?;
+        Ok(digest_entities.into_iter().map(|entity| entity.into()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::outgoing::persistence::entity::digest_entity::DigestEntity;
+    use crate::adapter::outgoing::persistence::entity::webhook_delivery_attempt_entity::WebhookDeliveryAttemptEntity;
+    use crate::port::outgoing::persistence::digest_repository_port::MockDigestRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_delivery_attempt_repository_port::MockWebhookDeliveryAttemptRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_subscription_repository_port::MockWebhookSubscriptionRepositoryPort;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    fn service(
+        subscription_repository: MockWebhookSubscriptionRepositoryPort,
+        delivery_attempt_repository: MockWebhookDeliveryAttemptRepositoryPort,
+    ) -> WebhookServiceImplementation {
+        WebhookServiceImplementation::new(
+            Arc::new(subscription_repository),
+            Arc::new(delivery_attempt_repository),
+            Arc::new(MockDigestRepositoryPort::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_subscription_success() {
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_save()
+            .times(1)
+            .returning(|subscription| Ok(subscription.clone()));
+
+        let result = service(subscription_repository, MockWebhookDeliveryAttemptRepositoryPort::new())
+            .create_subscription(
+                "https://example.com/hook".to_string(),
+                Some("secret".to_string()),
+                vec![NotificationEventType::Created],
+                Some(Channel::Email),
+            ).await
+            .unwrap();
+
+        assert_eq!(result.url, "https://example.com/hook");
+        assert_eq!(result.secret, "secret");
+        assert_eq!(result.events, vec![NotificationEventType::Created]);
+        assert_eq!(result.channel, Some(Channel::Email));
+    }
+
+    #[tokio::test]
+    async fn test_create_subscription_generates_secret() {
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_save()
+            .returning(|subscription| Ok(subscription.clone()));
+
+        let result = service(subscription_repository, MockWebhookDeliveryAttemptRepositoryPort::new())
+            .create_subscription("https://example.com/hook".to_string(), None, vec![], None).await
+            .unwrap();
+
+        assert!(!result.secret.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_subscription_invalid_url_error() {
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_save().never();
+
+        let result = service(subscription_repository, MockWebhookDeliveryAttemptRepositoryPort::new())
+            .create_subscription("ftp://example.com".to_string(), None, vec![], None).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_subscription_internal_address_error() {
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_save().never();
+        let service = service(subscription_repository, MockWebhookDeliveryAttemptRepositoryPort::new());
+
+        for url in [
+            "http://localhost:8080/hook",
+            "http://127.0.0.1/hook",
+            "http://10.0.0.5/hook",
+            "http://192.168.1.1/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/hook",
+            "http://[fd00::1]/hook",
+            "http://[::ffff:127.0.0.1]/hook",
+        ] {
+            let result = service.create_subscription(url.to_string(), None, vec![], None).await;
+
+            assert!(matches!(result, Err(ApplicationError::BadRequest(_))), "{url} was accepted");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_subscription_digest_event_error() {
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_save().never();
+
+        let result = service(subscription_repository, MockWebhookDeliveryAttemptRepositoryPort::new())
+            .create_subscription("https://example.com/hook".to_string(), None, vec![NotificationEventType::Digest], None).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_subscription_invalid_uuid_error() {
+        let result = service(MockWebhookSubscriptionRepositoryPort::new(), MockWebhookDeliveryAttemptRepositoryPort::new())
+            .delete_subscription("not-a-uuid".to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_delivery_attempts_success() {
+        let subscription = WebhookSubscription::new("https://example.com/hook".to_string(), "secret".to_string(), vec![]);
+        let subscription_uuid = subscription.uuid;
+        let subscription_entity: WebhookSubscriptionEntity = subscription.into();
+        let attempt_entity: WebhookDeliveryAttemptEntity = WebhookDeliveryAttempt::new(
+            subscription_uuid, Uuid::new_v4(), NotificationEventType::Seen, 1, Some(200), None,
+        ).into();
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get()
+            .returning(move |_| Ok(subscription_entity.clone()));
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_get_by_subscription()
+            .times(1)
+            .returning(move |_| Ok(vec![attempt_entity.clone()]));
+
+        let result = service(subscription_repository, delivery_attempt_repository)
+            .list_delivery_attempts(subscription_uuid.to_string()).await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].subscription_uuid, subscription_uuid);
+    }
+
+    #[tokio::test]
+    async fn test_list_delivery_attempts_unknown_subscription_error() {
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get()
+            .returning(|uuid| Err(ApplicationError::NotFound(uuid.to_string())));
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_get_by_subscription().never();
+
+        let result = service(subscription_repository, delivery_attempt_repository)
+            .list_delivery_attempts(Uuid::new_v4().to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_digests_success() {
+        let subscription = WebhookSubscription::new("https://example.com/hook".to_string(), "secret".to_string(), vec![]);
+        let subscription_uuid = subscription.uuid;
+        let subscription_entity: WebhookSubscriptionEntity = subscription.into();
+        let digest_entity: DigestEntity = Digest::new(subscription_uuid, "recipient".to_string(), Utc::now()).into();
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get()
+            .returning(move |_| Ok(subscription_entity.clone()));
+
+        let mut digest_repository = MockDigestRepositoryPort::new();
+        digest_repository.expect_get_by_subscription()
+            .with(eq(subscription_uuid))
+            .times(1)
+            .returning(move |_| Ok(vec![digest_entity.clone()]));
+
+        let result = WebhookServiceImplementation::new(
+            Arc::new(subscription_repository),
+            Arc::new(MockWebhookDeliveryAttemptRepositoryPort::new()),
+            Arc::new(digest_repository),
+        )
+            .list_digests(subscription_uuid.to_string()).await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].subscription_uuid, subscription_uuid);
+    }
+}
@@ -1,3 +1,11 @@
+pub mod authentication_service;
+pub mod broadcast_service;
 pub mod clock_service;
+pub mod delivery_policy_service;
 pub mod notification_service;
+pub mod notification_stream_service;
+pub mod notification_template_service;
+pub mod recipient_profile_service;
+pub mod topic_service;
+pub mod webhook_service;
 pub mod implementation;
@@ -1,14 +1,163 @@
+use crate::application::command::create_notification_command::CreateNotificationCommand;
+use crate::application::command::create_notification_from_template_command::CreateNotificationFromTemplateCommand;
+use crate::application::command::update_notification_command::UpdateNotificationCommand;
 use crate::application::error::ApplicationError;
 use crate::domain::model::notification::Notification;
+use crate::domain::model::notification_filter::NotificationFilter;
+use crate::domain::model::notification_group::NotificationGroup;
+use crate::domain::model::notification_history::{ChangeSource, NotificationHistoryEntry};
+use crate::domain::model::notification_search::SearchHit;
+use crate::domain::model::state_change::StateChange;
+use crate::domain::model::tag_count::TagCount;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::automock;
 
 #[automock]
 #[async_trait]
 pub trait NotificationServicePort: Send + Sync {
-    async fn create_notification(&self, message: String) -> Result<Notification, ApplicationError>;
-    async fn get_notification(&self, uuid: String) -> Result<Notification, ApplicationError>;
-    async fn list_notifications(&self) -> Result<Vec<Notification>, ApplicationError>;
-    async fn mark_as_seen(&self, uuid: String) -> Result<bool, ApplicationError>;
-    async fn mark_as_deleted(&self, uuid: String) -> Result<bool, ApplicationError>;
+    async fn create_notification(&self, command: CreateNotificationCommand) -> Result<Notification, ApplicationError>;
+    async fn create_notification_from_template(
+        &self,
+        command: CreateNotificationFromTemplateCommand,
+    ) -> Result<Notification, ApplicationError>;
+    /// The notification if it is the `recipient`'s, or a broadcast with their state of it.
+    async fn get_notification_of(&self, uuid: String, recipient: String) -> Result<Notification, ApplicationError>;
+    /// The recipient's inbox: their own notifications mixed with the broadcasts, by creation date. Notifications are
+    /// localized preferring the recipient's profile locale over `accepted_locales`, which are in order of preference.
+    /// Pinned notifications come first, and snoozed ones are left out along with those not matching the `filter`.
+    /// Listing the changes since a time, snoozed notifications are kept, so that clients syncing incrementally learn
+    /// they were snoozed.
+    async fn list_notifications(
+        &self,
+        recipient: String,
+        filter: NotificationFilter,
+        accepted_locales: Vec<String>,
+    ) -> Result<Vec<Notification>, ApplicationError>;
+    /// The recipient's inbox with the notifications sharing a group key collapsed into one entry each, leaving out
+    /// those deleted or archived.
+    async fn list_notification_groups(
+        &self,
+        recipient: String,
+        accepted_locales: Vec<String>,
+    ) -> Result<Vec<NotificationGroup>, ApplicationError>;
+    /// The recipient's notifications holding every word of `query`, most relevant first, leaving out those deleted,
+    /// snoozed or not matching the `filter`. Broadcasts are not searched.
+    async fn search_notifications(
+        &self,
+        recipient: String,
+        query: String,
+        filter: NotificationFilter,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>, ApplicationError>;
+    /// Tags used in the recipient's inbox, by how many notifications neither deleted nor archived have them.
+    async fn count_tags(&self, recipient: String) -> Result<Vec<TagCount>, ApplicationError>;
+    /// Unseen notifications and broadcasts in the recipient's inbox, which archived notifications are not part of.
+    async fn count_unread(&self, recipient: String) -> Result<usize, ApplicationError>;
+    /// The notification must be the `recipient`'s or a broadcast, whose state is then kept for them alone and, unlike
+    /// that of notifications, publishes no event. Like every change below, it is recorded in the
+    /// notification's history as made by the `recipient` through `source`. With an `expected_version`, the change
+    /// is refused unless the notification is still at that version, which broadcasts never are as they have none.
+    /// Marking a notification already seen changes, saves and publishes nothing, and returns it as it is.
+    async fn mark_as_seen(
+        &self,
+        uuid: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<StateChange, ApplicationError>;
+    /// Like `mark_as_seen`, also marking the notification as seen when deleting it.
+    async fn mark_as_deleted(
+        &self,
+        uuid: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<StateChange, ApplicationError>;
+    /// Records the action taken, publishing an `action_taken` event for the webhooks handling callbacks.
+    async fn take_action(
+        &self,
+        uuid: String,
+        action_id: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError>;
+    /// Hides the notification from listings and counts until `until`, which must be in the future.
+    async fn snooze(
+        &self,
+        uuid: String,
+        until: DateTime<Utc>,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError>;
+    /// Pins or unpins the notification, publishing a `pinned` or `unpinned` event.
+    async fn set_pinned(
+        &self,
+        uuid: String,
+        pinned: bool,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError>;
+    /// Moves the notification to the archive or back to the inbox, publishing an `archived` or `unarchived` event.
+    async fn set_archived(
+        &self,
+        uuid: String,
+        archived: bool,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError>;
+    /// Tags the notification, publishing a `tagged` event unless it already had the tag.
+    async fn add_tag(
+        &self,
+        uuid: String,
+        tag: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError>;
+    /// Untags the notification, publishing an `untagged` event unless it did not have the tag.
+    async fn remove_tag(
+        &self,
+        uuid: String,
+        tag: String,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError>;
+    /// Applies every change of the command that alters the notification in a single save, publishing an event of
+    /// each change's type, and returns the notification as updated. Broadcasts can only be marked as seen.
+    async fn update_notification(
+        &self,
+        uuid: String,
+        command: UpdateNotificationCommand,
+        recipient: String,
+        expected_version: Option<u64>,
+        source: ChangeSource,
+    ) -> Result<Notification, ApplicationError>;
+    /// Brings back the notifications whose snooze ended by `until`, publishing a `resurfaced` event for each, and
+    /// returns how many.
+    async fn resurface_snoozed(&self, until: DateTime<Utc>, limit: usize) -> Result<usize, ApplicationError>;
+    /// Marks every notification of the recipient's group, publishing an event for each, and returns how many changed.
+    async fn mark_group_as_seen(
+        &self,
+        recipient: String,
+        group_key: String,
+        source: ChangeSource,
+    ) -> Result<usize, ApplicationError>;
+    async fn mark_group_as_deleted(
+        &self,
+        recipient: String,
+        group_key: String,
+        source: ChangeSource,
+    ) -> Result<usize, ApplicationError>;
+    /// Every change made to the notification, oldest first; for a broadcast, those made by the `recipient`.
+    async fn get_history(
+        &self,
+        uuid: String,
+        recipient: String,
+    ) -> Result<Vec<NotificationHistoryEntry>, ApplicationError>;
 }
\ No newline at end of file
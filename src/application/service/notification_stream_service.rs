@@ -0,0 +1,14 @@
+use crate::domain::model::notification_event::NotificationEvent;
+use mockall::automock;
+use std::pin::Pin;
+use tokio_stream::Stream;
+
+pub type NotificationEventStream = Pin<Box<dyn Stream<Item = NotificationEvent> + Send>>;
+
+#[automock]
+pub trait NotificationStreamServicePort: Send + Sync {
+    /// Streams the events of `recipient`'s notifications, first replaying the ones published after `last_event_id`.
+    /// The stream ends if the subscriber falls so far behind that events are lost, for it to resubscribe from the
+    /// last event it received.
+    fn subscribe(&self, recipient: String, last_event_id: Option<String>) -> NotificationEventStream;
+}
@@ -0,0 +1,43 @@
+use crate::application::error::ApplicationError;
+use crate::domain::model::notification_template::{NotificationTemplate, RenderedTemplate, TemplateFormat};
+use async_trait::async_trait;
+use mockall::automock;
+use std::collections::HashMap;
+
+#[automock]
+#[async_trait]
+pub trait NotificationTemplateServicePort: Send + Sync {
+    async fn create_template(
+        &self,
+        id: String,
+        locale: String,
+        title: String,
+        body: String,
+    ) -> Result<NotificationTemplate, ApplicationError>;
+    async fn get_template(&self, id: String) -> Result<NotificationTemplate, ApplicationError>;
+    async fn list_templates(&self) -> Result<Vec<NotificationTemplate>, ApplicationError>;
+    async fn update_template(
+        &self,
+        id: String,
+        locale: String,
+        title: String,
+        body: String,
+    ) -> Result<NotificationTemplate, ApplicationError>;
+    async fn delete_template(&self, id: String) -> Result<(), ApplicationError>;
+    async fn set_variant(
+        &self,
+        id: String,
+        locale: String,
+        title: String,
+        body: String,
+    ) -> Result<NotificationTemplate, ApplicationError>;
+    async fn delete_variant(&self, id: String, locale: String) -> Result<NotificationTemplate, ApplicationError>;
+    /// Renders the variant of `locale`, or of its base language, falling back to the template's own content.
+    async fn render_template(
+        &self,
+        id: String,
+        variables: HashMap<String, String>,
+        format: TemplateFormat,
+        locale: Option<String>,
+    ) -> Result<RenderedTemplate, ApplicationError>;
+}
@@ -0,0 +1,29 @@
+use crate::application::error::ApplicationError;
+use crate::domain::model::digest::DigestFrequency;
+use crate::domain::model::preference::{ChannelPreference, EffectivePreference};
+use crate::domain::model::quiet_hours::QuietHours;
+use crate::domain::model::recipient_profile::RecipientProfile;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait RecipientProfileServicePort: Send + Sync {
+    async fn get_profile(&self, recipient: String) -> Result<RecipientProfile, ApplicationError>;
+    async fn update_profile(&self, recipient: String, locale: Option<String>) -> Result<RecipientProfile, ApplicationError>;
+    /// Every configured category on every channel, by category then channel.
+    async fn get_preferences(&self, recipient: String) -> Result<Vec<EffectivePreference>, ApplicationError>;
+    async fn update_preferences(
+        &self,
+        recipient: String,
+        preferences: Vec<ChannelPreference>,
+    ) -> Result<Vec<EffectivePreference>, ApplicationError>;
+    async fn get_quiet_hours(&self, recipient: String) -> Result<QuietHours, ApplicationError>;
+    async fn update_quiet_hours(&self, recipient: String, quiet_hours: QuietHours) -> Result<QuietHours, ApplicationError>;
+    async fn get_digest_frequency(&self, recipient: String) -> Result<Option<DigestFrequency>, ApplicationError>;
+    async fn update_digest_frequency(
+        &self,
+        recipient: String,
+        digest_frequency: Option<DigestFrequency>,
+    ) -> Result<Option<DigestFrequency>, ApplicationError>;
+}
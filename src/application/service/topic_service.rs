@@ -0,0 +1,28 @@
+use crate::application::command::publish_to_topic_command::PublishToTopicCommand;
+use crate::application::error::ApplicationError;
+use crate::domain::model::topic::{Topic, TopicSubscription};
+use crate::domain::model::topic_publication::TopicPublication;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait TopicServicePort: Send + Sync {
+    async fn create_topic(&self, id: String, description: Option<String>) -> Result<Topic, ApplicationError>;
+    async fn get_topic(&self, id: String) -> Result<Topic, ApplicationError>;
+    async fn list_topics(&self) -> Result<Vec<Topic>, ApplicationError>;
+    async fn delete_topic(&self, id: String) -> Result<(), ApplicationError>;
+    async fn subscribe(&self, topic_id: String, recipient: String) -> Result<TopicSubscription, ApplicationError>;
+    async fn unsubscribe(&self, topic_id: String, recipient: String) -> Result<(), ApplicationError>;
+    /// Subscribers of the topic by recipient, starting after `after` when given.
+    async fn list_subscribers(
+        &self,
+        topic_id: String,
+        after: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<TopicSubscription>, ApplicationError>;
+    async fn list_subscriptions(&self, recipient: String) -> Result<Vec<TopicSubscription>, ApplicationError>;
+    /// Accepts the notification for fan-out to the topic subscribers, which happens in the background.
+    async fn publish(&self, command: PublishToTopicCommand) -> Result<TopicPublication, ApplicationError>;
+    async fn get_publication(&self, topic_id: String, uuid: String) -> Result<TopicPublication, ApplicationError>;
+}
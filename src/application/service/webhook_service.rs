@@ -0,0 +1,25 @@
+use crate::application::error::ApplicationError;
+use crate::domain::model::digest::Digest;
+use crate::domain::model::notification_event::NotificationEventType;
+use crate::domain::model::preference::Channel;
+use crate::domain::model::webhook_delivery_attempt::WebhookDeliveryAttempt;
+use crate::domain::model::webhook_subscription::WebhookSubscription;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait WebhookServicePort: Send + Sync {
+    async fn create_subscription(
+        &self,
+        url: String,
+        secret: Option<String>,
+        events: Vec<NotificationEventType>,
+        channel: Option<Channel>,
+    ) -> Result<WebhookSubscription, ApplicationError>;
+    async fn get_subscription(&self, uuid: String) -> Result<WebhookSubscription, ApplicationError>;
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, ApplicationError>;
+    async fn delete_subscription(&self, uuid: String) -> Result<(), ApplicationError>;
+    async fn list_delivery_attempts(&self, uuid: String) -> Result<Vec<WebhookDeliveryAttempt>, ApplicationError>;
+    async fn list_digests(&self, uuid: String) -> Result<Vec<Digest>, ApplicationError>;
+}
@@ -0,0 +1,201 @@
+use crate::application::configuration::structs::SchedulerSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::clock_service::ClockPort;
+use crate::application::worker::webhook_delivery_worker::WebhookDeliveryWorker;
+use crate::domain::model::scheduled_delivery::ScheduledDelivery;
+use crate::port::outgoing::persistence::scheduled_delivery_repository_port::ScheduledDeliveryRepositoryPort;
+use crate::port::outgoing::persistence::webhook_subscription_repository_port::WebhookSubscriptionRepositoryPort;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Hands deferred deliveries back to the webhook delivery worker once they are due.
+///
+/// The delivery policy is applied again on release, so a delivery still falling in quiet hours, for instance because
+/// the recipient extended them meanwhile, is scheduled anew.
+pub struct DeliverySchedulerWorker {
+    scheduled_delivery_repository: Arc<dyn ScheduledDeliveryRepositoryPort>,
+    subscription_repository: Arc<dyn WebhookSubscriptionRepositoryPort>,
+    delivery_worker: Arc<WebhookDeliveryWorker>,
+    clock: Arc<dyn ClockPort>,
+    settings: SchedulerSettings,
+}
+
+impl DeliverySchedulerWorker {
+    pub fn new(
+        scheduled_delivery_repository: Arc<dyn ScheduledDeliveryRepositoryPort>,
+        subscription_repository: Arc<dyn WebhookSubscriptionRepositoryPort>,
+        delivery_worker: Arc<WebhookDeliveryWorker>,
+        clock: Arc<dyn ClockPort>,
+        settings: SchedulerSettings,
+    ) -> Self {
+        Self { scheduled_delivery_repository, subscription_repository, delivery_worker, clock, settings }
+    }
+
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(Duration::from_millis(self.settings.poll_interval_milliseconds));
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.release().await {
+                eprintln!("Failed to release scheduled deliveries: {}", error);
+            }
+        }
+    }
+
+    /// Dispatches the deliveries due by now, earliest first, and returns how many were released.
+    /// A delivery is removed only after being dispatched, so it is retried on the next poll when dispatching fails.
+    pub async fn release(&self) -> Result<usize, ApplicationError> {
+        let mut released = 0;
+        loop {
+            let scheduled_deliveries = self.scheduled_delivery_repository
+                .get_due(self.clock.now(), self.settings.batch_size)
+                .await?;
+            if scheduled_deliveries.is_empty() {
+                return Ok(released);
+            }
+
+            for scheduled_delivery in scheduled_deliveries.into_iter().map(ScheduledDelivery::from) {
+                match self.subscription_repository.get(&scheduled_delivery.subscription_uuid).await {
+                    Ok(subscription) => {
+                        self.delivery_worker.dispatch_to(vec![subscription.into()], &scheduled_delivery.event).await?;
+                    }
+                    Err(ApplicationError::NotFound(_)) => {}
+                    Err(error) => return Err(error),
+                }
+                self.scheduled_delivery_repository.delete(&scheduled_delivery.uuid).await?;
+                released += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::outgoing::persistence::entity::scheduled_delivery_entity::ScheduledDeliveryEntity;
+    use crate::adapter::outgoing::persistence::entity::webhook_subscription_entity::WebhookSubscriptionEntity;
+    use crate::application::configuration::structs::WebhookSettings;
+    use crate::application::service::clock_service::MockClockPort;
+    use crate::application::service::delivery_policy_service::{DeliveryDecision, MockDeliveryPolicyServicePort};
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+    use crate::domain::model::webhook_subscription::WebhookSubscription;
+    use crate::port::outgoing::persistence::digest_repository_port::MockDigestRepositoryPort;
+    use crate::port::outgoing::persistence::scheduled_delivery_repository_port::MockScheduledDeliveryRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_delivery_attempt_repository_port::MockWebhookDeliveryAttemptRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_subscription_repository_port::MockWebhookSubscriptionRepositoryPort;
+    use crate::port::outgoing::webhook::webhook_sender_port::MockWebhookSenderPort;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+    use mockall::Sequence;
+    use uuid::Uuid;
+
+    fn scheduled_delivery(subscription_uuid: Uuid) -> ScheduledDeliveryEntity {
+        let event = NotificationEvent::new(
+            NotificationEventType::Created,
+            Notification::new("recipient".to_string(), "message".to_string()),
+        );
+        ScheduledDelivery::new(subscription_uuid, event, Utc::now()).into()
+    }
+
+    fn scheduler(
+        scheduled_delivery_repository: MockScheduledDeliveryRepositoryPort,
+        subscription_repository: MockWebhookSubscriptionRepositoryPort,
+        webhook_sender: MockWebhookSenderPort,
+    ) -> DeliverySchedulerWorker {
+        let scheduled_delivery_repository = Arc::new(scheduled_delivery_repository);
+        let subscription_repository = Arc::new(subscription_repository);
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save().returning(|attempt| Ok(attempt.clone()));
+        let mut delivery_policy = MockDeliveryPolicyServicePort::new();
+        delivery_policy.expect_decide().returning(|_, _| Ok(DeliveryDecision::Deliver));
+        let delivery_worker = Arc::new(WebhookDeliveryWorker::new(
+            subscription_repository.clone(),
+            Arc::new(delivery_attempt_repository),
+            scheduled_delivery_repository.clone(),
+            Arc::new(MockDigestRepositoryPort::new()),
+            Arc::new(webhook_sender),
+            Arc::new(delivery_policy),
+            WebhookSettings { max_attempts: 1, initial_backoff_milliseconds: 1, max_backoff_milliseconds: 1, timeout_seconds: 1 },
+        ));
+
+        let mut clock = MockClockPort::new();
+        clock.expect_now().returning(Utc::now);
+
+        DeliverySchedulerWorker::new(
+            scheduled_delivery_repository,
+            subscription_repository,
+            delivery_worker,
+            Arc::new(clock),
+            SchedulerSettings { poll_interval_milliseconds: 1, batch_size: 10 },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_release_dispatches_due_deliveries() {
+        let subscription: WebhookSubscriptionEntity =
+            WebhookSubscription::new("http://localhost/hook".to_string(), "secret".to_string(), vec![]).into();
+        let scheduled = scheduled_delivery(subscription.uuid);
+        let scheduled_uuid = scheduled.uuid;
+
+        let mut sequence = Sequence::new();
+        let mut scheduled_delivery_repository = MockScheduledDeliveryRepositoryPort::new();
+        scheduled_delivery_repository.expect_get_due()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_, _| Ok(vec![scheduled.clone()]));
+        scheduled_delivery_repository.expect_delete()
+            .with(eq(scheduled_uuid))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(()));
+        scheduled_delivery_repository.expect_get_due()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(vec![]));
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get()
+            .returning(move |_| Ok(subscription.clone()));
+
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post()
+            .times(1)
+            .returning(|_, _, _| Ok(200));
+
+        let scheduler = scheduler(scheduled_delivery_repository, subscription_repository, webhook_sender);
+
+        assert_eq!(scheduler.release().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_release_drops_deliveries_of_deleted_subscriptions() {
+        let scheduled = scheduled_delivery(Uuid::new_v4());
+
+        let mut sequence = Sequence::new();
+        let mut scheduled_delivery_repository = MockScheduledDeliveryRepositoryPort::new();
+        scheduled_delivery_repository.expect_get_due()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_, _| Ok(vec![scheduled.clone()]));
+        scheduled_delivery_repository.expect_delete()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(()));
+        scheduled_delivery_repository.expect_get_due()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(vec![]));
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get()
+            .returning(|uuid| Err(ApplicationError::NotFound(uuid.to_string())));
+
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post().never();
+
+        let scheduler = scheduler(scheduled_delivery_repository, subscription_repository, webhook_sender);
+
+        assert_eq!(scheduler.release().await.unwrap(), 1);
+    }
+}
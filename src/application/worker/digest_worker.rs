@@ -0,0 +1,190 @@
+use crate::application::configuration::structs::DigestSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::clock_service::ClockPort;
+use crate::application::worker::webhook_delivery_worker::WebhookDeliveryWorker;
+use crate::domain::model::digest::Digest;
+use crate::port::outgoing::persistence::digest_repository_port::DigestRepositoryPort;
+use crate::port::outgoing::persistence::webhook_subscription_repository_port::WebhookSubscriptionRepositoryPort;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Sends the digests that are due as a single webhook delivery each, and closes them.
+pub struct DigestWorker {
+    digest_repository: Arc<dyn DigestRepositoryPort>,
+    subscription_repository: Arc<dyn WebhookSubscriptionRepositoryPort>,
+    delivery_worker: Arc<WebhookDeliveryWorker>,
+    clock: Arc<dyn ClockPort>,
+    settings: DigestSettings,
+}
+
+impl DigestWorker {
+    pub fn new(
+        digest_repository: Arc<dyn DigestRepositoryPort>,
+        subscription_repository: Arc<dyn WebhookSubscriptionRepositoryPort>,
+        delivery_worker: Arc<WebhookDeliveryWorker>,
+        clock: Arc<dyn ClockPort>,
+        settings: DigestSettings,
+    ) -> Self {
+        Self { digest_repository, subscription_repository, delivery_worker, clock, settings }
+    }
+
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(Duration::from_millis(self.settings.poll_interval_milliseconds));
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.send_due().await {
+                eprintln!("Failed to send digests: {}", error);
+            }
+        }
+    }
+
+    /// Sends the digests due by now, earliest first, and returns how many were closed.
+    /// Digests of deleted subscriptions are closed as failed without being sent.
+    pub async fn send_due(&self) -> Result<usize, ApplicationError> {
+        let mut sent = 0;
+        loop {
+            let digests = self.digest_repository.get_due(self.clock.now(), self.settings.batch_size).await?;
+            if digests.is_empty() {
+                return Ok(sent);
+            }
+
+            for mut digest in digests.into_iter().map(Digest::from) {
+                let delivered = match self.subscription_repository.get(&digest.subscription_uuid).await {
+                    Ok(subscription) => self.delivery_worker.deliver_digest(&subscription.into(), &digest).await,
+                    Err(ApplicationError::NotFound(_)) => false,
+                    Err(error) => return Err(error),
+                };
+                digest.complete(delivered);
+                self.digest_repository.save(&digest.into()).await?;
+                sent += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::outgoing::persistence::entity::digest_entity::DigestEntity;
+    use crate::adapter::outgoing::persistence::entity::webhook_subscription_entity::WebhookSubscriptionEntity;
+    use crate::application::configuration::structs::WebhookSettings;
+    use crate::application::service::clock_service::MockClockPort;
+    use crate::application::service::delivery_policy_service::MockDeliveryPolicyServicePort;
+    use crate::domain::model::digest::DigestStatus;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::webhook_subscription::WebhookSubscription;
+    use crate::port::outgoing::persistence::digest_repository_port::MockDigestRepositoryPort;
+    use crate::port::outgoing::persistence::scheduled_delivery_repository_port::MockScheduledDeliveryRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_delivery_attempt_repository_port::MockWebhookDeliveryAttemptRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_subscription_repository_port::MockWebhookSubscriptionRepositoryPort;
+    use crate::port::outgoing::webhook::webhook_sender_port::MockWebhookSenderPort;
+    use chrono::Utc;
+    use mockall::Sequence;
+    use uuid::Uuid;
+
+    fn digest(subscription_uuid: Uuid) -> DigestEntity {
+        let mut digest = Digest::new(subscription_uuid, "recipient".to_string(), Utc::now());
+        digest.notifications.push(Notification::new("recipient".to_string(), "message".to_string()));
+        digest.into()
+    }
+
+    fn worker(
+        digest_repository: MockDigestRepositoryPort,
+        subscription_repository: MockWebhookSubscriptionRepositoryPort,
+        webhook_sender: MockWebhookSenderPort,
+    ) -> DigestWorker {
+        let subscription_repository = Arc::new(subscription_repository);
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save().returning(|attempt| Ok(attempt.clone()));
+        let delivery_worker = Arc::new(WebhookDeliveryWorker::new(
+            subscription_repository.clone(),
+            Arc::new(delivery_attempt_repository),
+            Arc::new(MockScheduledDeliveryRepositoryPort::new()),
+            Arc::new(MockDigestRepositoryPort::new()),
+            Arc::new(webhook_sender),
+            Arc::new(MockDeliveryPolicyServicePort::new()),
+            WebhookSettings { max_attempts: 1, initial_backoff_milliseconds: 1, max_backoff_milliseconds: 1, timeout_seconds: 1 },
+        ));
+
+        let mut clock = MockClockPort::new();
+        clock.expect_now().returning(Utc::now);
+
+        DigestWorker::new(
+            Arc::new(digest_repository),
+            subscription_repository,
+            delivery_worker,
+            Arc::new(clock),
+            DigestSettings { poll_interval_milliseconds: 1, batch_size: 10 },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_send_due_delivers_and_closes_digests() {
+        let subscription: WebhookSubscriptionEntity =
+            WebhookSubscription::new("http://localhost/hook".to_string(), "secret".to_string(), vec![]).into();
+        let due = digest(subscription.uuid);
+        let digest_uuid = due.uuid;
+
+        let mut sequence = Sequence::new();
+        let mut digest_repository = MockDigestRepositoryPort::new();
+        digest_repository.expect_get_due()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_, _| Ok(vec![due.clone()]));
+        digest_repository.expect_save()
+            .withf(move |digest| digest.uuid == digest_uuid && digest.status == DigestStatus::Sent && digest.sent_at.is_some())
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|digest| Ok(digest.clone()));
+        digest_repository.expect_get_due()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(vec![]));
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get()
+            .returning(move |_| Ok(subscription.clone()));
+
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post()
+            .times(1)
+            .returning(|_, _, _| Ok(200));
+
+        let worker = worker(digest_repository, subscription_repository, webhook_sender);
+
+        assert_eq!(worker.send_due().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_due_fails_digests_of_deleted_subscriptions() {
+        let due = digest(Uuid::new_v4());
+
+        let mut sequence = Sequence::new();
+        let mut digest_repository = MockDigestRepositoryPort::new();
+        digest_repository.expect_get_due()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_, _| Ok(vec![due.clone()]));
+        digest_repository.expect_save()
+            .withf(|digest| digest.status == DigestStatus::Failed)
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|digest| Ok(digest.clone()));
+        digest_repository.expect_get_due()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(vec![]));
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get()
+            .returning(|uuid| Err(ApplicationError::NotFound(uuid.to_string())));
+
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post().never();
+
+        let worker = worker(digest_repository, subscription_repository, webhook_sender);
+
+        assert_eq!(worker.send_due().await.unwrap(), 1);
+    }
+}
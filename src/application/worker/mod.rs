@@ -0,0 +1,6 @@
+pub mod delivery_scheduler_worker;
+pub mod digest_worker;
+pub mod outbox_relay_worker;
+pub mod snooze_worker;
+pub mod topic_fan_out_worker;
+pub mod webhook_delivery_worker;
\ No newline at end of file
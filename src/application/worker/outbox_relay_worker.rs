@@ -0,0 +1,354 @@
+use crate::application::configuration::structs::OutboxSettings;
+use crate::adapter::outgoing::persistence::entity::outbox_event_entity::OutboxEventEntity;
+use crate::application::error::ApplicationError;
+use crate::application::service::clock_service::ClockService;
+use crate::application::worker::webhook_delivery_worker::WebhookDeliveryWorker;
+use crate::domain::model::notification_event::NotificationEvent;
+use crate::port::outgoing::event::notification_event_publisher_port::NotificationEventPublisherPort;
+use crate::port::outgoing::persistence::outbox_repository_port::OutboxRepositoryPort;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Hands the events written to the outbox to the webhook deliveries, publishes them to live subscribers and marks
+/// them as relayed.
+///
+/// An event is only marked once every webhook subscription has accepted it and it was published, so neither a crash
+/// nor a failure in between loses it. Until then the outbox keeps which subscriptions accepted it and whether it was
+/// published, and the next runs only retry what is missing. Delivery is at least once, as a crash can come before that
+/// progress is kept, and consumers deduplicate on the event uuid. Live subscribers, which may miss events anyway, catch
+/// up through their own replay.
+pub struct OutboxRelayWorker {
+    outbox_repository: Arc<dyn OutboxRepositoryPort>,
+    event_publisher: Arc<dyn NotificationEventPublisherPort>,
+    delivery_worker: Arc<WebhookDeliveryWorker>,
+    settings: OutboxSettings,
+}
+
+impl OutboxRelayWorker {
+    pub fn new(
+        outbox_repository: Arc<dyn OutboxRepositoryPort>,
+        event_publisher: Arc<dyn NotificationEventPublisherPort>,
+        delivery_worker: Arc<WebhookDeliveryWorker>,
+        settings: OutboxSettings,
+    ) -> Self {
+        Self { outbox_repository, event_publisher, delivery_worker, settings }
+    }
+
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(Duration::from_millis(self.settings.poll_interval_milliseconds));
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.relay().await {
+                eprintln!("Failed to relay outbox events: {}", error);
+            }
+        }
+    }
+
+    /// Relays pending events in the order they were written and returns how many were relayed. An event that fails
+    /// stays pending for the next run without holding back the ones after it, which may then reach webhooks first.
+    pub async fn relay(&self) -> Result<usize, ApplicationError> {
+        let mut relayed = 0;
+        loop {
+            let outbox_events = self.outbox_repository.get_pending(self.settings.batch_size).await?;
+            let mut relayed_in_batch = 0;
+            for outbox_event in outbox_events {
+                let uuid = outbox_event.uuid;
+                match self.relay_event(outbox_event).await {
+                    Ok(()) => relayed_in_batch += 1,
+                    Err(error) => eprintln!("Failed to relay outbox event {}: {}", uuid, error),
+                }
+            }
+            // A batch left entirely pending would only be read again.
+            if relayed_in_batch == 0 {
+                return Ok(relayed);
+            }
+            relayed += relayed_in_batch;
+        }
+    }
+
+    async fn relay_event(&self, mut outbox_event: OutboxEventEntity) -> Result<(), ApplicationError> {
+        let event = NotificationEvent::from(outbox_event.clone());
+        let accepted = self.delivery_worker.accept(&event, &mut outbox_event.routed_to).await;
+        let published = match outbox_event.published_at {
+            Some(_) => Ok(()),
+            None => self.event_publisher.publish(event).await
+                .map(|_| outbox_event.published_at = Some(ClockService::now())),
+        };
+
+        match accepted.and(published) {
+            Ok(()) => self.outbox_repository.mark_relayed(&outbox_event.uuid).await,
+            Err(error) => {
+                self.outbox_repository.save_progress(&outbox_event).await?;
+                Err(error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::outgoing::persistence::entity::webhook_subscription_entity::WebhookSubscriptionEntity;
+    use crate::application::configuration::structs::WebhookSettings;
+    use crate::application::service::delivery_policy_service::{DeliveryDecision, MockDeliveryPolicyServicePort};
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::notification_event::NotificationEventType;
+    use crate::domain::model::webhook_subscription::WebhookSubscription;
+    use crate::port::outgoing::event::notification_event_publisher_port::MockNotificationEventPublisherPort;
+    use crate::port::outgoing::persistence::digest_repository_port::MockDigestRepositoryPort;
+    use crate::port::outgoing::persistence::outbox_repository_port::MockOutboxRepositoryPort;
+    use crate::port::outgoing::persistence::scheduled_delivery_repository_port::MockScheduledDeliveryRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_delivery_attempt_repository_port::MockWebhookDeliveryAttemptRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_subscription_repository_port::MockWebhookSubscriptionRepositoryPort;
+    use crate::port::outgoing::webhook::webhook_sender_port::MockWebhookSenderPort;
+    use mockall::predicate::eq;
+    use mockall::Sequence;
+
+    fn settings() -> OutboxSettings {
+        OutboxSettings { poll_interval_milliseconds: 1, batch_size: 10 }
+    }
+
+    fn delivery_worker(
+        subscription_repository: MockWebhookSubscriptionRepositoryPort,
+        delivery_policy: MockDeliveryPolicyServicePort,
+    ) -> Arc<WebhookDeliveryWorker> {
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save()
+            .returning(|attempt| Ok(attempt.clone()));
+        Arc::new(WebhookDeliveryWorker::new(
+            Arc::new(subscription_repository),
+            Arc::new(delivery_attempt_repository),
+            Arc::new(MockScheduledDeliveryRepositoryPort::new()),
+            Arc::new(MockDigestRepositoryPort::new()),
+            Arc::new(MockWebhookSenderPort::new()),
+            Arc::new(delivery_policy),
+            WebhookSettings { max_attempts: 1, initial_backoff_milliseconds: 1, max_backoff_milliseconds: 1, timeout_seconds: 1 },
+        ))
+    }
+
+    fn without_subscriptions() -> Arc<WebhookDeliveryWorker> {
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get_all().returning(|| Ok(vec![]));
+        delivery_worker(subscription_repository, MockDeliveryPolicyServicePort::new())
+    }
+
+    /// Subscriptions to every event, whose deliveries the policy suppresses so none is sent.
+    fn with_subscriptions(
+        subscriptions: Vec<WebhookSubscription>,
+        mut delivery_policy: MockDeliveryPolicyServicePort,
+    ) -> Arc<WebhookDeliveryWorker> {
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get_all()
+            .returning(move || Ok(subscriptions.iter().cloned().map(WebhookSubscriptionEntity::from).collect()));
+        delivery_policy.expect_decide()
+            .returning(|_, _| Ok(DeliveryDecision::Suppress("test".to_string())));
+        delivery_worker(subscription_repository, delivery_policy)
+    }
+
+    fn subscription() -> WebhookSubscription {
+        WebhookSubscription::new("https://example.com/hook".to_string(), "secret".to_string(), vec![])
+    }
+
+    fn outbox_event() -> OutboxEventEntity {
+        NotificationEvent::new(
+            NotificationEventType::Created,
+            Notification::new("recipient".to_string(), "message".to_string()),
+        ).into()
+    }
+
+    #[tokio::test]
+    async fn test_relay_publishes_and_marks_events() {
+        let first = outbox_event();
+        let second = outbox_event();
+        let (first_uuid, second_uuid) = (first.uuid, second.uuid);
+
+        let mut sequence = Sequence::new();
+        let mut outbox_repository = MockOutboxRepositoryPort::new();
+        outbox_repository.expect_get_pending()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_| Ok(vec![first.clone(), second.clone()]));
+        outbox_repository.expect_mark_relayed()
+            .with(eq(first_uuid))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(()));
+        outbox_repository.expect_mark_relayed()
+            .with(eq(second_uuid))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(()));
+        outbox_repository.expect_get_pending()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(vec![]));
+
+        let mut event_publisher = MockNotificationEventPublisherPort::new();
+        event_publisher.expect_publish()
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let worker = OutboxRelayWorker::new(
+            Arc::new(outbox_repository), Arc::new(event_publisher), without_subscriptions(), settings(),
+        );
+
+        assert_eq!(worker.relay().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_relay_keeps_event_when_publishing_fails() {
+        let event = outbox_event();
+
+        let mut outbox_repository = MockOutboxRepositoryPort::new();
+        outbox_repository.expect_get_pending()
+            .times(1)
+            .returning(move |_| Ok(vec![event.clone()]));
+        outbox_repository.expect_save_progress()
+            .withf(|outbox_event| outbox_event.published_at.is_none())
+            .times(1)
+            .returning(|_| Ok(()));
+        outbox_repository.expect_mark_relayed().never();
+
+        let mut event_publisher = MockNotificationEventPublisherPort::new();
+        event_publisher.expect_publish()
+            .times(1)
+            .returning(|_| Err(ApplicationError::InternalError("bus closed".to_string())));
+
+        let worker = OutboxRelayWorker::new(
+            Arc::new(outbox_repository), Arc::new(event_publisher), without_subscriptions(), settings(),
+        );
+
+        assert_eq!(worker.relay().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_relay_keeps_event_when_webhook_deliveries_fail() {
+        let event = outbox_event();
+
+        let mut outbox_repository = MockOutboxRepositoryPort::new();
+        outbox_repository.expect_get_pending()
+            .times(1)
+            .returning(move |_| Ok(vec![event.clone()]));
+        outbox_repository.expect_save_progress()
+            .withf(|outbox_event| outbox_event.published_at.is_some() && outbox_event.routed_to.is_empty())
+            .times(1)
+            .returning(|_| Ok(()));
+        outbox_repository.expect_mark_relayed().never();
+
+        let mut event_publisher = MockNotificationEventPublisherPort::new();
+        event_publisher.expect_publish()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get_all()
+            .times(1)
+            .returning(|| Err(ApplicationError::InternalError("store unavailable".to_string())));
+
+        let worker = OutboxRelayWorker::new(
+            Arc::new(outbox_repository),
+            Arc::new(event_publisher),
+            delivery_worker(subscription_repository, MockDeliveryPolicyServicePort::new()),
+            settings(),
+        );
+
+        assert_eq!(worker.relay().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_relay_goes_on_past_a_failing_event() {
+        let failing = OutboxEventEntity {
+            notification: Notification::new("unroutable".to_string(), "message".to_string()).into(),
+            ..outbox_event()
+        };
+        let following = outbox_event();
+        let (failing_uuid, following_uuid) = (failing.uuid, following.uuid);
+        let working = subscription();
+        let working_uuid = working.uuid;
+
+        let mut sequence = Sequence::new();
+        let mut outbox_repository = MockOutboxRepositoryPort::new();
+        outbox_repository.expect_get_pending()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_| Ok(vec![failing.clone(), following.clone()]));
+        outbox_repository.expect_save_progress()
+            .withf(move |outbox_event| outbox_event.uuid == failing_uuid && outbox_event.routed_to == vec![working_uuid])
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(()));
+        outbox_repository.expect_mark_relayed()
+            .with(eq(following_uuid))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(()));
+        outbox_repository.expect_get_pending()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(vec![]));
+
+        let mut event_publisher = MockNotificationEventPublisherPort::new();
+        event_publisher.expect_publish()
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let failing_subscription = subscription();
+        let failing_subscription_uuid = failing_subscription.uuid;
+        let mut delivery_policy = MockDeliveryPolicyServicePort::new();
+        delivery_policy.expect_decide()
+            .withf(move |subscription, notification| {
+                subscription.uuid == failing_subscription_uuid && notification.recipient == "unroutable"
+            })
+            .returning(|_, _| Err(ApplicationError::InternalError("store unavailable".to_string())));
+
+        let worker = OutboxRelayWorker::new(
+            Arc::new(outbox_repository),
+            Arc::new(event_publisher),
+            with_subscriptions(vec![working, failing_subscription], delivery_policy),
+            settings(),
+        );
+
+        assert_eq!(worker.relay().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_relay_retries_only_what_is_missing() {
+        let routed = subscription();
+        let missing = subscription();
+        let missing_uuid = missing.uuid;
+        let event = OutboxEventEntity {
+            published_at: Some(ClockService::now()),
+            routed_to: vec![routed.uuid],
+            ..outbox_event()
+        };
+        let uuid = event.uuid;
+
+        let mut outbox_repository = MockOutboxRepositoryPort::new();
+        outbox_repository.expect_get_pending()
+            .times(1)
+            .returning(move |_| Ok(vec![event.clone()]));
+        outbox_repository.expect_get_pending()
+            .returning(|_| Ok(vec![]));
+        outbox_repository.expect_mark_relayed()
+            .with(eq(uuid))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut event_publisher = MockNotificationEventPublisherPort::new();
+        event_publisher.expect_publish().never();
+
+        let mut delivery_policy = MockDeliveryPolicyServicePort::new();
+        delivery_policy.expect_decide()
+            .withf(move |subscription, _| subscription.uuid != missing_uuid)
+            .never();
+
+        let worker = OutboxRelayWorker::new(
+            Arc::new(outbox_repository),
+            Arc::new(event_publisher),
+            with_subscriptions(vec![routed, missing], delivery_policy),
+            settings(),
+        );
+
+        assert_eq!(worker.relay().await.unwrap(), 1);
+    }
+}
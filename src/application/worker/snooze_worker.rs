@@ -0,0 +1,80 @@
+use crate::application::configuration::structs::SnoozeSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::clock_service::ClockPort;
+use crate::application::service::notification_service::NotificationServicePort;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Brings snoozed notifications back to their recipient's inbox once their snooze ends.
+pub struct SnoozeWorker {
+    notification_service: Arc<dyn NotificationServicePort>,
+    clock: Arc<dyn ClockPort>,
+    settings: SnoozeSettings,
+}
+
+impl SnoozeWorker {
+    pub fn new(notification_service: Arc<dyn NotificationServicePort>, clock: Arc<dyn ClockPort>, settings: SnoozeSettings) -> Self {
+        Self { notification_service, clock, settings }
+    }
+
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(Duration::from_millis(self.settings.poll_interval_milliseconds));
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.resurface().await {
+                eprintln!("Failed to resurface snoozed notifications: {}", error);
+            }
+        }
+    }
+
+    /// Resurfaces every notification due by now, a batch at a time, and returns how many.
+    pub async fn resurface(&self) -> Result<usize, ApplicationError> {
+        let mut resurfaced = 0;
+        loop {
+            let batch = self.notification_service
+                .resurface_snoozed(self.clock.now(), self.settings.batch_size)
+                .await?;
+            resurfaced += batch;
+            if batch < self.settings.batch_size {
+                return Ok(resurfaced);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::service::clock_service::MockClockPort;
+    use crate::application::service::notification_service::MockNotificationServicePort;
+    use chrono::Utc;
+    use mockall::predicate::{always, eq};
+    use mockall::Sequence;
+
+    #[tokio::test]
+    async fn test_resurface_until_batch_is_not_full() {
+        let mut sequence = Sequence::new();
+        let mut notification_service = MockNotificationServicePort::new();
+        notification_service.expect_resurface_snoozed()
+            .with(always(), eq(2))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(2));
+        notification_service.expect_resurface_snoozed()
+            .with(always(), eq(2))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(1));
+
+        let mut clock = MockClockPort::new();
+        clock.expect_now().returning(Utc::now);
+
+        let worker = SnoozeWorker::new(
+            Arc::new(notification_service),
+            Arc::new(clock),
+            SnoozeSettings { poll_interval_milliseconds: 1, batch_size: 2 },
+        );
+
+        assert_eq!(worker.resurface().await.unwrap(), 3);
+    }
+}
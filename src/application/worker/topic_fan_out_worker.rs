@@ -0,0 +1,196 @@
+use crate::adapter::outgoing::persistence::entity::topic_publication_entity::TopicPublicationEntity;
+use crate::application::command::create_notification_command::CreateNotificationCommand;
+use crate::application::configuration::structs::FanOutSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::notification_service::NotificationServicePort;
+use crate::domain::model::topic_publication::TopicPublication;
+use crate::port::outgoing::persistence::topic_publication_repository_port::TopicPublicationRepositoryPort;
+use crate::port::outgoing::persistence::topic_repository_port::TopicRepositoryPort;
+use futures_util::future::join_all;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Creates the notifications of topic publications, one batch of subscribers per publication and poll, so that large
+/// audiences neither block the publisher nor starve other publications.
+pub struct TopicFanOutWorker {
+    publication_repository: Arc<dyn TopicPublicationRepositoryPort>,
+    topic_repository: Arc<dyn TopicRepositoryPort>,
+    notification_service: Arc<dyn NotificationServicePort>,
+    settings: FanOutSettings,
+}
+
+impl TopicFanOutWorker {
+    pub fn new(
+        publication_repository: Arc<dyn TopicPublicationRepositoryPort>,
+        topic_repository: Arc<dyn TopicRepositoryPort>,
+        notification_service: Arc<dyn NotificationServicePort>,
+        settings: FanOutSettings,
+    ) -> Self {
+        Self { publication_repository, topic_repository, notification_service, settings }
+    }
+
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(Duration::from_millis(self.settings.poll_interval_milliseconds));
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.fan_out().await {
+                eprintln!("Failed to fan out topic publications: {}", error);
+            }
+        }
+    }
+
+    /// Processes the next batch of every unfinished publication and returns how many notifications were created.
+    pub async fn fan_out(&self) -> Result<usize, ApplicationError> {
+        let publications = self.publication_repository.get_unfinished(self.settings.publications_per_poll).await?;
+
+        let mut created = 0;
+        for publication in publications.into_iter().map(TopicPublication::from) {
+            let uuid = publication.uuid;
+            match self.process_batch(publication).await {
+                Ok(batch_created) => created += batch_created,
+                Err(error) => eprintln!("Failed to fan out publication {}: {}", uuid, error),
+            }
+        }
+        Ok(created)
+    }
+
+    /// Progress is saved after each batch, so a failed batch is only retried from the last saved cursor.
+    async fn process_batch(&self, mut publication: TopicPublication) -> Result<usize, ApplicationError> {
+        let subscriptions = self.topic_repository
+            .get_subscriptions(&publication.topic_id, publication.cursor.clone(), self.settings.batch_size)
+            .await?;
+        let Some(last_recipient) = subscriptions.last().map(|subscription| subscription.recipient.clone()) else {
+            publication.complete();
+            return self.save(publication).await.map(|_| 0);
+        };
+
+        let processed = subscriptions.len();
+        let results = join_all(subscriptions.into_iter().map(|subscription| {
+            self.notification_service.create_notification(CreateNotificationCommand {
+                recipient: subscription.recipient,
+                message: publication.message.clone(),
+                category: Some(publication.category.clone()),
+                priority: publication.priority,
+                group_key: None,
+                actor: None,
+                actions: Vec::new(),
+                auto_resolve: false,
+                tags: vec![],
+            })
+        })).await;
+
+        let mut failed = 0;
+        for error in results.into_iter().filter_map(Result::err) {
+            eprintln!("Failed to create notification of publication {}: {}", publication.uuid, error);
+            failed += 1;
+        }
+
+        publication.record_batch(last_recipient, processed, failed);
+        if processed < self.settings.batch_size {
+            publication.complete();
+        }
+        self.save(publication).await?;
+        Ok(processed - failed)
+    }
+
+    async fn save(&self, publication: TopicPublication) -> Result<(), ApplicationError> {
+        let publication_entity: TopicPublicationEntity = publication.into();
+        self.publication_repository.save(&publication_entity).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::outgoing::persistence::entity::topic_entity::TopicSubscriptionEntity;
+    use crate::application::service::notification_service::MockNotificationServicePort;
+    use crate::domain::model::notification::Notification;
+    use crate::domain::model::priority::Priority;
+    use crate::domain::model::topic::TopicSubscription;
+    use crate::domain::model::topic_publication::PublicationStatus;
+    use crate::port::outgoing::persistence::topic_publication_repository_port::MockTopicPublicationRepositoryPort;
+    use crate::port::outgoing::persistence::topic_repository_port::MockTopicRepositoryPort;
+    use mockall::predicate::eq;
+
+    fn settings() -> FanOutSettings {
+        FanOutSettings { poll_interval_milliseconds: 1, batch_size: 2, publications_per_poll: 10 }
+    }
+
+    fn publication() -> TopicPublicationEntity {
+        TopicPublication::new("project-x".to_string(), "message".to_string(), "general".to_string(), Priority::Normal, 3).into()
+    }
+
+    fn subscriptions(recipients: &[&str]) -> Vec<TopicSubscriptionEntity> {
+        recipients.iter()
+            .map(|recipient| TopicSubscription::new("project-x".to_string(), recipient.to_string()).into())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_processes_one_batch_and_saves_progress() {
+        let mut publication_repository = MockTopicPublicationRepositoryPort::new();
+        publication_repository.expect_get_unfinished()
+            .returning(|_| Ok(vec![publication()]));
+        publication_repository.expect_save()
+            .withf(|publication| {
+                publication.status == PublicationStatus::InProgress
+                    && publication.cursor.as_deref() == Some("bob")
+                    && publication.processed_recipients == 2
+                    && publication.failed_recipients == 1
+            })
+            .times(1)
+            .returning(|publication| Ok(publication.clone()));
+
+        let mut topic_repository = MockTopicRepositoryPort::new();
+        topic_repository.expect_get_subscriptions()
+            .with(eq("project-x"), eq(None), eq(2))
+            .returning(|_, _, _| Ok(subscriptions(&["alice", "bob"])));
+
+        let mut notification_service = MockNotificationServicePort::new();
+        notification_service.expect_create_notification()
+            .times(2)
+            .returning(|command| match command.recipient.as_str() {
+                "alice" => Ok(Notification::new(command.recipient, command.message)),
+                _ => Err(ApplicationError::InternalError("unavailable".to_string())),
+            });
+
+        let worker = TopicFanOutWorker::new(
+            Arc::new(publication_repository), Arc::new(topic_repository), Arc::new(notification_service), settings(),
+        );
+
+        assert_eq!(worker.fan_out().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_completes_on_last_batch() {
+        let mut in_progress = TopicPublication::from(publication());
+        in_progress.record_batch("bob".to_string(), 2, 0);
+        let in_progress: TopicPublicationEntity = in_progress.into();
+
+        let mut publication_repository = MockTopicPublicationRepositoryPort::new();
+        publication_repository.expect_get_unfinished()
+            .returning(move |_| Ok(vec![in_progress.clone()]));
+        publication_repository.expect_save()
+            .withf(|publication| publication.status == PublicationStatus::Completed && publication.processed_recipients == 3)
+            .times(1)
+            .returning(|publication| Ok(publication.clone()));
+
+        let mut topic_repository = MockTopicRepositoryPort::new();
+        topic_repository.expect_get_subscriptions()
+            .with(eq("project-x"), eq(Some("bob".to_string())), eq(2))
+            .returning(|_, _, _| Ok(subscriptions(&["carol"])));
+
+        let mut notification_service = MockNotificationServicePort::new();
+        notification_service.expect_create_notification()
+            .withf(|command| command.recipient == "carol" && command.category.as_deref() == Some("general"))
+            .times(1)
+            .returning(|command| Ok(Notification::new(command.recipient, command.message)));
+
+        let worker = TopicFanOutWorker::new(
+            Arc::new(publication_repository), Arc::new(topic_repository), Arc::new(notification_service), settings(),
+        );
+
+        assert_eq!(worker.fan_out().await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,634 @@
+use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+use crate::adapter::outgoing::persistence::entity::scheduled_delivery_entity::ScheduledDeliveryEntity;
+use crate::adapter::outgoing::persistence::entity::webhook_delivery_attempt_entity::WebhookDeliveryAttemptEntity;
+use crate::application::configuration::structs::WebhookSettings;
+use crate::application::error::ApplicationError;
+use crate::application::service::clock_service::ClockService;
+use crate::application::service::delivery_policy_service::{DeliveryDecision, DeliveryPolicyServicePort};
+use crate::domain::model::digest::Digest;
+use crate::domain::model::notification::{Notification, NotificationKind};
+use crate::domain::model::notification_action::ActionTaken;
+use crate::domain::model::notification_event::{NotificationEvent, NotificationEventType};
+use crate::domain::model::priority::Priority;
+use crate::domain::model::scheduled_delivery::ScheduledDelivery;
+use crate::domain::model::webhook_delivery_attempt::WebhookDeliveryAttempt;
+use crate::domain::model::webhook_subscription::WebhookSubscription;
+use crate::port::outgoing::persistence::digest_repository_port::DigestRepositoryPort;
+use crate::port::outgoing::persistence::scheduled_delivery_repository_port::ScheduledDeliveryRepositoryPort;
+use crate::port::outgoing::persistence::webhook_delivery_attempt_repository_port::WebhookDeliveryAttemptRepositoryPort;
+use crate::port::outgoing::persistence::webhook_subscription_repository_port::WebhookSubscriptionRepositoryPort;
+use crate::port::outgoing::webhook::webhook_sender_port::WebhookSenderPort;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinSet;
+use uuid::Uuid;
+
+pub const SIGNATURE_HEADER: &str = "X-Notification-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Notification-Timestamp";
+pub const EVENT_HEADER: &str = "X-Notification-Event";
+pub const DELIVERY_HEADER: &str = "X-Notification-Delivery";
+
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    id: Uuid,
+    #[serde(rename = "type")]
+    event_type: NotificationEventType,
+    occurred_at: DateTime<Utc>,
+    notification: WebhookNotificationPayload<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct WebhookNotificationPayload<'a> {
+    uuid: Uuid,
+    kind: NotificationKind,
+    recipient: &'a str,
+    category: &'a str,
+    priority: Priority,
+    title: Option<&'a str>,
+    message: &'a str,
+    action_taken: Option<&'a ActionTaken>,
+    tags: &'a [String],
+    seen: bool,
+    deleted: bool,
+    date_created: DateTime<Utc>,
+    last_updated: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+struct WebhookDigestPayload<'a> {
+    id: Uuid,
+    #[serde(rename = "type")]
+    event_type: NotificationEventType,
+    occurred_at: DateTime<Utc>,
+    digest: WebhookDigestContentPayload<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct WebhookDigestContentPayload<'a> {
+    recipient: &'a str,
+    title: String,
+    body: String,
+    notifications: Vec<WebhookNotificationPayload<'a>>,
+}
+
+impl<'a> From<&'a Notification> for WebhookNotificationPayload<'a> {
+    fn from(notification: &'a Notification) -> Self {
+        Self {
+            uuid: notification.uuid,
+            kind: notification.kind,
+            recipient: &notification.recipient,
+            category: &notification.category,
+            priority: notification.priority,
+            title: notification.title.as_deref(),
+            message: &notification.message,
+            action_taken: notification.action_taken.as_ref(),
+            tags: &notification.tags,
+            seen: notification.seen,
+            deleted: notification.deleted,
+            date_created: notification.date_created,
+            last_updated: notification.last_updated,
+        }
+    }
+}
+
+impl<'a> From<&'a NotificationEvent> for WebhookPayload<'a> {
+    fn from(event: &'a NotificationEvent) -> Self {
+        Self {
+            id: event.uuid,
+            event_type: event.event_type,
+            occurred_at: event.occurred_at,
+            notification: WebhookNotificationPayload::from(&event.notification),
+        }
+    }
+}
+
+impl<'a> From<&'a Digest> for WebhookDigestPayload<'a> {
+    fn from(digest: &'a Digest) -> Self {
+        let rendered = digest.render();
+        Self {
+            id: digest.uuid,
+            event_type: NotificationEventType::Digest,
+            occurred_at: digest.due_at,
+            digest: WebhookDigestContentPayload {
+                recipient: &digest.recipient,
+                title: rendered.title,
+                body: rendered.body,
+                notifications: digest.notifications.iter().map(WebhookNotificationPayload::from).collect(),
+            },
+        }
+    }
+}
+
+/// Signs `{timestamp}.{body}` with the subscription secret, so receivers can reject replayed or tampered payloads.
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Delivers every notification event to the webhook subscriptions interested in it, retrying failed deliveries
+/// with exponential backoff and recording each attempt.
+///
+/// Subscriptions bound to a channel follow the recipient's delivery policy: events of categories the recipient
+/// disabled on the channel are recorded as suppressed, events arriving during quiet hours are scheduled for later, and
+/// low-priority notifications are collected into the recipient's digest when they chose one.
+pub struct WebhookDeliveryWorker {
+    subscription_repository: Arc<dyn WebhookSubscriptionRepositoryPort>,
+    delivery_attempt_repository: Arc<dyn WebhookDeliveryAttemptRepositoryPort>,
+    scheduled_delivery_repository: Arc<dyn ScheduledDeliveryRepositoryPort>,
+    digest_repository: Arc<dyn DigestRepositoryPort>,
+    webhook_sender: Arc<dyn WebhookSenderPort>,
+    delivery_policy: Arc<dyn DeliveryPolicyServicePort>,
+    settings: WebhookSettings,
+}
+
+impl WebhookDeliveryWorker {
+    pub fn new(
+        subscription_repository: Arc<dyn WebhookSubscriptionRepositoryPort>,
+        delivery_attempt_repository: Arc<dyn WebhookDeliveryAttemptRepositoryPort>,
+        scheduled_delivery_repository: Arc<dyn ScheduledDeliveryRepositoryPort>,
+        digest_repository: Arc<dyn DigestRepositoryPort>,
+        webhook_sender: Arc<dyn WebhookSenderPort>,
+        delivery_policy: Arc<dyn DeliveryPolicyServicePort>,
+        settings: WebhookSettings,
+    ) -> Self {
+        Self {
+            subscription_repository,
+            delivery_attempt_repository,
+            scheduled_delivery_repository,
+            digest_repository,
+            webhook_sender,
+            delivery_policy,
+            settings,
+        }
+    }
+
+    /// Routes the event to every interested subscription not yet in `routed_to`, adding those it was routed to and
+    /// leaving the deliveries to go on in the background.
+    ///
+    /// Called by the outbox relay, which keeps `routed_to` with the event and only marks it as relayed once this
+    /// succeeds. A subscription that cannot be routed to does not hold back the others, but fails the call so the
+    /// event is routed to it again later.
+    pub async fn accept(self: &Arc<Self>, event: &NotificationEvent, routed_to: &mut Vec<Uuid>) -> Result<(), ApplicationError> {
+        let subscriptions = self.interested_in(event).await?
+            .into_iter()
+            .filter(|subscription| !routed_to.contains(&subscription.uuid))
+            .collect();
+        let mut deliveries = JoinSet::new();
+        let routed = self.route_all(subscriptions, event, &mut deliveries, routed_to).await;
+        deliveries.detach_all();
+        routed
+    }
+
+    async fn interested_in(&self, event: &NotificationEvent) -> Result<Vec<WebhookSubscription>, ApplicationError> {
+        Ok(self.subscription_repository.get_all().await?
+            .into_iter()
+            .map(WebhookSubscription::from)
+            .filter(|subscription| subscription.accepts(event.event_type))
+            .collect())
+    }
+
+    /// Delivers the event to the given subscriptions, or suppresses, schedules or collects it into a digest as the
+    /// delivery policy decides, and returns how many accepted it right away. Only created events go into digests; the
+    /// later events of a digested notification are delivered as usual. Deliveries to the other subscriptions are
+    /// awaited even when one could not be routed to, whose error is returned afterwards.
+    pub async fn dispatch_to(
+        self: &Arc<Self>,
+        subscriptions: Vec<WebhookSubscription>,
+        event: &NotificationEvent,
+    ) -> Result<usize, ApplicationError> {
+        let mut deliveries = JoinSet::new();
+        let routed = self.route_all(subscriptions, event, &mut deliveries, &mut Vec::new()).await;
+        let delivered = deliveries.join_all().await.into_iter().filter(|delivered| *delivered).count();
+        routed.map(|_| delivered)
+    }
+
+    async fn route_all(
+        self: &Arc<Self>,
+        subscriptions: Vec<WebhookSubscription>,
+        event: &NotificationEvent,
+        deliveries: &mut JoinSet<bool>,
+        routed_to: &mut Vec<Uuid>,
+    ) -> Result<(), ApplicationError> {
+        let mut routed = Ok(());
+        for subscription in subscriptions {
+            let subscription_uuid = subscription.uuid;
+            match self.route(subscription, event, deliveries).await {
+                Ok(()) => routed_to.push(subscription_uuid),
+                Err(error) => {
+                    eprintln!("Failed to route event {} to webhook {}: {}", event.uuid, subscription_uuid, error);
+                    routed = Err(error);
+                }
+            }
+        }
+        routed
+    }
+
+    async fn route(
+        self: &Arc<Self>,
+        subscription: WebhookSubscription,
+        event: &NotificationEvent,
+        deliveries: &mut JoinSet<bool>,
+    ) -> Result<(), ApplicationError> {
+        match self.delivery_policy.decide(&subscription, &event.notification).await? {
+            DeliveryDecision::Digest(due_at) if event.event_type == NotificationEventType::Created => {
+                let notification: NotificationEntity = event.notification.clone().into();
+                self.digest_repository.append(&subscription.uuid, &event.notification.recipient, &notification, due_at).await?;
+            }
+            DeliveryDecision::Deliver | DeliveryDecision::Digest(_) => {
+                let worker = self.clone();
+                let event = event.clone();
+                deliveries.spawn(async move { worker.deliver(&subscription, &event).await });
+            }
+            DeliveryDecision::Suppress(reason) => {
+                self.record(WebhookDeliveryAttempt::suppressed(subscription.uuid, event.uuid, event.event_type, reason)).await;
+            }
+            DeliveryDecision::Defer(due_at) => {
+                let scheduled_delivery: ScheduledDeliveryEntity = ScheduledDelivery::new(subscription.uuid, event.clone(), due_at).into();
+                self.scheduled_delivery_repository.save(&scheduled_delivery).await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn deliver(&self, subscription: &WebhookSubscription, event: &NotificationEvent) -> bool {
+        let body = serde_json::to_string(&WebhookPayload::from(event)).expect("Webhook payload is serializable");
+        self.post(subscription, event.uuid, event.event_type, body).await
+    }
+
+    /// Delivers the digest as a single `digest` event whose id is the digest's.
+    pub async fn deliver_digest(&self, subscription: &WebhookSubscription, digest: &Digest) -> bool {
+        let body = serde_json::to_string(&WebhookDigestPayload::from(digest)).expect("Webhook payload is serializable");
+        self.post(subscription, digest.uuid, NotificationEventType::Digest, body).await
+    }
+
+    async fn post(&self, subscription: &WebhookSubscription, event_uuid: Uuid, event_type: NotificationEventType, body: String) -> bool {
+        for attempt in 1..=self.settings.max_attempts {
+            let timestamp = ClockService::now().timestamp();
+            let headers = vec![
+                (SIGNATURE_HEADER.to_string(), sign(&subscription.secret, timestamp, &body)),
+                (TIMESTAMP_HEADER.to_string(), timestamp.to_string()),
+                (EVENT_HEADER.to_string(), event_type.as_str().to_string()),
+                (DELIVERY_HEADER.to_string(), event_uuid.to_string()),
+            ];
+
+            let (status_code, error) = match self.webhook_sender.post(&subscription.url, headers, body.clone()).await {
+                Ok(status_code) => (Some(status_code), None),
+                Err(error) => (None, Some(error.to_string())),
+            };
+
+            let delivery_attempt = WebhookDeliveryAttempt::new(
+                subscription.uuid, event_uuid, event_type, attempt, status_code, error,
+            );
+            let delivered = delivery_attempt.is_success();
+            self.record(delivery_attempt).await;
+
+            if delivered {
+                return true;
+            }
+
+            if attempt < self.settings.max_attempts {
+                tokio::time::sleep(self.backoff(attempt)).await;
+            }
+        }
+
+        false
+    }
+
+    async fn record(&self, delivery_attempt: WebhookDeliveryAttempt) {
+        let attempt_entity: WebhookDeliveryAttemptEntity = delivery_attempt.into();
+        if let Err(error) = self.delivery_attempt_repository.save(&attempt_entity).await {
+            eprintln!("Failed to record webhook delivery attempt {}: {}", attempt_entity.uuid, error);
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let multiplier = 2u64.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self.settings.initial_backoff_milliseconds.saturating_mul(multiplier);
+        Duration::from_millis(backoff.min(self.settings.max_backoff_milliseconds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::outgoing::persistence::entity::webhook_subscription_entity::WebhookSubscriptionEntity;
+    use crate::domain::model::notification::Notification;
+    use crate::application::service::delivery_policy_service::MockDeliveryPolicyServicePort;
+    use crate::port::outgoing::persistence::digest_repository_port::MockDigestRepositoryPort;
+    use crate::port::outgoing::persistence::scheduled_delivery_repository_port::MockScheduledDeliveryRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_delivery_attempt_repository_port::MockWebhookDeliveryAttemptRepositoryPort;
+    use crate::port::outgoing::persistence::webhook_subscription_repository_port::MockWebhookSubscriptionRepositoryPort;
+    use chrono::Duration as ChronoDuration;
+    use crate::port::outgoing::webhook::webhook_sender_port::MockWebhookSenderPort;
+
+    fn settings() -> WebhookSettings {
+        WebhookSettings {
+            max_attempts: 3,
+            initial_backoff_milliseconds: 1,
+            max_backoff_milliseconds: 2,
+            timeout_seconds: 1,
+        }
+    }
+
+    fn worker_with_policy(
+        subscription_repository: MockWebhookSubscriptionRepositoryPort,
+        delivery_attempt_repository: MockWebhookDeliveryAttemptRepositoryPort,
+        scheduled_delivery_repository: MockScheduledDeliveryRepositoryPort,
+        digest_repository: MockDigestRepositoryPort,
+        webhook_sender: MockWebhookSenderPort,
+        delivery_policy: MockDeliveryPolicyServicePort,
+    ) -> Arc<WebhookDeliveryWorker> {
+        Arc::new(WebhookDeliveryWorker::new(
+            Arc::new(subscription_repository),
+            Arc::new(delivery_attempt_repository),
+            Arc::new(scheduled_delivery_repository),
+            Arc::new(digest_repository),
+            Arc::new(webhook_sender),
+            Arc::new(delivery_policy),
+            settings(),
+        ))
+    }
+
+    fn worker(
+        subscription_repository: MockWebhookSubscriptionRepositoryPort,
+        delivery_attempt_repository: MockWebhookDeliveryAttemptRepositoryPort,
+        webhook_sender: MockWebhookSenderPort,
+    ) -> Arc<WebhookDeliveryWorker> {
+        let mut delivery_policy = MockDeliveryPolicyServicePort::new();
+        delivery_policy.expect_decide().returning(|_, _| Ok(DeliveryDecision::Deliver));
+
+        worker_with_policy(
+            subscription_repository,
+            delivery_attempt_repository,
+            MockScheduledDeliveryRepositoryPort::new(),
+            MockDigestRepositoryPort::new(),
+            webhook_sender,
+            delivery_policy,
+        )
+    }
+
+    fn subscription(events: Vec<NotificationEventType>) -> WebhookSubscription {
+        WebhookSubscription::new("http://localhost/hook".to_string(), "secret".to_string(), events)
+    }
+
+    fn event() -> NotificationEvent {
+        NotificationEvent::new(NotificationEventType::Created, Notification::new("recipient".to_string(), "message".to_string()))
+    }
+
+    #[test]
+    fn test_sign() {
+        let signature = sign("secret", 1700000000, "{}");
+
+        assert!(signature.starts_with("sha256="));
+        assert_eq!(signature, sign("secret", 1700000000, "{}"));
+        assert_ne!(signature, sign("secret", 1700000001, "{}"));
+        assert_ne!(signature, sign("other secret", 1700000000, "{}"));
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let worker = worker(
+            MockWebhookSubscriptionRepositoryPort::new(),
+            MockWebhookDeliveryAttemptRepositoryPort::new(),
+            MockWebhookSenderPort::new(),
+        );
+
+        assert_eq!(worker.backoff(1), Duration::from_millis(1));
+        assert_eq!(worker.backoff(2), Duration::from_millis(2));
+        assert_eq!(worker.backoff(10), Duration::from_millis(2));
+    }
+
+    #[tokio::test]
+    async fn test_deliver_signs_payload() {
+        let subscription = subscription(vec![]);
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post()
+            .withf(|url, headers, body| {
+                let header = |name: &str| headers.iter().find(|(key, _)| key == name).map(|(_, value)| value.clone());
+                let timestamp: i64 = header(TIMESTAMP_HEADER).unwrap().parse().unwrap();
+                url == "http://localhost/hook"
+                    && header(SIGNATURE_HEADER) == Some(sign("secret", timestamp, body))
+                    && header(EVENT_HEADER) == Some("created".to_string())
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(200));
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save()
+            .withf(|attempt| attempt.attempt == 1 && attempt.status_code == Some(200))
+            .times(1)
+            .returning(|attempt| Ok(attempt.clone()));
+
+        let worker = worker(MockWebhookSubscriptionRepositoryPort::new(), delivery_attempt_repository, webhook_sender);
+
+        assert!(worker.deliver(&subscription, &event()).await);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_digest_sends_single_event() {
+        let mut digest = Digest::new(Uuid::new_v4(), "recipient".to_string(), Utc::now());
+        digest.notifications.push(Notification::new("recipient".to_string(), "first".to_string()));
+        digest.notifications.push(Notification::new("recipient".to_string(), "second".to_string()));
+        let digest_uuid = digest.uuid;
+
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post()
+            .withf(move |_, headers, body| {
+                let header = |name: &str| headers.iter().find(|(key, _)| key == name).map(|(_, value)| value.clone());
+                let payload: serde_json::Value = serde_json::from_str(body).unwrap();
+                header(EVENT_HEADER) == Some("digest".to_string())
+                    && header(DELIVERY_HEADER) == Some(digest_uuid.to_string())
+                    && payload["digest"]["title"] == "2 new notifications"
+                    && payload["digest"]["notifications"].as_array().unwrap().len() == 2
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(200));
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save()
+            .withf(move |attempt| attempt.event_uuid == digest_uuid && attempt.event_type == NotificationEventType::Digest)
+            .times(1)
+            .returning(|attempt| Ok(attempt.clone()));
+
+        let worker = worker(MockWebhookSubscriptionRepositoryPort::new(), delivery_attempt_repository, webhook_sender);
+
+        assert!(worker.deliver_digest(&subscription(vec![]), &digest).await);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_retries_until_success() {
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        let mut responses = vec![Ok(200), Ok(503), Err(ApplicationError::InternalError("refused".to_string()))];
+        webhook_sender.expect_post()
+            .times(3)
+            .returning(move |_, _, _| responses.pop().unwrap());
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save()
+            .times(3)
+            .returning(|attempt| Ok(attempt.clone()));
+
+        let worker = worker(MockWebhookSubscriptionRepositoryPort::new(), delivery_attempt_repository, webhook_sender);
+
+        assert!(worker.deliver(&subscription(vec![]), &event()).await);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_gives_up_after_max_attempts() {
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post()
+            .times(3)
+            .returning(|_, _, _| Ok(500));
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save()
+            .times(3)
+            .returning(|attempt| Ok(attempt.clone()));
+
+        let worker = worker(MockWebhookSubscriptionRepositoryPort::new(), delivery_attempt_repository, webhook_sender);
+
+        assert!(!worker.deliver(&subscription(vec![]), &event()).await);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_only_to_interested_subscriptions() {
+        let interested: WebhookSubscriptionEntity = subscription(vec![NotificationEventType::Created]).into();
+        let not_interested: WebhookSubscriptionEntity = subscription(vec![NotificationEventType::Deleted]).into();
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get_all()
+            .returning(move || Ok(vec![interested.clone(), not_interested.clone()]));
+
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post()
+            .times(1)
+            .returning(|_, _, _| Ok(204));
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save()
+            .returning(|attempt| Ok(attempt.clone()));
+
+        let worker = worker(subscription_repository, delivery_attempt_repository, webhook_sender);
+
+        let event = event();
+        let subscriptions = worker.interested_in(&event).await.unwrap();
+
+        assert_eq!(worker.dispatch_to(subscriptions, &event).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_follows_delivery_policy() {
+        let delivered: WebhookSubscriptionEntity = subscription(vec![]).into();
+        let suppressed: WebhookSubscriptionEntity = subscription(vec![]).into();
+        let deferred: WebhookSubscriptionEntity = subscription(vec![]).into();
+        let digested: WebhookSubscriptionEntity = subscription(vec![]).into();
+        let (delivered_uuid, suppressed_uuid, deferred_uuid, digested_uuid) = (delivered.uuid, suppressed.uuid, deferred.uuid, digested.uuid);
+        let due_at = Utc::now() + ChronoDuration::hours(8);
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get_all()
+            .returning(move || Ok(vec![delivered.clone(), suppressed.clone(), deferred.clone(), digested.clone()]));
+
+        let mut delivery_policy = MockDeliveryPolicyServicePort::new();
+        delivery_policy.expect_decide()
+            .returning(move |subscription, _| Ok(match subscription.uuid {
+                uuid if uuid == suppressed_uuid => DeliveryDecision::Suppress("opted out".to_string()),
+                uuid if uuid == deferred_uuid => DeliveryDecision::Defer(due_at),
+                uuid if uuid == digested_uuid => DeliveryDecision::Digest(due_at),
+                _ => DeliveryDecision::Deliver,
+            }));
+
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post()
+            .times(1)
+            .returning(|_, _, _| Ok(200));
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save()
+            .withf(move |attempt| attempt.subscription_uuid == suppressed_uuid && attempt.suppression_reason.as_deref() == Some("opted out"))
+            .times(1)
+            .returning(|attempt| Ok(attempt.clone()));
+        delivery_attempt_repository.expect_save()
+            .withf(move |attempt| attempt.subscription_uuid == delivered_uuid && attempt.status_code == Some(200))
+            .times(1)
+            .returning(|attempt| Ok(attempt.clone()));
+
+        let mut scheduled_delivery_repository = MockScheduledDeliveryRepositoryPort::new();
+        scheduled_delivery_repository.expect_save()
+            .withf(move |scheduled_delivery| scheduled_delivery.subscription_uuid == deferred_uuid && scheduled_delivery.due_at == due_at)
+            .times(1)
+            .returning(|scheduled_delivery| Ok(scheduled_delivery.clone()));
+
+        let mut digest_repository = MockDigestRepositoryPort::new();
+        digest_repository.expect_append()
+            .withf(move |subscription_uuid, recipient, _, digest_due_at| {
+                *subscription_uuid == digested_uuid && recipient == "recipient" && *digest_due_at == due_at
+            })
+            .times(1)
+            .returning(|subscription_uuid, recipient, notification, due_at| {
+                let mut digest = Digest::new(*subscription_uuid, recipient.to_string(), due_at);
+                digest.notifications.push(notification.clone().into());
+                Ok(digest.into())
+            });
+
+        let worker = worker_with_policy(
+            subscription_repository,
+            delivery_attempt_repository,
+            scheduled_delivery_repository,
+            digest_repository,
+            webhook_sender,
+            delivery_policy,
+        );
+
+        let event = event();
+        let subscriptions = worker.interested_in(&event).await.unwrap();
+
+        assert_eq!(worker.dispatch_to(subscriptions, &event).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_keeps_delivering_when_a_subscription_fails() {
+        let failing: WebhookSubscriptionEntity = subscription(vec![]).into();
+        let delivered: WebhookSubscriptionEntity = subscription(vec![]).into();
+        let failing_uuid = failing.uuid;
+
+        let mut subscription_repository = MockWebhookSubscriptionRepositoryPort::new();
+        subscription_repository.expect_get_all()
+            .returning(move || Ok(vec![failing.clone(), delivered.clone()]));
+
+        let mut delivery_policy = MockDeliveryPolicyServicePort::new();
+        delivery_policy.expect_decide()
+            .withf(move |subscription, _| subscription.uuid == failing_uuid)
+            .returning(|_, _| Err(ApplicationError::InternalError("profile store down".to_string())));
+        delivery_policy.expect_decide()
+            .returning(|_, _| Ok(DeliveryDecision::Deliver));
+
+        let mut webhook_sender = MockWebhookSenderPort::new();
+        webhook_sender.expect_post()
+            .times(1)
+            .returning(|_, _, _| Ok(200));
+
+        let mut delivery_attempt_repository = MockWebhookDeliveryAttemptRepositoryPort::new();
+        delivery_attempt_repository.expect_save()
+            .returning(|attempt| Ok(attempt.clone()));
+
+        let worker = worker_with_policy(
+            subscription_repository,
+            delivery_attempt_repository,
+            MockScheduledDeliveryRepositoryPort::new(),
+            MockDigestRepositoryPort::new(),
+            webhook_sender,
+            delivery_policy,
+        );
+
+        let event = event();
+        let subscriptions = worker.interested_in(&event).await.unwrap();
+
+        assert!(worker.dispatch_to(subscriptions, &event).await.is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use crate::application::service::clock_service::ClockService;
+use crate::domain::model::notification::{Notification, NotificationKind};
+use crate::domain::model::preference::DEFAULT_CATEGORY;
+use crate::domain::model::priority::Priority;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Recipient of a broadcast's events, which are published once for every recipient.
+pub const EVERY_RECIPIENT: &str = "*";
+
+/// Announcement for every recipient, stored once instead of as a notification per recipient.
+#[derive(Debug, Clone)]
+pub struct Broadcast {
+    pub uuid: Uuid,
+    pub category: String,
+    pub priority: Priority,
+    pub title: Option<String>,
+    pub message: String,
+    pub date_created: DateTime<Utc>,
+}
+
+impl Broadcast {
+    pub fn new(title: Option<String>, message: String) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            category: DEFAULT_CATEGORY.to_string(),
+            priority: Priority::default(),
+            title,
+            message,
+            date_created: ClockService::now(),
+        }
+    }
+
+    /// The broadcast as it appears in the recipient's inbox; without a receipt it is still unseen.
+    pub fn to_notification(&self, recipient: String, receipt: Option<&BroadcastReceipt>) -> Notification {
+        Notification {
+            uuid: self.uuid,
+            kind: NotificationKind::Broadcast,
+            recipient,
+            category: self.category.clone(),
+            priority: self.priority,
+            group_key: None,
+            actor: None,
+            title: self.title.clone(),
+            message: self.message.clone(),
+            locale: None,
+            localizations: Vec::new(),
+            actions: Vec::new(),
+            auto_resolve: false,
+            action_taken: None,
+            snoozed_until: None,
+            seen: receipt.is_some_and(|receipt| receipt.seen),
+            deleted: receipt.is_some_and(|receipt| receipt.deleted),
+            seen_at: receipt.and_then(|receipt| receipt.seen_at),
+            deleted_at: receipt.and_then(|receipt| receipt.deleted_at),
+            tags: Vec::new(),
+            pinned: false,
+            archived: false,
+            // Receipts are not versioned, so the same broadcast always reports the first version.
+            version: 0,
+            date_created: self.date_created,
+            last_updated: receipt.map_or(self.date_created, |receipt| receipt.last_updated),
+        }
+    }
+}
+
+/// State of a broadcast for one recipient, only stored once the recipient has acted on it.
+#[derive(Debug, Clone)]
+pub struct BroadcastReceipt {
+    pub broadcast_uuid: Uuid,
+    pub recipient: String,
+    pub seen: bool,
+    pub deleted: bool,
+    pub seen_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub last_updated: DateTime<Utc>,
+}
+
+impl BroadcastReceipt {
+    pub fn new(broadcast_uuid: Uuid, recipient: String) -> Self {
+        Self {
+            broadcast_uuid,
+            recipient,
+            seen: false,
+            deleted: false,
+            seen_at: None,
+            deleted_at: None,
+            last_updated: ClockService::now(),
+        }
+    }
+
+    pub fn set_as_seen(&mut self) {
+        let now = ClockService::now();
+        self.seen = true;
+        self.seen_at.get_or_insert(now);
+        self.last_updated = now;
+    }
+
+    pub fn set_as_deleted(&mut self) {
+        let now = ClockService::now();
+        self.seen = true;
+        self.seen_at.get_or_insert(now);
+        self.deleted = true;
+        self.deleted_at.get_or_insert(now);
+        self.last_updated = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_notification_applies_receipt() {
+        let broadcast = Broadcast::new(Some("Maintenance".to_string()), "Down at 22:00".to_string());
+        let mut receipt = BroadcastReceipt::new(broadcast.uuid, "alice".to_string());
+        receipt.set_as_deleted();
+
+        let unseen = broadcast.to_notification("bob".to_string(), None);
+        let deleted = broadcast.to_notification("alice".to_string(), Some(&receipt));
+
+        assert_eq!(unseen.uuid, broadcast.uuid);
+        assert_eq!(unseen.kind, NotificationKind::Broadcast);
+        assert_eq!(unseen.recipient, "bob");
+        assert!(!unseen.seen && !unseen.deleted);
+        assert!(deleted.seen && deleted.deleted);
+        assert!(unseen.seen_at.is_none() && unseen.deleted_at.is_none());
+        assert_eq!(deleted.seen_at, Some(receipt.last_updated));
+        assert_eq!(deleted.deleted_at, Some(receipt.last_updated));
+        assert_eq!(deleted.last_updated, receipt.last_updated);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::application::service::clock_service::ClockService;
+use crate::domain::model::notification::Notification;
+use crate::domain::model::notification_template::RenderedTemplate;
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DigestFrequency {
+    Hourly,
+    /// Sent at midnight UTC.
+    Daily,
+}
+
+impl DigestFrequency {
+    /// Start of the period following the one `at` falls in.
+    pub fn next_boundary(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let period = match self {
+            DigestFrequency::Hourly => TimeDelta::hours(1),
+            DigestFrequency::Daily => TimeDelta::days(1),
+        };
+        at.duration_trunc(period).expect("Periods are shorter than the representable range") + period
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DigestStatus {
+    /// Still collecting notifications until `due_at`.
+    Open,
+    Sent,
+    /// Every delivery attempt failed, or the subscription no longer exists.
+    Failed,
+}
+
+/// Low-priority notifications collected for one recipient and subscription, delivered together as one message.
+#[derive(Debug, Clone)]
+pub struct Digest {
+    pub uuid: Uuid,
+    pub subscription_uuid: Uuid,
+    pub recipient: String,
+    /// The notifications included, in the order they were collected.
+    pub notifications: Vec<Notification>,
+    pub status: DigestStatus,
+    pub due_at: DateTime<Utc>,
+    pub date_created: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+impl Digest {
+    pub fn new(subscription_uuid: Uuid, recipient: String, due_at: DateTime<Utc>) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            subscription_uuid,
+            recipient,
+            notifications: Vec::new(),
+            status: DigestStatus::Open,
+            due_at,
+            date_created: ClockService::now(),
+            sent_at: None,
+        }
+    }
+
+    pub fn complete(&mut self, delivered: bool) {
+        self.status = if delivered { DigestStatus::Sent } else { DigestStatus::Failed };
+        self.sent_at = Some(ClockService::now());
+    }
+
+    /// Combined message listing every notification on its own line, by its title when it has one.
+    pub fn render(&self) -> RenderedTemplate {
+        let title = match self.notifications.len() {
+            1 => "1 new notification".to_string(),
+            count => format!("{} new notifications", count),
+        };
+        let body = self.notifications.iter()
+            .map(|notification| match &notification.title {
+                Some(title) => format!("- {}: {}", title, notification.message),
+                None => format!("- {}", notification.message),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        RenderedTemplate { title, body }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_boundary() {
+        let at = DateTime::parse_from_rfc3339("2024-03-10T13:45:10Z").unwrap().to_utc();
+
+        assert_eq!(DigestFrequency::Hourly.next_boundary(at).to_rfc3339(), "2024-03-10T14:00:00+00:00");
+        assert_eq!(DigestFrequency::Daily.next_boundary(at).to_rfc3339(), "2024-03-11T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_render() {
+        let mut digest = Digest::new(Uuid::new_v4(), "recipient".to_string(), ClockService::now());
+        digest.notifications.push(Notification {
+            title: Some("Order 42".to_string()),
+            ..Notification::new("recipient".to_string(), "shipped".to_string())
+        });
+        digest.notifications.push(Notification::new("recipient".to_string(), "New follower".to_string()));
+
+        let rendered = digest.render();
+
+        assert_eq!(rendered.title, "2 new notifications");
+        assert_eq!(rendered.body, "- Order 42: shipped\n- New follower");
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Content of a notification in one locale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct LocalizedContent {
+    pub locale: String,
+    pub title: Option<String>,
+    pub message: String,
+}
+
+/// Expands the preferred locales with their base language (`pt-BR` then `pt`) and appends the fallback chain,
+/// keeping the first occurrence of each locale.
+pub fn locale_candidates(preferred: &[String], fallback: &[String]) -> Vec<String> {
+    let mut candidates: Vec<String> = Vec::new();
+    let mut push = |locale: &str| {
+        if !locale.is_empty() && !candidates.iter().any(|candidate| candidate.eq_ignore_ascii_case(locale)) {
+            candidates.push(locale.to_string());
+        }
+    };
+
+    for locale in preferred {
+        push(locale);
+        if let Some((language, _)) = locale.split_once('-') {
+            push(language);
+        }
+    }
+    for locale in fallback {
+        push(locale);
+    }
+    candidates
+}
+
+/// First candidate that is available, compared case-insensitively.
+pub fn resolve_locale<'a>(available: &[&'a str], candidates: &[String]) -> Option<&'a str> {
+    candidates.iter().find_map(|candidate| {
+        available.iter().copied().find(|locale| locale.eq_ignore_ascii_case(candidate))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locales(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_locale_candidates_expands_and_deduplicates() {
+        let result = locale_candidates(&locales(&["pt-BR", "PT", "es"]), &locales(&["en", "es"]));
+
+        assert_eq!(result, locales(&["pt-BR", "pt", "es", "en"]));
+    }
+
+    #[test]
+    fn test_resolve_locale() {
+        let available = ["en", "pt"];
+
+        assert_eq!(resolve_locale(&available, &locales(&["fr", "PT"])), Some("pt"));
+        assert_eq!(resolve_locale(&available, &locales(&["fr"])), None);
+    }
+}
@@ -1 +1,22 @@
-pub mod notification;
\ No newline at end of file
+pub mod broadcast;
+pub mod digest;
+pub mod localisation;
+pub mod notification;
+pub mod notification_action;
+pub mod notification_event;
+pub mod notification_filter;
+pub mod notification_group;
+pub mod notification_history;
+pub mod notification_search;
+pub mod notification_template;
+pub mod preference;
+pub mod priority;
+pub mod quiet_hours;
+pub mod recipient_profile;
+pub mod scheduled_delivery;
+pub mod state_change;
+pub mod tag_count;
+pub mod topic;
+pub mod topic_publication;
+pub mod webhook_delivery_attempt;
+pub mod webhook_subscription;
\ No newline at end of file
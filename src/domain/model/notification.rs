@@ -1,49 +1,223 @@
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use crate::application::service::clock_service::ClockService;
+use crate::domain::model::localisation::{resolve_locale, LocalizedContent};
+use crate::domain::model::notification_action::{ActionError, ActionTaken, NotificationAction};
+use crate::domain::model::preference::DEFAULT_CATEGORY;
+use crate::domain::model::priority::Priority;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// Stored for its recipient alone.
+    #[default]
+    Personal,
+    /// A broadcast as seen by one recipient.
+    Broadcast,
+}
 
 #[derive(Debug, Clone)]
 pub struct Notification {
     pub uuid: Uuid,
+    pub kind: NotificationKind,
+    pub recipient: String,
+    pub category: String,
+    pub priority: Priority,
+    /// Notifications sharing a key, such as comments on the same post, collapse into one thread.
+    pub group_key: Option<String>,
+    /// Who caused the notification, listed among the actors of its group.
+    pub actor: Option<String>,
+    pub title: Option<String>,
     pub message: String,
+    /// Locale of `title` and `message`, when known.
+    pub locale: Option<String>,
+    /// The same content in other locales.
+    pub localizations: Vec<LocalizedContent>,
+    pub actions: Vec<NotificationAction>,
+    /// Whether taking an action marks the notification as seen and closes it to further actions.
+    pub auto_resolve: bool,
+    pub action_taken: Option<ActionTaken>,
+    /// Labels set by the producer or the recipient, each at most once.
+    pub tags: Vec<String>,
+    /// Hidden from the inbox until then, when it resurfaces as unseen.
+    pub snoozed_until: Option<DateTime<Utc>>,
     pub seen: bool,
     pub deleted: bool,
+    /// When the notification was first seen, since it last resurfaced.
+    pub seen_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Listed before the other notifications.
+    pub pinned: bool,
+    /// Out of the inbox, but still listed with the archive and found by searches.
+    pub archived: bool,
+    /// Incremented by every save, so that a change based on an older version can be refused.
+    pub version: u64,
     pub date_created: DateTime<Utc>,
     pub last_updated: DateTime<Utc>,
 }
 
 impl Notification {
-    pub fn new(message: String) -> Self {
+    pub fn new(recipient: String, message: String) -> Self {
         let now = ClockService::now();
         Self {
             uuid: Uuid::new_v4(),
+            kind: NotificationKind::Personal,
+            recipient,
+            category: DEFAULT_CATEGORY.to_string(),
+            priority: Priority::default(),
+            group_key: None,
+            actor: None,
+            title: None,
             message,
+            locale: None,
+            localizations: Vec::new(),
+            actions: Vec::new(),
+            auto_resolve: false,
+            action_taken: None,
+            tags: Vec::new(),
+            snoozed_until: None,
             seen: false,
             deleted: false,
+            seen_at: None,
+            deleted_at: None,
+            pinned: false,
+            archived: false,
+            version: 0,
             date_created: now,
             last_updated: now,
         }
     }
 
     pub fn set_as_seen(&mut self) {
+        let now = ClockService::now();
         self.seen = true;
-        self.last_updated = ClockService::now();
+        self.seen_at.get_or_insert(now);
+        self.last_updated = now;
     }
 
     pub fn set_as_deleted(&mut self) {
+        let now = ClockService::now();
         self.seen = true;
+        self.seen_at.get_or_insert(now);
         self.deleted = true;
+        self.deleted_at.get_or_insert(now);
+        self.last_updated = now;
+    }
+
+    pub fn snooze(&mut self, until: DateTime<Utc>) {
+        self.snoozed_until = Some(until);
         self.last_updated = ClockService::now();
     }
+
+    pub fn is_snoozed(&self, at: DateTime<Utc>) -> bool {
+        self.snoozed_until.is_some_and(|until| until > at)
+    }
+
+    /// Ends the snooze, bringing the notification back as unseen. Deleted notifications are left as they are.
+    pub fn resurface(&mut self) {
+        if self.deleted {
+            return;
+        }
+        self.snoozed_until = None;
+        self.seen = false;
+        self.seen_at = None;
+        self.last_updated = ClockService::now();
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.auto_resolve && self.action_taken.is_some()
+    }
+
+    /// Records the action as the last one taken, resolving the notification when it auto-resolves.
+    pub fn take_action(&mut self, action_id: &str) -> Result<(), ActionError> {
+        if !self.actions.iter().any(|action| action.id == action_id) {
+            return Err(ActionError::UnknownAction(action_id.to_string()));
+        }
+        if self.is_resolved() {
+            return Err(ActionError::AlreadyResolved(self.uuid.to_string()));
+        }
+
+        let now = ClockService::now();
+        self.action_taken = Some(ActionTaken { action_id: action_id.to_string(), date_taken: now });
+        if self.auto_resolve {
+            self.seen = true;
+            self.seen_at.get_or_insert(now);
+        }
+        self.last_updated = now;
+        Ok(())
+    }
+
+    /// Returns whether the tag was added, as the notification may already have it.
+    pub fn add_tag(&mut self, tag: String) -> bool {
+        if self.tags.contains(&tag) {
+            return false;
+        }
+        self.tags.push(tag);
+        self.last_updated = ClockService::now();
+        true
+    }
+
+    /// Returns whether the tag was removed, as the notification may not have it.
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let count = self.tags.len();
+        self.tags.retain(|known| known != tag);
+        if self.tags.len() == count {
+            return false;
+        }
+        self.last_updated = ClockService::now();
+        true
+    }
+
+    pub fn set_pinned(&mut self, pinned: bool) {
+        self.pinned = pinned;
+        self.last_updated = ClockService::now();
+    }
+
+    /// Archiving also unpins, as pinned notifications belong on top of the inbox.
+    pub fn set_archived(&mut self, archived: bool) {
+        self.archived = archived;
+        if archived {
+            self.pinned = false;
+        }
+        self.last_updated = ClockService::now();
+    }
+
+    /// Swaps `title` and `message` for the localization matching the first possible candidate.
+    /// The content is left untouched when its own locale is preferred or nothing matches.
+    pub fn localize(&mut self, candidates: &[String]) {
+        let mut available: Vec<&str> = self.localizations.iter().map(|content| content.locale.as_str()).collect();
+        if let Some(locale) = &self.locale {
+            available.insert(0, locale);
+        }
+
+        let Some(resolved) = resolve_locale(&available, candidates) else { return };
+        if self.locale.as_deref() == Some(resolved) {
+            return;
+        }
+
+        let resolved = resolved.to_string();
+        if let Some(content) = self.localizations.iter().find(|content| content.locale == resolved).cloned() {
+            self.title = content.title;
+            self.message = content.message;
+            self.locale = Some(content.locale);
+        }
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
+    use crate::domain::model::notification_action::ActionStyle;
 
     #[test]
     fn test_new() {
-        let notification = Notification::new("test".to_string());
+        let notification = Notification::new("recipient".to_string(), "test".to_string());
+        assert_eq!(notification.kind, NotificationKind::Personal);
+        assert_eq!(notification.recipient, "recipient");
+        assert_eq!(notification.category, DEFAULT_CATEGORY);
         assert_eq!(notification.message, "test");
         assert_eq!(notification.seen, false);
         assert_eq!(notification.deleted, false);
@@ -51,17 +225,149 @@ mod tests {
 
     #[test]
     fn test_set_as_seen() {
-        let mut notification = Notification::new("test".to_string());
+        let mut notification = Notification::new("recipient".to_string(), "test".to_string());
         notification.set_as_seen();
         assert_eq!(notification.seen, true);
+        assert_eq!(notification.seen_at, Some(notification.last_updated));
     }
 
     #[test]
     fn test_set_as_deleted() {
-        let mut notification = Notification::new("test".to_string());
+        let mut notification = Notification::new("recipient".to_string(), "test".to_string());
+        notification.set_as_seen();
+        let seen_at = notification.seen_at;
         notification.set_as_deleted();
         assert_eq!(notification.seen, true);
         assert_eq!(notification.deleted, true);
+        assert_eq!(notification.seen_at, seen_at);
+        assert_eq!(notification.deleted_at, Some(notification.last_updated));
     }
 
+    #[test]
+    fn test_snooze_and_resurface() {
+        let mut notification = Notification::new("recipient".to_string(), "test".to_string());
+        notification.set_as_seen();
+        let until = notification.date_created + chrono::Duration::hours(1);
+
+        notification.snooze(until);
+
+        assert!(notification.is_snoozed(until - chrono::Duration::minutes(1)));
+        assert!(!notification.is_snoozed(until));
+
+        notification.resurface();
+
+        assert_eq!(notification.snoozed_until, None);
+        assert!(!notification.seen);
+        assert_eq!(notification.seen_at, None);
+    }
+
+    #[test]
+    fn test_resurface_deleted_unchanged() {
+        let mut notification = Notification::new("recipient".to_string(), "test".to_string());
+        notification.snooze(notification.date_created + chrono::Duration::hours(1));
+        notification.set_as_deleted();
+        let snoozed_until = notification.snoozed_until;
+
+        notification.resurface();
+
+        assert_eq!(notification.snoozed_until, snoozed_until);
+        assert!(notification.seen);
+        assert!(notification.seen_at.is_some());
+    }
+
+    #[test]
+    fn test_add_and_remove_tag() {
+        let mut notification = Notification::new("recipient".to_string(), "test".to_string());
+
+        assert!(notification.add_tag("billing".to_string()));
+        assert!(!notification.add_tag("billing".to_string()));
+        assert!(!notification.remove_tag("work"));
+        assert!(notification.remove_tag("billing"));
+        assert!(notification.tags.is_empty());
+    }
+
+    #[test]
+    fn test_set_archived_unpins() {
+        let mut notification = Notification::new("recipient".to_string(), "test".to_string());
+        notification.set_pinned(true);
+
+        notification.set_archived(true);
+
+        assert!(notification.archived);
+        assert!(!notification.pinned);
+    }
+
+    fn actionable(auto_resolve: bool) -> Notification {
+        let action = |id: &str| NotificationAction {
+            id: id.to_string(),
+            label: id.to_string(),
+            style: ActionStyle::Primary,
+            url: None,
+        };
+        Notification {
+            actions: vec![action("approve"), action("reject")],
+            auto_resolve,
+            ..Notification::new("recipient".to_string(), "Approve the request?".to_string())
+        }
+    }
+
+    #[test]
+    fn test_take_action_auto_resolves() {
+        let mut notification = actionable(true);
+
+        notification.take_action("approve").unwrap();
+
+        assert_eq!(notification.action_taken.as_ref().map(|taken| taken.action_id.as_str()), Some("approve"));
+        assert!(notification.seen);
+        assert!(notification.is_resolved());
+        assert_eq!(notification.take_action("reject"), Err(ActionError::AlreadyResolved(notification.uuid.to_string())));
+    }
+
+    #[test]
+    fn test_take_action_without_auto_resolve() {
+        let mut notification = actionable(false);
+
+        notification.take_action("approve").unwrap();
+        notification.take_action("reject").unwrap();
+
+        assert_eq!(notification.action_taken.map(|taken| taken.action_id), Some("reject".to_string()));
+        assert!(!notification.seen);
+        assert_eq!(
+            actionable(false).take_action("unknown"),
+            Err(ActionError::UnknownAction("unknown".to_string())),
+        );
+    }
+
+    #[test]
+    fn test_localize() {
+        let mut notification = Notification {
+            locale: Some("en".to_string()),
+            localizations: vec![LocalizedContent {
+                locale: "pt".to_string(),
+                title: Some("Olá".to_string()),
+                message: "mensagem".to_string(),
+            }],
+            ..Notification::new("recipient".to_string(), "message".to_string())
+        };
+
+        notification.localize(&["fr".to_string(), "PT".to_string(), "en".to_string()]);
+
+        assert_eq!(notification.locale, Some("pt".to_string()));
+        assert_eq!(notification.title, Some("Olá".to_string()));
+        assert_eq!(notification.message, "mensagem");
+    }
+
+    #[test]
+    fn test_localize_keeps_preferred_default() {
+        let mut notification = Notification {
+            locale: Some("en".to_string()),
+            localizations: vec![LocalizedContent { locale: "pt".to_string(), title: None, message: "mensagem".to_string() }],
+            ..Notification::new("recipient".to_string(), "message".to_string())
+        };
+
+        notification.localize(&["en-GB".to_string(), "en".to_string(), "pt".to_string()]);
+
+        assert_eq!(notification.locale, Some("en".to_string()));
+        assert_eq!(notification.message, "message");
+    }
 }
\ No newline at end of file
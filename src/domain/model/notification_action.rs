@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ActionError {
+    #[error("Action {0} not found")]
+    UnknownAction(String),
+    #[error("An action was already taken on notification {0}")]
+    AlreadyResolved(String),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionStyle {
+    Primary,
+    #[default]
+    Secondary,
+    Danger,
+}
+
+/// Button offered along with a notification, such as "Approve" or "Reject".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct NotificationAction {
+    pub id: String,
+    pub label: String,
+    #[serde(default)]
+    pub style: ActionStyle,
+    /// Link opened by the client; without one the action is a callback, handled by the webhooks receiving
+    /// `action_taken` events.
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// The last action taken on a notification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ActionTaken {
+    pub action_id: String,
+    pub date_taken: DateTime<Utc>,
+}
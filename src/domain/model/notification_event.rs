@@ -0,0 +1,66 @@
+use crate::application::service::clock_service::ClockService;
+use crate::domain::model::notification::Notification;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEventType {
+    Created,
+    Seen,
+    Deleted,
+    /// An action was taken on the notification, which carries it as `action_taken`.
+    ActionTaken,
+    Snoozed,
+    Pinned,
+    Unpinned,
+    Archived,
+    Unarchived,
+    Tagged,
+    Untagged,
+    /// A snoozed notification is back in the inbox, which may warrant delivering it again.
+    Resurfaced,
+    /// Created notifications delivered together in a digest; only ever sent to webhooks.
+    Digest,
+}
+
+impl NotificationEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationEventType::Created => "created",
+            NotificationEventType::Seen => "seen",
+            NotificationEventType::Deleted => "deleted",
+            NotificationEventType::ActionTaken => "action_taken",
+            NotificationEventType::Snoozed => "snoozed",
+            NotificationEventType::Pinned => "pinned",
+            NotificationEventType::Unpinned => "unpinned",
+            NotificationEventType::Archived => "archived",
+            NotificationEventType::Unarchived => "unarchived",
+            NotificationEventType::Tagged => "tagged",
+            NotificationEventType::Untagged => "untagged",
+            NotificationEventType::Resurfaced => "resurfaced",
+            NotificationEventType::Digest => "digest",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationEvent {
+    pub uuid: Uuid,
+    pub event_type: NotificationEventType,
+    pub notification: Notification,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl NotificationEvent {
+    pub fn new(event_type: NotificationEventType, notification: Notification) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            event_type,
+            notification,
+            occurred_at: ClockService::now(),
+        }
+    }
+}
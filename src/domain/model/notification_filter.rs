@@ -0,0 +1,61 @@
+use crate::domain::model::notification::Notification;
+use chrono::{DateTime, Utc};
+
+/// Which notifications of an inbox a listing returns; by default those not archived.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationFilter {
+    /// Whether to return the archived notifications or the others, `None` returning both.
+    pub archived: Option<bool>,
+    pub pinned_only: bool,
+    /// Only returns the notifications with this tag.
+    pub tag: Option<String>,
+    /// Only returns the notifications changed after this time.
+    pub updated_since: Option<DateTime<Utc>>,
+}
+
+impl Default for NotificationFilter {
+    fn default() -> Self {
+        Self { archived: Some(false), pinned_only: false, tag: None, updated_since: None }
+    }
+}
+
+impl NotificationFilter {
+    pub fn matches(&self, notification: &Notification) -> bool {
+        self.archived.is_none_or(|archived| notification.archived == archived)
+            && (!self.pinned_only || notification.pinned)
+            && self.tag.as_ref().is_none_or(|tag| notification.tags.contains(tag))
+            && self.updated_since.is_none_or(|since| notification.last_updated > since)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        let mut pinned = Notification::new("recipient".to_string(), "pinned".to_string());
+        pinned.set_pinned(true);
+        let mut archived = Notification::new("recipient".to_string(), "archived".to_string());
+        archived.set_archived(true);
+        let plain = Notification::new("recipient".to_string(), "plain".to_string());
+        let mut tagged = Notification::new("recipient".to_string(), "tagged".to_string());
+        tagged.add_tag("billing".to_string());
+
+        let inbox = NotificationFilter::default();
+        let pinned_only = NotificationFilter { pinned_only: true, ..NotificationFilter::default() };
+        let archive = NotificationFilter { archived: Some(true), ..NotificationFilter::default() };
+        let any = NotificationFilter { archived: None, ..NotificationFilter::default() };
+        let billing = NotificationFilter { tag: Some("billing".to_string()), ..NotificationFilter::default() };
+        let since = plain.last_updated;
+        tagged.last_updated = since + chrono::Duration::seconds(1);
+        let updated = NotificationFilter { updated_since: Some(since), ..NotificationFilter::default() };
+
+        assert!(inbox.matches(&pinned) && inbox.matches(&plain) && !inbox.matches(&archived));
+        assert!(pinned_only.matches(&pinned) && !pinned_only.matches(&plain));
+        assert!(archive.matches(&archived) && !archive.matches(&plain));
+        assert!(any.matches(&archived) && any.matches(&plain));
+        assert!(billing.matches(&tagged) && !billing.matches(&plain));
+        assert!(updated.matches(&tagged) && !updated.matches(&plain));
+    }
+}
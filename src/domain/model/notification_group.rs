@@ -0,0 +1,115 @@
+use crate::domain::model::notification::Notification;
+use std::collections::HashMap;
+
+/// Notifications of a recipient sharing a group key, collapsed into one inbox entry.
+#[derive(Debug, Clone)]
+pub struct NotificationGroup {
+    /// `None` for a notification without a group key, which stands alone.
+    pub group_key: Option<String>,
+    pub count: usize,
+    pub unseen: usize,
+    /// Whether any notification of the group is pinned, which lists the group first.
+    pub pinned: bool,
+    /// The most recent notification of the group.
+    pub latest: Notification,
+    /// Distinct actors of the group, most recent first.
+    pub actors: Vec<String>,
+}
+
+impl NotificationGroup {
+    fn new(notification: Notification) -> Self {
+        Self {
+            group_key: notification.group_key.clone(),
+            count: 1,
+            unseen: usize::from(!notification.seen),
+            pinned: notification.pinned,
+            actors: notification.actor.iter().cloned().collect(),
+            latest: notification,
+        }
+    }
+
+    fn push(&mut self, notification: Notification) {
+        self.count += 1;
+        self.unseen += usize::from(!notification.seen);
+        self.pinned |= notification.pinned;
+        if let Some(actor) = &notification.actor {
+            self.actors.retain(|known| known != actor);
+            self.actors.insert(0, actor.clone());
+        }
+        self.latest = notification;
+    }
+
+    /// Collapses notifications, given by creation date, into groups ordered by their latest notification, pinned
+    /// groups first.
+    pub fn group(notifications: Vec<Notification>) -> Vec<NotificationGroup> {
+        let mut groups: Vec<NotificationGroup> = Vec::new();
+        let mut index_by_key: HashMap<String, usize> = HashMap::new();
+        for notification in notifications {
+            match notification.group_key.as_ref().and_then(|key| index_by_key.get(key)) {
+                Some(&index) => groups[index].push(notification),
+                None => {
+                    if let Some(key) = &notification.group_key {
+                        index_by_key.insert(key.clone(), groups.len());
+                    }
+                    groups.push(NotificationGroup::new(notification));
+                }
+            }
+        }
+        groups.sort_by_key(|group| (!group.pinned, group.latest.date_created));
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn notification(group_key: Option<&str>, actor: &str, minutes: i64) -> Notification {
+        let notification = Notification::new("recipient".to_string(), format!("{} commented", actor));
+        Notification {
+            group_key: group_key.map(str::to_string),
+            actor: Some(actor.to_string()),
+            date_created: notification.date_created + Duration::minutes(minutes),
+            ..notification
+        }
+    }
+
+    #[test]
+    fn test_group() {
+        let mut seen = notification(Some("post-1"), "bob", 0);
+        seen.set_as_seen();
+        let notifications = vec![
+            seen,
+            notification(None, "eve", 1),
+            notification(Some("post-1"), "ana", 2),
+            notification(Some("post-1"), "bob", 3),
+            notification(Some("post-2"), "ana", 4),
+        ];
+
+        let groups = NotificationGroup::group(notifications);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].group_key, None);
+        assert_eq!(groups[0].count, 1);
+        assert_eq!(groups[1].group_key, Some("post-1".to_string()));
+        assert_eq!(groups[1].count, 3);
+        assert_eq!(groups[1].unseen, 2);
+        assert_eq!(groups[1].latest.message, "bob commented");
+        assert_eq!(groups[1].actors, vec!["bob".to_string(), "ana".to_string()]);
+        assert_eq!(groups[2].group_key, Some("post-2".to_string()));
+    }
+
+    #[test]
+    fn test_group_lists_pinned_groups_first() {
+        let mut pinned = notification(Some("post-1"), "bob", 0);
+        pinned.set_pinned(true);
+        let notifications = vec![pinned, notification(Some("post-2"), "ana", 1), notification(Some("post-1"), "eve", 2)];
+
+        let groups = NotificationGroup::group(notifications);
+
+        assert_eq!(groups[0].group_key, Some("post-1".to_string()));
+        assert!(groups[0].pinned);
+        assert!(!groups[1].pinned);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::application::service::clock_service::ClockService;
+use crate::domain::model::broadcast::BroadcastReceipt;
+use crate::domain::model::notification::Notification;
+use crate::domain::model::notification_event::NotificationEventType;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Interface a change to a notification came through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeSource {
+    Rest,
+    #[serde(rename = "websocket")]
+    WebSocket,
+    /// The application itself, such as the worker resurfacing snoozed notifications.
+    System,
+}
+
+/// The part of a notification its recipient can change.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+pub struct NotificationState {
+    pub seen: bool,
+    pub deleted: bool,
+    pub pinned: bool,
+    pub archived: bool,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    /// Id of the last action taken.
+    pub action_taken: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl From<&Notification> for NotificationState {
+    fn from(notification: &Notification) -> Self {
+        Self {
+            seen: notification.seen,
+            deleted: notification.deleted,
+            pinned: notification.pinned,
+            archived: notification.archived,
+            snoozed_until: notification.snoozed_until,
+            action_taken: notification.action_taken.as_ref().map(|action_taken| action_taken.action_id.clone()),
+            tags: notification.tags.clone(),
+        }
+    }
+}
+
+impl From<&BroadcastReceipt> for NotificationState {
+    fn from(receipt: &BroadcastReceipt) -> Self {
+        Self { seen: receipt.seen, deleted: receipt.deleted, ..Self::default() }
+    }
+}
+
+/// Append-only record of a change to a notification, or to a broadcast for one recipient.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationHistoryEntry {
+    pub uuid: Uuid,
+    pub notification_uuid: Uuid,
+    pub event_type: NotificationEventType,
+    /// Recipient on whose behalf the change was made, if the caller named one.
+    pub changed_by: Option<String>,
+    pub source: ChangeSource,
+    pub from_state: NotificationState,
+    pub to_state: NotificationState,
+    pub date_created: DateTime<Utc>,
+}
+
+impl NotificationHistoryEntry {
+    pub fn new(
+        notification_uuid: Uuid,
+        event_type: NotificationEventType,
+        changed_by: Option<String>,
+        source: ChangeSource,
+        from_state: NotificationState,
+        to_state: NotificationState,
+    ) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            notification_uuid,
+            event_type,
+            changed_by,
+            source,
+            from_state,
+            to_state,
+            date_created: ClockService::now(),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use crate::domain::model::notification::Notification;
+use crate::domain::model::notification_template::escape_html;
+use std::ops::Range;
+
+/// Words kept around the first match when a snippet is cut out of a longer text.
+const SNIPPET_WORDS: usize = 20;
+const SNIPPET_WORDS_BEFORE_MATCH: usize = 5;
+
+/// Words of a search, matched whole and regardless of case; a text matches when it holds all of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchQuery {
+    terms: Vec<String>,
+}
+
+/// A notification found by a search, with its relevance and the matching part of its text.
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub notification: Notification,
+    /// Higher is more relevant; only comparable between hits of the same search.
+    pub rank: f64,
+    /// Matching part of the message, or of the title when only it matches, as HTML: the text is escaped and matches
+    /// are wrapped in `<mark>`.
+    pub snippet: String,
+}
+
+fn words(text: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (index, character) in text.char_indices() {
+        match (character.is_alphanumeric(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(word_start)) => {
+                words.push(word_start..index);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(word_start) = start {
+        words.push(word_start..text.len());
+    }
+    words
+}
+
+impl SearchQuery {
+    pub fn new(query: &str) -> Self {
+        let mut terms: Vec<String> = Vec::new();
+        for word in words(query) {
+            let term = query[word].to_lowercase();
+            if !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+        Self { terms }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    fn is_term(&self, word: &str) -> bool {
+        self.terms.contains(&word.to_lowercase())
+    }
+
+    fn occurrences(&self, text: &str) -> Vec<String> {
+        words(text).into_iter()
+            .map(|word| text[word].to_lowercase())
+            .filter(|word| self.terms.contains(word))
+            .collect()
+    }
+
+    /// Ranks by how often the terms occur, title matches counting double, or `None` when a term is missing.
+    pub fn rank(&self, title: Option<&str>, message: &str) -> Option<f64> {
+        let title_occurrences = title.map(|title| self.occurrences(title)).unwrap_or_default();
+        let message_occurrences = self.occurrences(message);
+        let all_found = self.terms.iter()
+            .all(|term| title_occurrences.contains(term) || message_occurrences.contains(term));
+        if self.is_empty() || !all_found {
+            return None;
+        }
+        Some((2 * title_occurrences.len() + message_occurrences.len()) as f64)
+    }
+
+    /// Cuts the text around its first match, marking the matches and eliding what was left out with `…`. The text
+    /// is escaped, so the `<mark>` tags are the only markup in the snippet.
+    pub fn highlight(&self, text: &str) -> String {
+        let words = words(text);
+        if words.is_empty() {
+            return escape_html(text);
+        }
+        let first_match = words.iter().position(|word| self.is_term(&text[word.clone()])).unwrap_or(0);
+        let first = first_match.saturating_sub(SNIPPET_WORDS_BEFORE_MATCH);
+        let last = (first + SNIPPET_WORDS).min(words.len()) - 1;
+
+        let start = if first == 0 { 0 } else { words[first].start };
+        let end = if last == words.len() - 1 { text.len() } else { words[last].end };
+        let mut snippet = String::new();
+        if start > 0 {
+            snippet.push('…');
+        }
+        let mut position = start;
+        for word in &words[first..=last] {
+            if self.is_term(&text[word.clone()]) {
+                snippet.push_str(&escape_html(&text[position..word.start]));
+                snippet.push_str("<mark>");
+                snippet.push_str(&escape_html(&text[word.clone()]));
+                snippet.push_str("</mark>");
+                position = word.end;
+            }
+        }
+        snippet.push_str(&escape_html(&text[position..end]));
+        if end < text.len() {
+            snippet.push('…');
+        }
+        snippet
+    }
+
+    /// The hit for the notification, when it matches.
+    pub fn search(&self, notification: Notification) -> Option<SearchHit> {
+        let rank = self.rank(notification.title.as_deref(), &notification.message)?;
+        let snippet = match &notification.title {
+            Some(title) if self.occurrences(&notification.message).is_empty() => self.highlight(title),
+            _ => self.highlight(&notification.message),
+        };
+        Some(SearchHit { notification, rank, snippet })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_requires_every_term() {
+        let query = SearchQuery::new("Invoice, PAID invoice");
+
+        assert_eq!(query.rank(Some("Invoice 42"), "Your invoice was paid"), Some(4.0));
+        assert_eq!(query.rank(None, "Your invoice is due"), None);
+        assert_eq!(query.rank(None, "Your invoices were paid"), None);
+        assert_eq!(SearchQuery::new(" ,.").rank(None, "anything"), None);
+    }
+
+    #[test]
+    fn test_highlight_marks_matches() {
+        let query = SearchQuery::new("paid");
+
+        assert_eq!(query.highlight("Invoice paid, thanks!"), "Invoice <mark>paid</mark>, thanks!");
+    }
+
+    #[test]
+    fn test_highlight_escapes_text() {
+        let query = SearchQuery::new("script");
+
+        assert_eq!(
+            query.highlight("Tom & Jerry <script>alert(1)</script>"),
+            "Tom &amp; Jerry &lt;<mark>script</mark>&gt;alert(1)&lt;/<mark>script</mark>&gt;",
+        );
+    }
+
+    #[test]
+    fn test_highlight_cuts_long_text_around_first_match() {
+        let text = (1..=40).map(|number| format!("w{}", number)).collect::<Vec<String>>().join(" ");
+        let query = SearchQuery::new("w30");
+
+        let snippet = query.highlight(&text);
+
+        assert!(snippet.starts_with("…w25 "));
+        assert!(snippet.contains("<mark>w30</mark>"));
+        assert!(snippet.ends_with(" w40"));
+    }
+
+    #[test]
+    fn test_search_highlights_title_when_only_it_matches() {
+        let notification = Notification {
+            title: Some("Order 42 shipped".to_string()),
+            ..Notification::new("recipient".to_string(), "It is on its way".to_string())
+        };
+
+        let hit = SearchQuery::new("order").search(notification).unwrap();
+
+        assert_eq!(hit.snippet, "<mark>Order</mark> 42 shipped");
+        assert_eq!(hit.rank, 2.0);
+    }
+}
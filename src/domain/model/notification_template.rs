@@ -0,0 +1,337 @@
+use crate::application::service::clock_service::ClockService;
+use crate::domain::model::localisation::resolve_locale;
+use crate::domain::model::preference::Channel;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateFormat {
+    /// Variables are substituted verbatim, for in-app and push content.
+    #[default]
+    Text,
+    /// Variables are HTML escaped, for email bodies.
+    Html,
+}
+
+impl TemplateFormat {
+    /// Format of the content for a notification meant for the given channel, if any.
+    pub fn for_channel(channel: Option<Channel>) -> Self {
+        match channel {
+            Some(Channel::Email) => TemplateFormat::Html,
+            Some(Channel::Push) | Some(Channel::Sms) | None => TemplateFormat::Text,
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum TemplateError {
+    #[error("Placeholder opened at position {0} is never closed")]
+    UnclosedPlaceholder(usize),
+    #[error("Placeholder at position {0} has an invalid variable name")]
+    InvalidPlaceholder(usize),
+    #[error("Missing template variables: {}", .0.join(", "))]
+    MissingVariables(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedTemplate {
+    pub title: String,
+    pub body: String,
+}
+
+/// The template content in a locale other than the template's own.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateVariant {
+    pub locale: String,
+    pub title: String,
+    pub body: String,
+}
+
+/// Notification content with `{{variable}}` placeholders, versioned so producers can tell which revision they rendered.
+#[derive(Debug, Clone)]
+pub struct NotificationTemplate {
+    pub id: String,
+    pub version: u32,
+    pub locale: String,
+    pub title: String,
+    pub body: String,
+    pub variants: Vec<TemplateVariant>,
+    pub date_created: DateTime<Utc>,
+    pub last_updated: DateTime<Utc>,
+}
+
+impl NotificationTemplate {
+    pub fn new(id: String, locale: String, title: String, body: String) -> Self {
+        let now = ClockService::now();
+        Self { id, version: 1, locale, title, body, variants: Vec::new(), date_created: now, last_updated: now }
+    }
+
+    pub fn update(&mut self, locale: String, title: String, body: String) {
+        self.locale = locale;
+        self.title = title;
+        self.body = body;
+        self.version += 1;
+        self.last_updated = ClockService::now();
+    }
+
+    /// Adds or replaces the variant of `locale`, or the template's own content when it is in that locale.
+    pub fn set_variant(&mut self, locale: String, title: String, body: String) {
+        if self.locale.eq_ignore_ascii_case(&locale) {
+            self.title = title;
+            self.body = body;
+        } else if let Some(variant) = self.variants.iter_mut().find(|variant| variant.locale.eq_ignore_ascii_case(&locale)) {
+            variant.title = title;
+            variant.body = body;
+        } else {
+            self.variants.push(TemplateVariant { locale, title, body });
+        }
+        self.version += 1;
+        self.last_updated = ClockService::now();
+    }
+
+    /// Returns whether a variant was removed; the template's own locale cannot be removed.
+    pub fn remove_variant(&mut self, locale: &str) -> bool {
+        let count = self.variants.len();
+        self.variants.retain(|variant| !variant.locale.eq_ignore_ascii_case(locale));
+        let removed = self.variants.len() != count;
+        if removed {
+            self.version += 1;
+            self.last_updated = ClockService::now();
+        }
+        removed
+    }
+
+    /// Makes the variant matching the first possible candidate the template's content.
+    pub fn localize(&mut self, candidates: &[String]) {
+        let mut available: Vec<&str> = self.variants.iter().map(|variant| variant.locale.as_str()).collect();
+        available.insert(0, &self.locale);
+
+        let Some(resolved) = resolve_locale(&available, candidates).map(str::to_string) else { return };
+        if let Some(index) = self.variants.iter().position(|variant| variant.locale == resolved) {
+            let variant = self.variants.remove(index);
+            self.variants.push(TemplateVariant {
+                locale: std::mem::replace(&mut self.locale, variant.locale),
+                title: std::mem::replace(&mut self.title, variant.title),
+                body: std::mem::replace(&mut self.body, variant.body),
+            });
+        }
+    }
+
+    /// Fails on malformed placeholders, so broken templates are rejected when saved rather than when rendered.
+    pub fn validate(&self) -> Result<(), TemplateError> {
+        placeholders(&self.title)?;
+        placeholders(&self.body)?;
+        for variant in &self.variants {
+            placeholders(&variant.title)?;
+            placeholders(&variant.body)?;
+        }
+        Ok(())
+    }
+
+    /// Renders every variant, keyed by locale, with the same rules as `render`.
+    pub fn render_variants(
+        &self,
+        variables: &HashMap<String, String>,
+        format: TemplateFormat,
+    ) -> Result<Vec<(String, RenderedTemplate)>, TemplateError> {
+        self.variants.iter()
+            .map(|variant| {
+                let template = NotificationTemplate {
+                    locale: variant.locale.clone(),
+                    title: variant.title.clone(),
+                    body: variant.body.clone(),
+                    variants: Vec::new(),
+                    ..self.clone()
+                };
+                Ok((variant.locale.clone(), template.render(variables, format)?))
+            })
+            .collect()
+    }
+
+    /// Every placeholder must have a variable; unused variables are ignored.
+    pub fn render(&self, variables: &HashMap<String, String>, format: TemplateFormat) -> Result<RenderedTemplate, TemplateError> {
+        let mut missing: Vec<String> = Vec::new();
+        for name in placeholders(&self.title)?.into_iter().chain(placeholders(&self.body)?) {
+            if !variables.contains_key(name) && !missing.iter().any(|missing_name| missing_name == name) {
+                missing.push(name.to_string());
+            }
+        }
+        if !missing.is_empty() {
+            return Err(TemplateError::MissingVariables(missing));
+        }
+
+        Ok(RenderedTemplate {
+            title: substitute(&self.title, variables, format),
+            body: substitute(&self.body, variables, format),
+        })
+    }
+}
+
+/// Byte range of a `{{ name }}` placeholder and its trimmed variable name.
+struct Placeholder<'a> {
+    start: usize,
+    end: usize,
+    name: &'a str,
+}
+
+fn parse(text: &str) -> Result<Vec<Placeholder<'_>>, TemplateError> {
+    let mut parsed = Vec::new();
+    let mut offset = 0;
+    while let Some(open) = text[offset..].find("{{") {
+        let start = offset + open;
+        let close = text[start + 2..].find("}}").ok_or(TemplateError::UnclosedPlaceholder(start))?;
+        let end = start + 2 + close + 2;
+        let name = text[start + 2..end - 2].trim();
+        let valid = !name.is_empty()
+            && name.chars().all(|character| character.is_ascii_alphanumeric() || character == '_' || character == '.');
+        if !valid {
+            return Err(TemplateError::InvalidPlaceholder(start));
+        }
+        parsed.push(Placeholder { start, end, name });
+        offset = end;
+    }
+    Ok(parsed)
+}
+
+fn placeholders(text: &str) -> Result<Vec<&str>, TemplateError> {
+    Ok(parse(text)?.into_iter().map(|placeholder| placeholder.name).collect())
+}
+
+/// Only called on validated text where every variable is present.
+fn substitute(text: &str, variables: &HashMap<String, String>, format: TemplateFormat) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    let mut offset = 0;
+    for placeholder in parse(text).unwrap_or_default() {
+        rendered.push_str(&text[offset..placeholder.start]);
+        let value = &variables[placeholder.name];
+        match format {
+            TemplateFormat::Text => rendered.push_str(value),
+            TemplateFormat::Html => rendered.push_str(&escape_html(value)),
+        }
+        offset = placeholder.end;
+    }
+    rendered.push_str(&text[offset..]);
+    rendered
+}
+
+pub(crate) fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(title: &str, body: &str) -> NotificationTemplate {
+        NotificationTemplate::new("order_shipped".to_string(), "en".to_string(), title.to_string(), body.to_string())
+    }
+
+    fn variables(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn test_render_substitutes_variables() {
+        let template = template("Order {{order}}", "Hi {{ name }}, order {{order}} has shipped.");
+
+        let result = template.render(&variables(&[("name", "Ana"), ("order", "42"), ("unused", "x")]), TemplateFormat::Text);
+
+        assert_eq!(result.unwrap(), RenderedTemplate {
+            title: "Order 42".to_string(),
+            body: "Hi Ana, order 42 has shipped.".to_string(),
+        });
+    }
+
+    #[test]
+    fn test_render_missing_variables_error() {
+        let template = template("Order {{order}}", "Hi {{name}}, {{order}} {{eta}}");
+
+        let result = template.render(&variables(&[("name", "Ana")]), TemplateFormat::Text);
+
+        assert_eq!(result, Err(TemplateError::MissingVariables(vec!["order".to_string(), "eta".to_string()])));
+    }
+
+    #[test]
+    fn test_render_html_escapes_variables() {
+        let template = template("<b>{{name}}</b>", "{{name}}");
+
+        let result = template.render(&variables(&[("name", "<script>\"&'")]), TemplateFormat::Html).unwrap();
+
+        assert_eq!(result.title, "<b>&lt;script&gt;&quot;&amp;&#39;</b>");
+        assert_eq!(result.body, "&lt;script&gt;&quot;&amp;&#39;");
+    }
+
+    #[test]
+    fn test_validate_malformed_placeholders_error() {
+        assert_eq!(template("Hi {{name", "").validate(), Err(TemplateError::UnclosedPlaceholder(3)));
+        assert_eq!(template("", "Hi {{ }}").validate(), Err(TemplateError::InvalidPlaceholder(3)));
+        assert_eq!(template("", "Hi {{first name}}").validate(), Err(TemplateError::InvalidPlaceholder(3)));
+    }
+
+    #[test]
+    fn test_set_and_remove_variant() {
+        let mut template = template("Hi", "Hello");
+
+        template.set_variant("pt".to_string(), "Olá".to_string(), "Olá {{name}}".to_string());
+        template.set_variant("EN".to_string(), "Hey".to_string(), "Hey there".to_string());
+
+        assert_eq!(template.version, 3);
+        assert_eq!(template.title, "Hey");
+        assert_eq!(template.variants.len(), 1);
+        assert!(template.remove_variant("PT"));
+        assert!(!template.remove_variant("en"));
+        assert_eq!(template.version, 4);
+    }
+
+    #[test]
+    fn test_localize_swaps_content() {
+        let mut template = template("Hi", "Hello");
+        template.set_variant("pt".to_string(), "Olá".to_string(), "Olá!".to_string());
+
+        template.localize(&["pt".to_string()]);
+
+        assert_eq!(template.locale, "pt");
+        assert_eq!(template.body, "Olá!");
+        assert_eq!(template.variants, vec![TemplateVariant {
+            locale: "en".to_string(),
+            title: "Hi".to_string(),
+            body: "Hello".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn test_render_variants() {
+        let mut template = template("Hi", "Hello {{name}}");
+        template.set_variant("pt".to_string(), "Olá".to_string(), "Olá {{name}}".to_string());
+
+        let result = template.render_variants(&variables(&[("name", "Ana")]), TemplateFormat::Text).unwrap();
+
+        assert_eq!(result, vec![("pt".to_string(), RenderedTemplate { title: "Olá".to_string(), body: "Olá Ana".to_string() })]);
+    }
+
+    #[test]
+    fn test_update_increments_version() {
+        let mut template = template("title", "body");
+
+        template.update("es".to_string(), "título".to_string(), "cuerpo".to_string());
+
+        assert_eq!(template.version, 2);
+        assert_eq!(template.locale, "es");
+        assert_eq!(template.body, "cuerpo");
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Notifications without a category belong to this one.
+pub const DEFAULT_CATEGORY: &str = "general";
+
+/// Channels notifications are delivered through outside of the inbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Channel {
+    Email,
+    Push,
+    Sms,
+}
+
+impl Channel {
+    pub const ALL: [Channel; 3] = [Channel::Email, Channel::Push, Channel::Sms];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Channel::Email => "email",
+            Channel::Push => "push",
+            Channel::Sms => "sms",
+        }
+    }
+}
+
+/// Opt-in or opt-out of a recipient for one category on one channel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ChannelPreference {
+    pub category: String,
+    pub channel: Channel,
+    pub enabled: bool,
+}
+
+/// Preference in effect, telling whether it comes from the category default or the recipient.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectivePreference {
+    pub category: String,
+    pub channel: Channel,
+    pub enabled: bool,
+    pub default: bool,
+}
@@ -0,0 +1,145 @@
+use crate::domain::model::priority::Priority;
+use chrono::{DateTime, Days, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Daily window, in the recipient's time zone, during which channel deliveries wait.
+/// A window whose `end` is before its `start` spans midnight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct QuietWindow {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    #[schema(value_type = String, example = "Europe/Lisbon")]
+    pub time_zone: Tz,
+}
+
+impl QuietWindow {
+    /// End of the window when `at` falls inside it.
+    pub fn ends_after(&self, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let local = at.with_timezone(&self.time_zone);
+        let time = local.time();
+        let inside = if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        };
+        if !inside {
+            return None;
+        }
+
+        let mut date = local.date_naive();
+        if time >= self.end {
+            date = date + Days::new(1);
+        }
+        Some(self.to_utc(date.and_time(self.end)))
+    }
+
+    /// Local times skipped by a daylight saving change resolve to the first valid time after them.
+    fn to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        let mut local = local;
+        loop {
+            if let Some(resolved) = self.time_zone.from_local_datetime(&local).earliest() {
+                return resolved.with_timezone(&Utc);
+            }
+            local += chrono::Duration::minutes(15);
+        }
+    }
+}
+
+/// When a recipient does not want to be disturbed on their channels. The inbox is never affected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuietHours {
+    pub window: Option<QuietWindow>,
+    /// Holds every channel delivery until then, on top of the daily window.
+    pub do_not_disturb_until: Option<DateTime<Utc>>,
+    /// Whether urgent notifications are delivered anyway.
+    pub allow_urgent: bool,
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self { window: None, do_not_disturb_until: None, allow_urgent: true }
+    }
+}
+
+impl QuietHours {
+    /// When a delivery of the given priority requested at `at` may happen, or `None` when it may happen right away.
+    pub fn deferred_until(&self, at: DateTime<Utc>, priority: Priority) -> Option<DateTime<Utc>> {
+        if priority == Priority::Urgent && self.allow_urgent {
+            return None;
+        }
+
+        let do_not_disturb_until = self.do_not_disturb_until.filter(|until| *until > at);
+        let resumed_at = do_not_disturb_until.unwrap_or(at);
+        self.window.as_ref()
+            .and_then(|window| window.ends_after(resumed_at))
+            .or(do_not_disturb_until)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> NaiveTime {
+        value.parse().unwrap()
+    }
+
+    fn instant(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    fn window(start: &str, end: &str) -> QuietWindow {
+        QuietWindow { start: time(start), end: time(end), time_zone: chrono_tz::Europe::Lisbon }
+    }
+
+    #[test]
+    fn test_window_within_a_day() {
+        let window = window("13:00", "14:00");
+
+        assert_eq!(window.ends_after(instant("2026-01-10T13:30:00Z")), Some(instant("2026-01-10T14:00:00Z")));
+        assert_eq!(window.ends_after(instant("2026-01-10T14:00:00Z")), None);
+        assert_eq!(window.ends_after(instant("2026-01-10T12:59:00Z")), None);
+    }
+
+    #[test]
+    fn test_window_spanning_midnight_in_time_zone() {
+        let window = window("22:00", "07:00");
+
+        // Lisbon is UTC+1 in summer
+        assert_eq!(window.ends_after(instant("2026-07-10T21:30:00Z")), Some(instant("2026-07-11T06:00:00Z")));
+        assert_eq!(window.ends_after(instant("2026-07-11T05:00:00Z")), Some(instant("2026-07-11T06:00:00Z")));
+        assert_eq!(window.ends_after(instant("2026-07-11T06:00:00Z")), None);
+        assert_eq!(window.ends_after(instant("2026-07-10T20:59:00Z")), None);
+    }
+
+    #[test]
+    fn test_window_ending_in_skipped_local_time() {
+        // clocks in Lisbon jump from 01:00 to 02:00 on 2026-03-29
+        let window = window("00:00", "01:30");
+
+        assert_eq!(window.ends_after(instant("2026-03-29T00:30:00Z")), Some(instant("2026-03-29T01:00:00Z")));
+    }
+
+    #[test]
+    fn test_deferred_until() {
+        let quiet_hours = QuietHours {
+            window: Some(window("22:00", "07:00")),
+            do_not_disturb_until: Some(instant("2026-01-10T20:00:00Z")),
+            allow_urgent: true,
+        };
+
+        assert_eq!(quiet_hours.deferred_until(instant("2026-01-10T12:00:00Z"), Priority::Normal), Some(instant("2026-01-10T20:00:00Z")));
+        assert_eq!(quiet_hours.deferred_until(instant("2026-01-10T22:30:00Z"), Priority::High), Some(instant("2026-01-11T07:00:00Z")));
+        assert_eq!(quiet_hours.deferred_until(instant("2026-01-11T08:00:00Z"), Priority::Low), None);
+        assert_eq!(quiet_hours.deferred_until(instant("2026-01-10T22:30:00Z"), Priority::Urgent), None);
+
+        // do not disturb ending within the window lasts until the window ends
+        let extended = QuietHours { do_not_disturb_until: Some(instant("2026-01-10T23:00:00Z")), ..quiet_hours.clone() };
+        assert_eq!(extended.deferred_until(instant("2026-01-10T12:00:00Z"), Priority::Normal), Some(instant("2026-01-11T07:00:00Z")));
+
+        let quiet_hours = QuietHours { allow_urgent: false, ..quiet_hours };
+        assert_eq!(quiet_hours.deferred_until(instant("2026-01-10T22:30:00Z"), Priority::Urgent), Some(instant("2026-01-11T07:00:00Z")));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::application::service::clock_service::ClockService;
+use crate::domain::model::digest::DigestFrequency;
+use crate::domain::model::preference::{Channel, ChannelPreference};
+use crate::domain::model::quiet_hours::QuietHours;
+use chrono::{DateTime, Utc};
+
+/// Per-recipient settings; a recipient without a stored profile gets the defaults.
+#[derive(Debug, Clone)]
+pub struct RecipientProfile {
+    pub recipient: String,
+    pub locale: Option<String>,
+    /// Choices of the recipient; categories and channels without one use the category default.
+    pub preferences: Vec<ChannelPreference>,
+    pub quiet_hours: QuietHours,
+    /// How often low-priority notifications are delivered together; each one is delivered on its own when `None`.
+    pub digest_frequency: Option<DigestFrequency>,
+    pub last_updated: DateTime<Utc>,
+}
+
+impl RecipientProfile {
+    pub fn new(recipient: String) -> Self {
+        Self {
+            recipient,
+            locale: None,
+            preferences: Vec::new(),
+            quiet_hours: QuietHours::default(),
+            digest_frequency: None,
+            last_updated: ClockService::now(),
+        }
+    }
+
+    pub fn set_locale(&mut self, locale: Option<String>) {
+        self.locale = locale;
+        self.last_updated = ClockService::now();
+    }
+
+    pub fn set_preference(&mut self, preference: ChannelPreference) {
+        self.preferences.retain(|existing| existing.category != preference.category || existing.channel != preference.channel);
+        self.preferences.push(preference);
+        self.last_updated = ClockService::now();
+    }
+
+    pub fn set_quiet_hours(&mut self, quiet_hours: QuietHours) {
+        self.quiet_hours = quiet_hours;
+        self.last_updated = ClockService::now();
+    }
+
+    pub fn set_digest_frequency(&mut self, digest_frequency: Option<DigestFrequency>) {
+        self.digest_frequency = digest_frequency;
+        self.last_updated = ClockService::now();
+    }
+
+    pub fn preference(&self, category: &str, channel: Channel) -> Option<bool> {
+        self.preferences.iter()
+            .find(|preference| preference.category == category && preference.channel == channel)
+            .map(|preference| preference.enabled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_preference_replaces_previous_choice() {
+        let mut profile = RecipientProfile::new("recipient".to_string());
+
+        profile.set_preference(ChannelPreference { category: "marketing".to_string(), channel: Channel::Email, enabled: false });
+        profile.set_preference(ChannelPreference { category: "marketing".to_string(), channel: Channel::Email, enabled: true });
+
+        assert_eq!(profile.preferences.len(), 1);
+        assert_eq!(profile.preference("marketing", Channel::Email), Some(true));
+        assert_eq!(profile.preference("marketing", Channel::Push), None);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::domain::model::notification_event::NotificationEvent;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Delivery of an event to a subscription held back until `due_at`, such as during the recipient's quiet hours.
+#[derive(Debug, Clone)]
+pub struct ScheduledDelivery {
+    pub uuid: Uuid,
+    pub subscription_uuid: Uuid,
+    pub event: NotificationEvent,
+    pub due_at: DateTime<Utc>,
+}
+
+impl ScheduledDelivery {
+    pub fn new(subscription_uuid: Uuid, event: NotificationEvent, due_at: DateTime<Utc>) -> Self {
+        Self { uuid: Uuid::new_v4(), subscription_uuid, event, due_at }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::domain::model::notification::Notification;
+
+/// The notification as it stands once a state was asked for, and whether it had to change to reach it, as asking
+/// again for the state it is already in is allowed and changes nothing.
+#[derive(Debug, Clone)]
+pub struct StateChange {
+    pub notification: Notification,
+    pub changed: bool,
+}
@@ -0,0 +1,6 @@
+/// How many notifications of an inbox have a tag, to offer it as a filter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagCount {
+    pub tag: String,
+    pub count: usize,
+}
@@ -0,0 +1,29 @@
+use crate::application::service::clock_service::ClockService;
+use chrono::{DateTime, Utc};
+
+/// Audience recipients follow, such as a project, so producers can notify all of them at once.
+#[derive(Debug, Clone)]
+pub struct Topic {
+    pub id: String,
+    pub description: Option<String>,
+    pub date_created: DateTime<Utc>,
+}
+
+impl Topic {
+    pub fn new(id: String, description: Option<String>) -> Self {
+        Self { id, description, date_created: ClockService::now() }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TopicSubscription {
+    pub topic_id: String,
+    pub recipient: String,
+    pub date_created: DateTime<Utc>,
+}
+
+impl TopicSubscription {
+    pub fn new(topic_id: String, recipient: String) -> Self {
+        Self { topic_id, recipient, date_created: ClockService::now() }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::application::service::clock_service::ClockService;
+use crate::domain::model::priority::Priority;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PublicationStatus {
+    Pending,
+    InProgress,
+    Completed,
+}
+
+/// A notification published to a topic, fanned out to its subscribers in batches.
+///
+/// Subscribers are processed in recipient order and `cursor` holds the last one processed, so fan-out resumes where it
+/// stopped. Recipients subscribing while it runs are included when they sort after the cursor.
+#[derive(Debug, Clone)]
+pub struct TopicPublication {
+    pub uuid: Uuid,
+    pub topic_id: String,
+    pub message: String,
+    pub category: String,
+    pub priority: Priority,
+    pub status: PublicationStatus,
+    /// Subscribers when the notification was published.
+    pub total_recipients: usize,
+    pub processed_recipients: usize,
+    pub failed_recipients: usize,
+    pub cursor: Option<String>,
+    pub date_created: DateTime<Utc>,
+    pub last_updated: DateTime<Utc>,
+}
+
+impl TopicPublication {
+    pub fn new(topic_id: String, message: String, category: String, priority: Priority, total_recipients: usize) -> Self {
+        let now = ClockService::now();
+        Self {
+            uuid: Uuid::new_v4(),
+            topic_id,
+            message,
+            category,
+            priority,
+            status: PublicationStatus::Pending,
+            total_recipients,
+            processed_recipients: 0,
+            failed_recipients: 0,
+            cursor: None,
+            date_created: now,
+            last_updated: now,
+        }
+    }
+
+    /// Records a batch of subscribers, up to and including `last_recipient`, of which `failed` got no notification.
+    pub fn record_batch(&mut self, last_recipient: String, processed: usize, failed: usize) {
+        self.status = PublicationStatus::InProgress;
+        self.cursor = Some(last_recipient);
+        self.processed_recipients += processed;
+        self.failed_recipients += failed;
+        self.last_updated = ClockService::now();
+    }
+
+    pub fn complete(&mut self) {
+        self.status = PublicationStatus::Completed;
+        self.last_updated = ClockService::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_batch_and_complete() {
+        let mut publication = TopicPublication::new(
+            "project-x".to_string(), "message".to_string(), "general".to_string(), Priority::Normal, 3,
+        );
+
+        publication.record_batch("bob".to_string(), 2, 1);
+        publication.record_batch("carol".to_string(), 1, 0);
+
+        assert_eq!(publication.status, PublicationStatus::InProgress);
+        assert_eq!(publication.cursor, Some("carol".to_string()));
+        assert_eq!(publication.processed_recipients, 3);
+        assert_eq!(publication.failed_recipients, 1);
+
+        publication.complete();
+
+        assert_eq!(publication.status, PublicationStatus::Completed);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::application::service::clock_service::ClockService;
+use crate::domain::model::notification_event::NotificationEventType;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct WebhookDeliveryAttempt {
+    pub uuid: Uuid,
+    pub subscription_uuid: Uuid,
+    pub event_uuid: Uuid,
+    pub event_type: NotificationEventType,
+    pub attempt: u32,
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+    /// Why the event was not sent at all, in which case `attempt` is 0.
+    pub suppression_reason: Option<String>,
+    pub attempted_at: DateTime<Utc>,
+}
+
+impl WebhookDeliveryAttempt {
+    pub fn new(
+        subscription_uuid: Uuid,
+        event_uuid: Uuid,
+        event_type: NotificationEventType,
+        attempt: u32,
+        status_code: Option<u16>,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            subscription_uuid,
+            event_uuid,
+            event_type,
+            attempt,
+            status_code,
+            error,
+            suppression_reason: None,
+            attempted_at: ClockService::now(),
+        }
+    }
+
+    pub fn suppressed(subscription_uuid: Uuid, event_uuid: Uuid, event_type: NotificationEventType, reason: String) -> Self {
+        Self {
+            suppression_reason: Some(reason),
+            ..Self::new(subscription_uuid, event_uuid, event_type, 0, None, None)
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.status_code.is_some_and(|status_code| (200..300).contains(&status_code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_success() {
+        let attempt = |status_code| WebhookDeliveryAttempt::new(
+            Uuid::new_v4(), Uuid::new_v4(), NotificationEventType::Created, 1, status_code, None,
+        );
+
+        assert!(attempt(Some(204)).is_success());
+        assert!(!attempt(Some(500)).is_success());
+        assert!(!attempt(None).is_success());
+    }
+}
@@ -0,0 +1,63 @@
+use crate::application::service::clock_service::ClockService;
+use crate::domain::model::notification_event::NotificationEventType;
+use crate::domain::model::preference::Channel;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct WebhookSubscription {
+    pub uuid: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<NotificationEventType>,
+    /// Channel the subscriber delivers notifications through, making deliveries subject to recipient preferences.
+    pub channel: Option<Channel>,
+    pub date_created: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    pub fn new(url: String, secret: String, events: Vec<NotificationEventType>) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            url,
+            secret,
+            events,
+            channel: None,
+            date_created: ClockService::now(),
+        }
+    }
+
+    /// A subscription without an event filter receives every event.
+    pub fn accepts(&self, event_type: NotificationEventType) -> bool {
+        self.events.is_empty() || self.events.contains(&event_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_filtered_events() {
+        let subscription = WebhookSubscription::new(
+            "http://localhost/hook".to_string(),
+            "secret".to_string(),
+            vec![NotificationEventType::Seen],
+        );
+
+        assert!(subscription.accepts(NotificationEventType::Seen));
+        assert!(!subscription.accepts(NotificationEventType::Created));
+    }
+
+    #[test]
+    fn test_accepts_every_event_without_filter() {
+        let subscription = WebhookSubscription::new(
+            "http://localhost/hook".to_string(),
+            "secret".to_string(),
+            vec![],
+        );
+
+        assert!(subscription.accepts(NotificationEventType::Created));
+        assert!(subscription.accepts(NotificationEventType::Deleted));
+    }
+}
@@ -3,6 +3,183 @@ mod application;
 mod domain;
 mod port;
 
-fn main() {
-    println!("Hello, world!");
+use crate::adapter::incoming::rest::broadcast_api::BroadcastApi;
+use crate::adapter::incoming::rest::notification_api::{create_router, NotificationApi};
+use crate::adapter::incoming::rest::notification_stream_api::NotificationStreamApi;
+use crate::adapter::incoming::rest::notification_template_api::NotificationTemplateApi;
+use crate::adapter::incoming::rest::recipient_profile_api::RecipientProfileApi;
+use crate::adapter::incoming::rest::topic_api::TopicApi;
+use crate::adapter::incoming::rest::webhook_api::WebhookApi;
+use crate::adapter::incoming::sqs::notification_consumer::NotificationConsumer;
+use crate::adapter::incoming::websocket::notification_socket_api::NotificationSocketApi;
+use crate::adapter::incoming::sqs::sqs_client::HttpSqsClient;
+use crate::adapter::outgoing::event::in_process_event_bus::InProcessEventBus;
+use crate::adapter::outgoing::persistence::broadcast_repository::InMemoryBroadcastRepository;
+use crate::adapter::outgoing::persistence::digest_repository::InMemoryDigestRepository;
+use crate::adapter::outgoing::persistence::notification_repository::InMemoryNotificationRepository;
+use crate::adapter::outgoing::persistence::notification_template_repository::InMemoryNotificationTemplateRepository;
+use crate::adapter::outgoing::persistence::recipient_profile_repository::InMemoryRecipientProfileRepository;
+use crate::adapter::outgoing::persistence::scheduled_delivery_repository::InMemoryScheduledDeliveryRepository;
+use crate::adapter::outgoing::persistence::topic_publication_repository::InMemoryTopicPublicationRepository;
+use crate::adapter::outgoing::persistence::topic_repository::InMemoryTopicRepository;
+use crate::adapter::outgoing::persistence::webhook_delivery_attempt_repository::InMemoryWebhookDeliveryAttemptRepository;
+use crate::adapter::outgoing::persistence::webhook_subscription_repository::InMemoryWebhookSubscriptionRepository;
+use crate::adapter::outgoing::webhook::http_webhook_sender::HttpWebhookSender;
+use crate::application::configuration::application_state::AppState;
+use crate::application::configuration::loader::load_config;
+use crate::application::service::clock_service::ClockService;
+use crate::application::service::implementation::broadcast_service_implementation::BroadcastServiceImplementation;
+use crate::application::service::implementation::delivery_policy_service_implementation::DeliveryPolicyServiceImplementation;
+use crate::application::service::implementation::notification_service_implementation::NotificationServiceImplementation;
+use crate::application::service::implementation::notification_stream_service_implementation::NotificationStreamServiceImplementation;
+use crate::application::service::implementation::notification_template_service_implementation::NotificationTemplateServiceImplementation;
+use crate::application::service::implementation::recipient_profile_service_implementation::RecipientProfileServiceImplementation;
+use crate::application::service::implementation::token_authentication_service_implementation::TokenAuthenticationServiceImplementation;
+use crate::application::service::implementation::topic_service_implementation::TopicServiceImplementation;
+use crate::application::service::implementation::webhook_service_implementation::WebhookServiceImplementation;
+use crate::application::service::notification_service::NotificationServicePort;
+use crate::application::worker::delivery_scheduler_worker::DeliverySchedulerWorker;
+use crate::application::worker::digest_worker::DigestWorker;
+use crate::application::worker::outbox_relay_worker::OutboxRelayWorker;
+use crate::application::worker::snooze_worker::SnoozeWorker;
+use crate::application::worker::topic_fan_out_worker::TopicFanOutWorker;
+use crate::application::worker::webhook_delivery_worker::WebhookDeliveryWorker;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() {
+    let configuration = load_config().expect("Failed to load configuration");
+
+    let event_bus = Arc::new(InProcessEventBus::new());
+
+    let notification_repository = InMemoryNotificationRepository::new();
+
+    let template_repository = Arc::new(InMemoryNotificationTemplateRepository::new());
+    let template_service = Arc::new(NotificationTemplateServiceImplementation::new(template_repository.clone()));
+    let preference_settings = configuration.app.preferences;
+    let profile_repository = Arc::new(InMemoryRecipientProfileRepository::new());
+    let profile_service = Arc::new(RecipientProfileServiceImplementation::new(
+        profile_repository.clone(),
+        preference_settings.clone(),
+    ));
+    let broadcast_repository = Arc::new(InMemoryBroadcastRepository::new(notification_repository.clone()));
+    let broadcast_service = Arc::new(BroadcastServiceImplementation::new(
+        broadcast_repository.clone(),
+        preference_settings.clone(),
+    ));
+    let notification_service: Arc<dyn NotificationServicePort + Send + Sync> =
+        Arc::new(NotificationServiceImplementation::new(
+            notification_repository.clone(),
+            template_repository,
+            profile_repository.clone(),
+            broadcast_repository,
+            configuration.app.localisation.fallback_locales,
+            preference_settings.clone(),
+        ));
+
+    let notification_stream_service = Arc::new(NotificationStreamServiceImplementation::new(event_bus.clone()));
+    let authentication_service = Arc::new(TokenAuthenticationServiceImplementation::new(
+        configuration.app.authentication.token_secret,
+        configuration.app.authentication.administrators,
+    ));
+
+    let webhook_settings = configuration.app.outgoing.webhook;
+    let webhook_subscription_repository = Arc::new(InMemoryWebhookSubscriptionRepository::new());
+    let webhook_delivery_attempt_repository = Arc::new(InMemoryWebhookDeliveryAttemptRepository::new());
+    let digest_repository = Arc::new(InMemoryDigestRepository::new());
+    let webhook_service = Arc::new(WebhookServiceImplementation::new(
+        webhook_subscription_repository.clone(),
+        webhook_delivery_attempt_repository.clone(),
+        digest_repository.clone(),
+    ));
+    let scheduled_delivery_repository = Arc::new(InMemoryScheduledDeliveryRepository::new());
+
+    let clock = Arc::new(ClockService {});
+    let delivery_policy = Arc::new(DeliveryPolicyServiceImplementation::new(
+        profile_repository,
+        preference_settings.clone(),
+        clock.clone(),
+    ));
+    let webhook_delivery_worker = Arc::new(WebhookDeliveryWorker::new(
+        webhook_subscription_repository.clone(),
+        webhook_delivery_attempt_repository,
+        scheduled_delivery_repository.clone(),
+        digest_repository.clone(),
+        Arc::new(HttpWebhookSender::new(Duration::from_secs(webhook_settings.timeout_seconds))),
+        delivery_policy,
+        webhook_settings,
+    ));
+    let outbox_relay_worker = OutboxRelayWorker::new(
+        Arc::new(notification_repository.clone()),
+        event_bus.clone(),
+        webhook_delivery_worker.clone(),
+        configuration.app.outgoing.outbox,
+    );
+    tokio::spawn(outbox_relay_worker.run());
+    let delivery_scheduler_worker = DeliverySchedulerWorker::new(
+        scheduled_delivery_repository,
+        webhook_subscription_repository.clone(),
+        webhook_delivery_worker.clone(),
+        clock.clone(),
+        configuration.app.outgoing.scheduler,
+    );
+    tokio::spawn(delivery_scheduler_worker.run());
+    let digest_worker = DigestWorker::new(
+        digest_repository,
+        webhook_subscription_repository,
+        webhook_delivery_worker,
+        clock.clone(),
+        configuration.app.outgoing.digest,
+    );
+    tokio::spawn(digest_worker.run());
+
+    let topic_repository = Arc::new(InMemoryTopicRepository::new());
+    let topic_publication_repository = Arc::new(InMemoryTopicPublicationRepository::new());
+    let topic_service = Arc::new(TopicServiceImplementation::new(
+        topic_repository.clone(),
+        topic_publication_repository.clone(),
+        preference_settings,
+    ));
+    let topic_fan_out_worker = TopicFanOutWorker::new(
+        topic_publication_repository,
+        topic_repository,
+        notification_service.clone(),
+        configuration.app.fan_out,
+    );
+    tokio::spawn(topic_fan_out_worker.run());
+    let snooze_worker = SnoozeWorker::new(notification_service.clone(), clock, configuration.app.snooze);
+    tokio::spawn(snooze_worker.run());
+
+    let default_recipient = configuration.app.incoming.default_recipient;
+    let sqs_settings = configuration.app.incoming.sqs;
+    if sqs_settings.enabled {
+        let sqs_client = Arc::new(HttpSqsClient::new(sqs_settings.endpoint.clone()));
+        let consumer = NotificationConsumer::new(notification_service.clone(), sqs_client, sqs_settings, default_recipient.clone());
+        tokio::spawn(async move { consumer.run().await });
+    }
+
+    let state = Arc::new(AppState {
+        authentication_service,
+        broadcast_api: Arc::new(BroadcastApi::new(broadcast_service)),
+        notification_api: Arc::new(NotificationApi::new(notification_service.clone(), default_recipient.clone())),
+        notification_socket_api: Arc::new(NotificationSocketApi::new(
+            notification_service,
+            notification_stream_service.clone(),
+        )),
+        notification_stream_api: Arc::new(NotificationStreamApi::new(notification_stream_service)),
+        notification_template_api: Arc::new(NotificationTemplateApi::new(template_service)),
+        recipient_profile_api: Arc::new(RecipientProfileApi::new(profile_service)),
+        topic_api: Arc::new(TopicApi::new(topic_service)),
+        webhook_api: Arc::new(WebhookApi::new(webhook_service)),
+    });
+
+    let listener = TcpListener::bind(("0.0.0.0", configuration.app.incoming.rest.port))
+        .await
+        .expect("Failed to bind REST port");
+
+    axum::serve(listener, create_router(state))
+        .await
+        .expect("Failed to start REST server");
 }
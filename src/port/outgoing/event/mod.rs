@@ -0,0 +1,2 @@
+pub mod notification_event_publisher_port;
+pub mod notification_event_stream_port;
\ No newline at end of file
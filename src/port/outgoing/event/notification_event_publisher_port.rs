@@ -0,0 +1,10 @@
+use crate::application::error::ApplicationError;
+use crate::domain::model::notification_event::NotificationEvent;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait NotificationEventPublisherPort: Send + Sync {
+    async fn publish(&self, event: NotificationEvent) -> Result<(), ApplicationError>;
+}
@@ -0,0 +1,11 @@
+use crate::domain::model::notification_event::NotificationEvent;
+use mockall::automock;
+use tokio::sync::broadcast;
+
+#[automock]
+pub trait NotificationEventStreamPort: Send + Sync {
+    fn subscribe(&self) -> broadcast::Receiver<NotificationEvent>;
+
+    /// Most recently published events, oldest first, kept so disconnected subscribers can resume.
+    fn recent_events(&self) -> Vec<NotificationEvent>;
+}
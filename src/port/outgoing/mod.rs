@@ -1 +1,3 @@
-pub mod persistence;
\ No newline at end of file
+pub mod event;
+pub mod persistence;
+pub mod webhook;
\ No newline at end of file
@@ -0,0 +1,41 @@
+use crate::adapter::outgoing::persistence::entity::broadcast_entity::{BroadcastEntity, BroadcastReceiptEntity};
+use crate::adapter::outgoing::persistence::entity::notification_history_entity::NotificationHistoryEntity;
+use crate::adapter::outgoing::persistence::entity::outbox_event_entity::OutboxEventEntity;
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+#[automock]
+#[async_trait]
+pub trait BroadcastRepositoryPort: Send + Sync {
+    /// Saves the broadcast and appends `outbox_event` to the outbox in the same transaction.
+    async fn save(&self, broadcast: &BroadcastEntity, outbox_event: &OutboxEventEntity) -> Result<BroadcastEntity, ApplicationError>;
+
+    async fn get(&self, uuid: &Uuid) -> Result<BroadcastEntity, ApplicationError>;
+
+    /// Every broadcast, oldest first.
+    async fn get_all(&self) -> Result<Vec<BroadcastEntity>, ApplicationError>;
+
+    /// Deletes the broadcast together with its receipts.
+    async fn delete(&self, uuid: &Uuid) -> Result<(), ApplicationError>;
+
+    async fn get_receipt(&self, broadcast_uuid: &Uuid, recipient: &str) -> Result<BroadcastReceiptEntity, ApplicationError>;
+
+    /// Saves the receipt and appends `history_entry` to the history of the broadcast for its recipient.
+    async fn save_receipt(
+        &self,
+        receipt: &BroadcastReceiptEntity,
+        history_entry: &NotificationHistoryEntity,
+    ) -> Result<BroadcastReceiptEntity, ApplicationError>;
+
+    /// History of the broadcast for the recipient, oldest first.
+    async fn get_receipt_history(
+        &self,
+        broadcast_uuid: &Uuid,
+        recipient: &str,
+    ) -> Result<Vec<NotificationHistoryEntity>, ApplicationError>;
+
+    /// Receipts of the recipient; broadcasts the recipient has not acted on have none.
+    async fn get_receipts_of(&self, recipient: &str) -> Result<Vec<BroadcastReceiptEntity>, ApplicationError>;
+}
@@ -0,0 +1,29 @@
+use crate::adapter::outgoing::persistence::entity::digest_entity::DigestEntity;
+use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use uuid::Uuid;
+
+#[automock]
+#[async_trait]
+pub trait DigestRepositoryPort: Send + Sync {
+    async fn save(&self, digest: &DigestEntity) -> Result<DigestEntity, ApplicationError>;
+
+    /// Adds the notification to the open digest of the subscription and recipient in one step, opening one due at
+    /// `due_at` when there is none.
+    async fn append(
+        &self,
+        subscription_uuid: &Uuid,
+        recipient: &str,
+        notification: &NotificationEntity,
+        due_at: DateTime<Utc>,
+    ) -> Result<DigestEntity, ApplicationError>;
+
+    /// Open digests due at or before `until`, earliest first.
+    async fn get_due(&self, until: DateTime<Utc>, limit: usize) -> Result<Vec<DigestEntity>, ApplicationError>;
+
+    /// Every digest of the subscription, newest first.
+    async fn get_by_subscription(&self, subscription_uuid: &Uuid) -> Result<Vec<DigestEntity>, ApplicationError>;
+}
@@ -1 +1,11 @@
-pub mod notification_repository_port;
\ No newline at end of file
+pub mod broadcast_repository_port;
+pub mod digest_repository_port;
+pub mod notification_repository_port;
+pub mod notification_template_repository_port;
+pub mod outbox_repository_port;
+pub mod recipient_profile_repository_port;
+pub mod scheduled_delivery_repository_port;
+pub mod topic_publication_repository_port;
+pub mod topic_repository_port;
+pub mod webhook_delivery_attempt_repository_port;
+pub mod webhook_subscription_repository_port;
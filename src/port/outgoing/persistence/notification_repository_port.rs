@@ -1,15 +1,56 @@
 use crate::adapter::outgoing::persistence::entity::notification_entity::NotificationEntity;
+use crate::adapter::outgoing::persistence::entity::notification_history_entity::NotificationHistoryEntity;
+use crate::adapter::outgoing::persistence::entity::notification_search_hit_entity::NotificationSearchHitEntity;
+use crate::adapter::outgoing::persistence::entity::outbox_event_entity::OutboxEventEntity;
 use crate::application::error::ApplicationError;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::automock;
 use uuid::Uuid;
 
 #[automock]
 #[async_trait]
 pub trait NotificationRepositoryPort {
-    async fn save(&self, notification: &NotificationEntity) -> Result<NotificationEntity, ApplicationError>;
+    /// Saves the notification and appends `outbox_event` to the outbox, and `history_entry` to the history of the
+    /// notification when the save changes its state, in the same transaction.
+    ///
+    /// The save only succeeds while the stored notification still has the version of `notification`, failing with
+    /// a conflict otherwise, and returns the notification as saved with the next version. The outbox event carries
+    /// the notification as saved, so its consumers see the version they can send back in `If-Match`.
+    async fn save(
+        &self,
+        notification: &NotificationEntity,
+        outbox_event: &OutboxEventEntity,
+        history_entry: Option<NotificationHistoryEntity>,
+    ) -> Result<NotificationEntity, ApplicationError>;
+
+    /// Saves several changes to the notification at once, as `save` does, appending every outbox event and history
+    /// entry in order. Each outbox event carries the notification as saved.
+    async fn save_changes(
+        &self,
+        notification: &NotificationEntity,
+        outbox_events: &[OutboxEventEntity],
+        history_entries: Vec<NotificationHistoryEntity>,
+    ) -> Result<NotificationEntity, ApplicationError>;
 
     async fn get(&self, uuid: &Uuid) -> Result<NotificationEntity, ApplicationError>;
 
-    async fn get_all(&self) -> Result<Vec<NotificationEntity>, ApplicationError>;
-}
\ No newline at end of file
+    /// Notifications of the recipient, oldest first.
+    async fn get_all_of(&self, recipient: &str) -> Result<Vec<NotificationEntity>, ApplicationError>;
+
+    /// Notifications of the recipient with the tag, oldest first.
+    async fn get_tagged_of(&self, recipient: &str, tag: &str) -> Result<Vec<NotificationEntity>, ApplicationError>;
+
+    /// How many notifications of the recipient, neither deleted nor archived, have each tag, most used first.
+    async fn count_tags_of(&self, recipient: &str) -> Result<Vec<(String, usize)>, ApplicationError>;
+
+    /// History of the notification, oldest first.
+    async fn get_history_of(&self, uuid: &Uuid) -> Result<Vec<NotificationHistoryEntity>, ApplicationError>;
+
+    /// Notifications whose snooze ends at or before `until`, earliest first, leaving out deleted ones.
+    async fn get_snoozed_until(&self, until: DateTime<Utc>, limit: usize) -> Result<Vec<NotificationEntity>, ApplicationError>;
+
+    /// Notifications of the recipient holding every word of `query` in their title or message, most relevant
+    /// first, with a snippet of the matching text.
+    async fn search_of(&self, recipient: &str, query: &str) -> Result<Vec<NotificationSearchHitEntity>, ApplicationError>;
+}
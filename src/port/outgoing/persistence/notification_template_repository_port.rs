@@ -0,0 +1,16 @@
+use crate::adapter::outgoing::persistence::entity::notification_template_entity::NotificationTemplateEntity;
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait NotificationTemplateRepositoryPort: Send + Sync {
+    async fn save(&self, template: &NotificationTemplateEntity) -> Result<NotificationTemplateEntity, ApplicationError>;
+
+    async fn get(&self, id: &str) -> Result<NotificationTemplateEntity, ApplicationError>;
+
+    async fn get_all(&self) -> Result<Vec<NotificationTemplateEntity>, ApplicationError>;
+
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError>;
+}
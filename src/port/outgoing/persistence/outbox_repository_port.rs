@@ -0,0 +1,18 @@
+use crate::adapter::outgoing::persistence::entity::outbox_event_entity::OutboxEventEntity;
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+#[automock]
+#[async_trait]
+pub trait OutboxRepositoryPort: Send + Sync {
+    /// Oldest events not yet marked as relayed, in the order they were written.
+    async fn get_pending(&self, limit: usize) -> Result<Vec<OutboxEventEntity>, ApplicationError>;
+
+    /// Keeps how far a pending event was relayed: its `published_at` and the subscriptions it was `routed_to`.
+    async fn save_progress(&self, outbox_event: &OutboxEventEntity) -> Result<(), ApplicationError>;
+
+    /// The event reached every consumer; stores may drop it from then on.
+    async fn mark_relayed(&self, uuid: &Uuid) -> Result<(), ApplicationError>;
+}
@@ -0,0 +1,12 @@
+use crate::adapter::outgoing::persistence::entity::recipient_profile_entity::RecipientProfileEntity;
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait RecipientProfileRepositoryPort: Send + Sync {
+    async fn save(&self, profile: &RecipientProfileEntity) -> Result<RecipientProfileEntity, ApplicationError>;
+
+    async fn get(&self, recipient: &str) -> Result<RecipientProfileEntity, ApplicationError>;
+}
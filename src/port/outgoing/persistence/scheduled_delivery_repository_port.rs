@@ -0,0 +1,17 @@
+use crate::adapter::outgoing::persistence::entity::scheduled_delivery_entity::ScheduledDeliveryEntity;
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use uuid::Uuid;
+
+#[automock]
+#[async_trait]
+pub trait ScheduledDeliveryRepositoryPort: Send + Sync {
+    async fn save(&self, scheduled_delivery: &ScheduledDeliveryEntity) -> Result<ScheduledDeliveryEntity, ApplicationError>;
+
+    /// Deliveries due at or before `until`, earliest first.
+    async fn get_due(&self, until: DateTime<Utc>, limit: usize) -> Result<Vec<ScheduledDeliveryEntity>, ApplicationError>;
+
+    async fn delete(&self, uuid: &Uuid) -> Result<(), ApplicationError>;
+}
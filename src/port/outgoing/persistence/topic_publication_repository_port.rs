@@ -0,0 +1,16 @@
+use crate::adapter::outgoing::persistence::entity::topic_publication_entity::TopicPublicationEntity;
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+#[automock]
+#[async_trait]
+pub trait TopicPublicationRepositoryPort: Send + Sync {
+    async fn save(&self, publication: &TopicPublicationEntity) -> Result<TopicPublicationEntity, ApplicationError>;
+
+    async fn get(&self, uuid: &Uuid) -> Result<TopicPublicationEntity, ApplicationError>;
+
+    /// Oldest publications whose fan-out has not completed yet.
+    async fn get_unfinished(&self, limit: usize) -> Result<Vec<TopicPublicationEntity>, ApplicationError>;
+}
@@ -0,0 +1,36 @@
+use crate::adapter::outgoing::persistence::entity::topic_entity::{TopicEntity, TopicSubscriptionEntity};
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait TopicRepositoryPort: Send + Sync {
+    async fn save(&self, topic: &TopicEntity) -> Result<TopicEntity, ApplicationError>;
+
+    async fn get(&self, id: &str) -> Result<TopicEntity, ApplicationError>;
+
+    /// Every topic, by id.
+    async fn get_all(&self) -> Result<Vec<TopicEntity>, ApplicationError>;
+
+    /// Deletes the topic together with its subscriptions.
+    async fn delete(&self, id: &str) -> Result<(), ApplicationError>;
+
+    /// Subscribing twice keeps the first subscription.
+    async fn subscribe(&self, subscription: &TopicSubscriptionEntity) -> Result<TopicSubscriptionEntity, ApplicationError>;
+
+    async fn unsubscribe(&self, topic_id: &str, recipient: &str) -> Result<(), ApplicationError>;
+
+    /// Subscriptions of the topic by recipient, starting after `after` when given.
+    async fn get_subscriptions(
+        &self,
+        topic_id: &str,
+        after: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<TopicSubscriptionEntity>, ApplicationError>;
+
+    async fn count_subscriptions(&self, topic_id: &str) -> Result<usize, ApplicationError>;
+
+    /// Subscriptions of the recipient, by topic.
+    async fn get_subscriptions_of(&self, recipient: &str) -> Result<Vec<TopicSubscriptionEntity>, ApplicationError>;
+}
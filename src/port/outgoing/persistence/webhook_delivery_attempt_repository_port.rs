@@ -0,0 +1,13 @@
+use crate::adapter::outgoing::persistence::entity::webhook_delivery_attempt_entity::WebhookDeliveryAttemptEntity;
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+#[automock]
+#[async_trait]
+pub trait WebhookDeliveryAttemptRepositoryPort: Send + Sync {
+    async fn save(&self, attempt: &WebhookDeliveryAttemptEntity) -> Result<WebhookDeliveryAttemptEntity, ApplicationError>;
+
+    async fn get_by_subscription(&self, subscription_uuid: &Uuid) -> Result<Vec<WebhookDeliveryAttemptEntity>, ApplicationError>;
+}
@@ -0,0 +1,17 @@
+use crate::adapter::outgoing::persistence::entity::webhook_subscription_entity::WebhookSubscriptionEntity;
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+#[automock]
+#[async_trait]
+pub trait WebhookSubscriptionRepositoryPort: Send + Sync {
+    async fn save(&self, subscription: &WebhookSubscriptionEntity) -> Result<WebhookSubscriptionEntity, ApplicationError>;
+
+    async fn get(&self, uuid: &Uuid) -> Result<WebhookSubscriptionEntity, ApplicationError>;
+
+    async fn get_all(&self) -> Result<Vec<WebhookSubscriptionEntity>, ApplicationError>;
+
+    async fn delete(&self, uuid: &Uuid) -> Result<(), ApplicationError>;
+}
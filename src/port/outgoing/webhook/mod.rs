@@ -0,0 +1 @@
+pub mod webhook_sender_port;
\ No newline at end of file
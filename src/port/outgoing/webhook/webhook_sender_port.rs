@@ -0,0 +1,10 @@
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait WebhookSenderPort: Send + Sync {
+    /// Posts `body` to `url` and returns the status code of the response.
+    async fn post(&self, url: &str, headers: Vec<(String, String)>, body: String) -> Result<u16, ApplicationError>;
+}